@@ -1,12 +1,54 @@
-use solang_parser::diagnostics::Diagnostic;
+use solang_parser::{diagnostics::Diagnostic as SolangDiagnostic, pt as solidity};
 use std::path::PathBuf;
 
 #[derive(Debug)]
 pub enum Error {
-    Wrapped(Box<dyn std::error::Error>),
+    Wrapped(Box<dyn std::error::Error + Send + Sync>),
     MissingContractFile,
     LineNotFound(PathBuf, usize),
-    SolangDiagnostics(PathBuf, Vec<(usize, usize)>, Vec<Diagnostic>),
+    SolangDiagnostics(PathBuf, Vec<(usize, usize)>, Vec<SolangDiagnostic>),
+    UnsupportedConstruct(solidity::Loc, String),
+    UnresolvedReference(solidity::Loc, String),
+    InvalidConstruct(solidity::Loc, String),
+}
+
+impl Error {
+    /// Gets the location of the Solidity code that caused the error, if any.
+    pub fn loc(&self) -> Option<&solidity::Loc> {
+        match self {
+            Error::UnsupportedConstruct(loc, _)
+            | Error::UnresolvedReference(loc, _)
+            | Error::InvalidConstruct(loc, _) => match loc {
+                solidity::Loc::File(_, _, _) => Some(loc),
+                _ => None,
+            },
+
+            _ => None,
+        }
+    }
+
+    /// Attaches `loc` to the error if it doesn't already have a location.
+    pub fn with_loc(self, loc: &solidity::Loc) -> Self {
+        if self.loc().is_some() {
+            return self;
+        }
+
+        match self {
+            Error::UnsupportedConstruct(_, message) => Error::UnsupportedConstruct(*loc, message),
+            Error::UnresolvedReference(_, message) => Error::UnresolvedReference(*loc, message),
+            Error::InvalidConstruct(_, message) => Error::InvalidConstruct(*loc, message),
+            _ => self,
+        }
+    }
+
+    /// Gets the error message without any location information.
+    pub fn message(&self) -> String {
+        match self {
+            Error::UnsupportedConstruct(_, message) => format!("Unsupported {message}"),
+            Error::UnresolvedReference(_, message) | Error::InvalidConstruct(_, message) => message.clone(),
+            _ => self.to_string().trim_start_matches("error: ").to_string(),
+        }
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -53,6 +95,50 @@ impl std::fmt::Display for Error {
 
                 Ok(())
             }
+
+            Error::UnsupportedConstruct(_, _)
+            | Error::UnresolvedReference(_, _)
+            | Error::InvalidConstruct(_, _) => {
+                write!(f, "error: {}", self.message())
+            }
         }
     }
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagnosticLevel {
+    Warning,
+    Error,
+}
+
+impl std::fmt::Display for DiagnosticLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DiagnosticLevel::Warning => write!(f, "warning"),
+            DiagnosticLevel::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A non-fatal message produced while translating a source unit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub level: DiagnosticLevel,
+    pub path: PathBuf,
+    pub line_and_column: Option<(usize, usize)>,
+    pub message: String,
+    /// Whether the file at `path` could not be parsed.
+    pub is_parse_error: bool,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}", self.level, self.path.to_string_lossy())?;
+
+        if let Some((line, column)) = self.line_and_column {
+            write!(f, ":{line}:{column}")?;
+        }
+
+        write!(f, ":\n\t{}", self.message)
+    }
+}
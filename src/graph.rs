@@ -0,0 +1,195 @@
+use crate::{project::{Project, SymbolKind}, report::ReportEntry, translate::TranslatedDefinition};
+use serde::Serialize;
+use solang_parser::pt as solidity;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphNodeKind {
+    Contract,
+    AbstractContract,
+    Interface,
+    Library,
+}
+
+/// A contract, library or interface in a [DependencyGraph].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct GraphNode {
+    /// The unique identifier of the node, made from the path of its file and its name.
+    pub id: String,
+    pub name: String,
+    pub kind: GraphNodeKind,
+    pub path: PathBuf,
+    /// The number of pieces of Solidity code in the definition which could not be fully translated.
+    pub untranslated_count: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GraphEdgeKind {
+    /// The file of the source node imports the target node.
+    Import,
+    /// The source node inherits from the target node.
+    Inheritance,
+    /// The source node has a `using` directive for the target library.
+    Using,
+    /// The source node calls the abi of the target node.
+    AbiCall,
+}
+
+/// A dependency of one [GraphNode] on another, referenced by their identifiers.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: GraphEdgeKind,
+}
+
+/// The graph of the dependencies between the contracts, libraries and interfaces of a project.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct DependencyGraph {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+impl DependencyGraph {
+    /// Creates the dependency graph of every definition translated in the `project`.
+    pub fn new(project: &Project, report_entries: &[ReportEntry]) -> Self {
+        let mut graph = Self::default();
+
+        for translated_definition in project.translated_definitions.iter() {
+            let Some(kind) = translated_definition.kind.as_ref() else { continue };
+
+            graph.nodes.push(GraphNode {
+                id: get_node_id(translated_definition),
+                name: translated_definition.name.clone(),
+                kind: match kind {
+                    solidity::ContractTy::Abstract(_) => GraphNodeKind::AbstractContract,
+                    solidity::ContractTy::Contract(_) => GraphNodeKind::Contract,
+                    solidity::ContractTy::Interface(_) => GraphNodeKind::Interface,
+                    solidity::ContractTy::Library(_) => GraphNodeKind::Library,
+                },
+                path: translated_definition.path.clone(),
+                untranslated_count: report_entries.iter()
+                    .filter(|e| e.path == translated_definition.path && e.contract_name.as_ref() == Some(&translated_definition.name))
+                    .count(),
+            });
+        }
+
+        for translated_definition in project.translated_definitions.iter() {
+            if translated_definition.kind.is_none() {
+                continue;
+            }
+
+            // Add an edge to each definition in the files imported by the definition's file
+            if let Some(import_directives) = project.import_directives.get(&translated_definition.path) {
+                for (import_path, import_names) in import_directives.iter() {
+                    for &index in project.symbol_index.get_definitions(import_path) {
+                        let imported_definition = &project.translated_definitions[index];
+
+                        if imported_definition.kind.is_none() {
+                            continue;
+                        }
+
+                        if import_names.as_ref().map(|names| !names.contains(&imported_definition.name)).unwrap_or(false) {
+                            continue;
+                        }
+
+                        graph.add_edge(translated_definition, imported_definition, GraphEdgeKind::Import);
+                    }
+                }
+            }
+
+            let mut add_named_edge = |name: &String, kind: GraphEdgeKind| {
+                if *name == translated_definition.name {
+                    return;
+                }
+
+                if let Some(dependency) = project.find_symbol(&translated_definition.path, SymbolKind::Definition, name, |d| d.kind.is_some()) {
+                    graph.add_edge(translated_definition, dependency, kind);
+                }
+            };
+
+            for name in translated_definition.inherits.iter() {
+                add_named_edge(name, GraphEdgeKind::Inheritance);
+            }
+
+            for using_directive in translated_definition.using_directives.iter() {
+                add_named_edge(&using_directive.library_name, GraphEdgeKind::Using);
+            }
+
+            for abi in translated_definition.abis.iter() {
+                add_named_edge(&abi.name, GraphEdgeKind::AbiCall);
+            }
+        }
+
+        graph
+    }
+
+    /// Adds the nodes and edges of the `other` graph which aren't already in this one.
+    pub fn merge(&mut self, other: DependencyGraph) {
+        for node in other.nodes {
+            if !self.nodes.iter().any(|n| n.id == node.id) {
+                self.nodes.push(node);
+            }
+        }
+
+        for edge in other.edges {
+            if !self.edges.contains(&edge) {
+                self.edges.push(edge);
+            }
+        }
+    }
+
+    fn add_edge(&mut self, from: &TranslatedDefinition, to: &TranslatedDefinition, kind: GraphEdgeKind) {
+        let edge = GraphEdge {
+            from: get_node_id(from),
+            to: get_node_id(to),
+            kind,
+        };
+
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    /// Renders the graph in the Graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut result = String::from("digraph {\n");
+
+        for node in self.nodes.iter() {
+            let shape = match node.kind {
+                GraphNodeKind::Contract => "box",
+                GraphNodeKind::AbstractContract => "box, style=dashed",
+                GraphNodeKind::Interface => "ellipse",
+                GraphNodeKind::Library => "hexagon",
+            };
+
+            let label = if node.untranslated_count > 0 {
+                format!("{}\\n{} untranslated", node.name, node.untranslated_count)
+            } else {
+                node.name.clone()
+            };
+
+            result.push_str(&format!("    {:?} [label=\"{label}\", shape={shape}];\n", node.id));
+        }
+
+        for edge in self.edges.iter() {
+            let style = match edge.kind {
+                GraphEdgeKind::Import => "style=dotted",
+                GraphEdgeKind::Inheritance => "arrowhead=empty",
+                GraphEdgeKind::Using => "style=dashed",
+                GraphEdgeKind::AbiCall => "style=solid",
+            };
+
+            result.push_str(&format!("    {:?} -> {:?} [{style}];\n", edge.from, edge.to));
+        }
+
+        result.push_str("}\n");
+        result
+    }
+}
+
+fn get_node_id(translated_definition: &TranslatedDefinition) -> String {
+    format!("{}:{}", translated_definition.path.to_string_lossy(), translated_definition.name)
+}
@@ -0,0 +1,402 @@
+#![allow(clippy::single_match, clippy::collapsible_match)]
+pub mod errors;
+pub mod graph;
+pub mod project;
+pub mod report;
+pub mod source_map;
+pub mod standard_json;
+pub mod sway;
+pub mod translate;
+pub mod workspace;
+
+use convert_case::{Case, Casing};
+use errors::{Diagnostic, Error};
+use graph::DependencyGraph;
+use project::{find_project_root_folder, Project, ProjectType};
+use report::{Report, ReportEntry};
+use standard_json::StandardJsonInput;
+use std::{collections::HashSet, path::{Path, PathBuf}};
+use translate::TranslatedDefinition;
+
+#[inline]
+pub fn translate_naming_convention(name: &str, case: Case) -> String {
+    let name = if name.chars().all(|c| c == '_') {
+        name.to_string()
+    } else {
+        let prefix = name.chars().take_while(|c| *c == '_').collect::<String>();
+        let postfix = name.chars().rev().take_while(|c| *c == '_').collect::<String>();
+        format!("{prefix}{}{postfix}", name.to_case(case))
+    };
+
+    match name.as_str() {
+        "self" => "this".into(),
+        _ => name,
+    }
+}
+
+#[inline]
+pub fn get_canonical_path<P: AsRef<Path>>(path: P, is_dir: bool, create_if_necessary: bool) -> std::io::Result<PathBuf> {
+    let mut path_string = path.as_ref().to_string_lossy().to_string();
+
+    while path_string.contains("\\\\") {
+        path_string = path_string.replace("\\\\", "\\");
+    }
+
+    path_string = path_string.replace('\\', "/");
+
+    while path_string.contains("//") {
+        path_string = path_string.replace("//", "/");
+    }
+
+    if is_dir && !path_string.ends_with('/') {
+        path_string = format!("{path_string}/");
+    }
+
+    let path = PathBuf::from(path_string);
+
+    if create_if_necessary && !path.exists() {
+        if is_dir {
+            std::fs::create_dir_all(path.clone())?;
+        } else {
+            std::fs::File::create(path.clone())?;
+        }
+    }
+
+    path.canonicalize()
+}
+
+/// The output of translating one or more Solidity source units.
+///
+/// Definitions are listed in the order they were translated in, so the definitions a contract depends on come before it.
+#[derive(Clone, Debug, Default)]
+pub struct Translation {
+    pub definitions: Vec<TranslatedDefinition>,
+    pub diagnostics: Vec<Diagnostic>,
+    pub report_entries: Vec<ReportEntry>,
+    /// The dependencies between every contract, library and interface in the translated project.
+    pub graph: DependencyGraph,
+}
+
+impl Translation {
+    /// Converts each of the translated definitions into a Sway module.
+    pub fn modules(&self) -> Vec<sway::Module> {
+        self.definitions.iter().cloned().map(sway::Module::from).collect()
+    }
+
+    /// Summarizes the Solidity code which could not be fully translated.
+    pub fn report(&self) -> Report {
+        Report::new(self.report_entries.as_slice())
+    }
+
+    fn extend(&mut self, project: &Project, definition_name: Option<&String>, source_unit_paths: &[PathBuf]) {
+        self.extend_with(project, |translated_definition| {
+            source_unit_paths.contains(&translated_definition.path)
+                && definition_name.map(|n| *n == translated_definition.name).unwrap_or(true)
+        });
+    }
+
+    fn extend_with<F: Fn(&TranslatedDefinition) -> bool>(&mut self, project: &Project, predicate: F) {
+        // Keep the order the definitions were translated in, so dependencies come before their dependents
+        for translated_definition in project.translated_definitions.iter() {
+            if predicate(translated_definition) {
+                self.definitions.push(translated_definition.clone());
+            }
+        }
+
+        for diagnostic in project.diagnostics.iter() {
+            if !self.diagnostics.contains(diagnostic) {
+                self.diagnostics.push(diagnostic.clone());
+            }
+        }
+
+        for report_entry in project.report_entries.iter() {
+            if !self.report_entries.contains(report_entry) {
+                self.report_entries.push(report_entry.clone());
+            }
+        }
+
+        self.graph = DependencyGraph::new(project, project.report_entries.as_slice());
+    }
+
+    /// Adds everything in the `other` translation which isn't already in this one, keeping the order of both.
+    fn merge(&mut self, other: Translation) {
+        for translated_definition in other.definitions {
+            if !self.definitions.iter().any(|d| d.path == translated_definition.path && d.name == translated_definition.name) {
+                self.definitions.push(translated_definition);
+            }
+        }
+
+        for diagnostic in other.diagnostics {
+            if !self.diagnostics.contains(&diagnostic) {
+                self.diagnostics.push(diagnostic);
+            }
+        }
+
+        for report_entry in other.report_entries {
+            if !self.report_entries.contains(&report_entry) {
+                self.report_entries.push(report_entry);
+            }
+        }
+
+        self.graph.merge(other.graph);
+    }
+}
+
+/// Translates the Solidity file or directory at `path`.
+///
+/// Only definitions named `definition_name` are returned when it is supplied.
+pub fn translate_path<P: AsRef<Path>>(path: P, definition_name: Option<&String>) -> Result<Translation, Error> {
+    let (project, source_unit_paths) = translate_project(path.as_ref(), definition_name)?;
+
+    let mut translation = Translation::default();
+    translation.extend(&project, definition_name, source_unit_paths.as_slice());
+
+    Ok(translation)
+}
+
+/// Translates the Solidity file or directory at `path`, splitting its source units between up to `jobs` threads.
+///
+/// Every file is parsed once up front, and source units which import the same files (directly or indirectly) are kept in the same
+/// thread, so each file is only translated once. The definitions of each thread keep the order they were translated in, so
+/// dependencies still come before their dependents.
+pub fn translate_path_with_jobs<P: AsRef<Path>>(path: P, definition_name: Option<&String>, jobs: usize) -> Result<Translation, Error> {
+    let path = path.as_ref();
+
+    // Standard JSON input is loaded into a single project
+    if jobs <= 1 || is_standard_json_path(path) {
+        return translate_path(path, definition_name);
+    }
+
+    let source_unit_paths = collect_source_unit_paths(path)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    let mut project = Project::default();
+
+    if let Some(root_path) = find_project_root_folder(path) {
+        project.detect_project_type(root_path)?;
+    }
+
+    let mut groups = project.group_source_units_by_imports(source_unit_paths.as_slice());
+
+    // Hand out the largest groups first, each to the thread with the fewest files so far
+    groups.sort_by_key(|(_, paths)| std::cmp::Reverse(paths.len()));
+
+    let mut buckets = vec![(0usize, vec![], HashSet::new()); jobs.min(groups.len()).max(1)];
+
+    for (group_source_unit_paths, group_paths) in groups {
+        let (size, bucket_source_unit_paths, bucket_paths) = buckets.iter_mut().min_by_key(|(size, _, _)| *size).unwrap();
+        *size += group_paths.len();
+        bucket_source_unit_paths.extend(group_source_unit_paths);
+        bucket_paths.extend(group_paths);
+    }
+
+    let results = std::thread::scope(|scope| {
+        let handles = buckets.into_iter()
+            .map(|(_, bucket_source_unit_paths, bucket_paths)| {
+                let mut project = project.fork(&bucket_paths);
+                let source_unit_paths = source_unit_paths.as_slice();
+
+                scope.spawn(move || -> Translation {
+                    // Keep translating the other source units when one of them fails to parse
+                    for source_unit_path in bucket_source_unit_paths.iter() {
+                        if let Err(error) = project.translate(definition_name, source_unit_path) {
+                            project.report_error(source_unit_path, &error);
+                        }
+                    }
+
+                    let mut translation = Translation::default();
+                    translation.extend(&project, definition_name, source_unit_paths);
+
+                    translation
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles.into_iter()
+            .map(|handle| handle.join().unwrap_or_else(|e| std::panic::resume_unwind(e)))
+            .collect::<Vec<_>>()
+    });
+
+    let mut translation = Translation::default();
+
+    for result in results {
+        translation.merge(result);
+    }
+
+    Ok(translation)
+}
+
+/// Translates the Solidity file, directory or standard JSON input at `path` along with every definition the definitions named in `root_names` depend on.
+///
+/// Only the root definitions and the contracts, libraries and interfaces they depend on through inheritance or imports are returned.
+pub fn translate_dependency_closure<P: AsRef<Path>>(path: P, root_names: &[String]) -> Result<Translation, Error> {
+    let (project, source_unit_paths) = translate_project(path.as_ref(), None)?;
+    let closure = project.collect_dependency_closure(root_names, source_unit_paths.as_slice())?;
+
+    let mut translation = Translation::default();
+    translation.extend_with(&project, |translated_definition| {
+        closure.contains(&(translated_definition.path.clone(), translated_definition.name.clone()))
+    });
+
+    Ok(translation)
+}
+
+/// Translates the Solidity file, directory or standard JSON input at `path`, returning the project along with the paths of the target source units.
+fn translate_project(path: &Path, definition_name: Option<&String>) -> Result<(Project, Vec<PathBuf>), Error> {
+    if is_standard_json_path(path) {
+        return translate_standard_json_project(path, definition_name);
+    }
+
+    let source_unit_paths = collect_source_unit_paths(path)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    // Share a single project between all of the source units so that common imports are only translated once
+    let mut project = Project::default();
+
+    // Directories inside of a project (i.e: `src`) use the configuration of the project they belong to
+    if let Some(root_path) = find_project_root_folder(path) {
+        project.detect_project_type(root_path)?;
+    } else {
+        project.project_type = ProjectType::Unknown;
+    }
+
+    // Keep translating the other source units when one of them fails to parse
+    for source_unit_path in source_unit_paths.iter() {
+        if let Err(error) = project.translate(definition_name, source_unit_path) {
+            project.report_error(source_unit_path, &error);
+        }
+    }
+
+    Ok((project, source_unit_paths))
+}
+
+/// Checks whether the file at `path` is solc standard JSON input, which is any file with a `.json` extension.
+fn is_standard_json_path(path: &Path) -> bool {
+    path.is_file() && path.extension().map(|e| e == "json").unwrap_or(false)
+}
+
+/// Translates the in-memory Solidity `source` as if it were the file at `path`.
+///
+/// The file at `path` does not need to exist, but relative imports in `source` are resolved from its parent directory.
+pub fn translate_source<P: AsRef<Path>>(path: P, source: &str, definition_name: Option<&String>) -> Result<Translation, Error> {
+    let mut path = path.as_ref().to_path_buf();
+
+    if path.is_relative() {
+        path = std::env::current_dir()
+            .map_err(|e| Error::Wrapped(Box::new(e)))?
+            .join(path);
+    }
+
+    if path.exists() {
+        path = get_canonical_path(path, false, false)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+    }
+
+    let mut project = Project::default();
+
+    if let Some(root_path) = path.parent().and_then(find_project_root_folder) {
+        project.detect_project_type(root_path)?;
+    }
+
+    project.load_solidity_source_unit(path.as_path(), source)?;
+    project.translate(definition_name, path.as_path())?;
+
+    let mut translation = Translation::default();
+    translation.extend(&project, definition_name, &[path]);
+
+    Ok(translation)
+}
+
+/// Translates the source units in the solc standard JSON input file at `path`.
+///
+/// Source unit names are resolved relative to the directory containing `path`, using the remappings in its `settings`.
+pub fn translate_standard_json<P: AsRef<Path>>(path: P, definition_name: Option<&String>) -> Result<Translation, Error> {
+    let (project, source_unit_paths) = translate_standard_json_project(path.as_ref(), definition_name)?;
+
+    let mut translation = Translation::default();
+    translation.extend(&project, definition_name, source_unit_paths.as_slice());
+
+    Ok(translation)
+}
+
+fn translate_standard_json_project(path: &Path, definition_name: Option<&String>) -> Result<(Project, Vec<PathBuf>), Error> {
+    let path = get_canonical_path(path, false, false)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    let base_path = path.parent().map(PathBuf::from).unwrap_or_default();
+    let input = StandardJsonInput::from_file(path.as_path())?;
+
+    let mut project = Project {
+        project_type: ProjectType::StandardJson {
+            base_path: base_path.clone(),
+            remappings: input.remappings()?,
+        },
+        ..Default::default()
+    };
+
+    let mut source_unit_paths = vec![];
+
+    // Load every source unit ahead of time so that imports between them are resolved from memory
+    for (source_unit_name, content) in input.source_contents(base_path.as_path())? {
+        let source_unit_path = project::normalize_path(base_path.join(source_unit_name));
+
+        // Keep loading the other source units when one of them fails to parse
+        if let Err(error) = project.load_solidity_source_unit(source_unit_path.as_path(), content.as_str()) {
+            project.report_error(source_unit_path.as_path(), &error);
+            continue;
+        }
+
+        source_unit_paths.push(source_unit_path);
+    }
+
+    for source_unit_path in source_unit_paths.iter() {
+        if let Err(error) = project.translate(definition_name, source_unit_path) {
+            project.report_error(source_unit_path, &error);
+        }
+    }
+
+    Ok((project, source_unit_paths))
+}
+
+/// Recursively search for .sol files in the given directory
+pub fn collect_source_unit_paths(path: &Path) -> std::io::Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        if path.extension().map(|e| e != "sol").unwrap_or(true) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Only solidity files are supported: {}", path.to_string_lossy()),
+            ));
+        }
+
+        if !path.exists() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("File not found: {}", path.to_string_lossy()),
+            ));
+        }
+
+        return Ok(vec![
+            get_canonical_path(path, false, false)?,
+        ]);
+    }
+
+    let mut source_unit_paths = vec![];
+
+    for entry in std::fs::read_dir(path)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        if path.is_dir() {
+            source_unit_paths.extend(collect_source_unit_paths(&path)?);
+            continue;
+        }
+
+        if let Some(extension) = path.extension() {
+            if extension == "sol" {
+                source_unit_paths.push(get_canonical_path(path, false, false)?);
+            }
+        }
+    }
+
+    Ok(source_unit_paths)
+}
@@ -1,62 +1,11 @@
-#![allow(clippy::single_match)]
-pub mod errors;
-pub mod project;
-pub mod sway;
-pub mod translate;
-
-use convert_case::{Case, Casing};
-use errors::Error;
-use project::Project;
+use charcoal::{
+    errors::{DiagnosticLevel, Error}, get_canonical_path, source_map, sway, translate::TranslatedDefinition,
+    translate_naming_convention, workspace::{Workspace, WorkspaceMember},
+};
+use convert_case::Case;
 use std::path::{Path, PathBuf};
 use structopt::{clap::AppSettings, StructOpt};
 
-#[inline]
-pub fn translate_naming_convention(name: &str, case: Case) -> String {
-    let name = if name.chars().all(|c| c == '_') {
-        name.to_string()
-    } else {
-        let prefix = name.chars().take_while(|c| *c == '_').collect::<String>();
-        let postfix = name.chars().rev().take_while(|c| *c == '_').collect::<String>();
-        format!("{prefix}{}{postfix}", name.to_case(case))
-    };
-
-    match name.as_str() {
-        "self" => "this".into(),
-        _ => name,
-    }
-}
-
-#[inline]
-pub fn get_canonical_path<P: AsRef<Path>>(path: P, is_dir: bool, create_if_necessary: bool) -> std::io::Result<PathBuf> {
-    let mut path_string = path.as_ref().to_string_lossy().to_string();
-
-    while path_string.contains("\\\\") {
-        path_string = path_string.replace("\\\\", "\\");
-    }
-
-    path_string = path_string.replace('\\', "/");
-
-    while path_string.contains("//") {
-        path_string = path_string.replace("//", "/");
-    }
-
-    if is_dir && !path_string.ends_with('/') {
-        path_string = format!("{path_string}/");
-    }
-
-    let path = PathBuf::from(path_string);
-
-    if create_if_necessary && !path.exists() {
-        if is_dir {
-            std::fs::create_dir_all(path.clone())?;
-        } else {
-            std::fs::File::create(path.clone())?;
-        }
-    }
-    
-    path.canonicalize()
-}
-
 #[derive(Default, StructOpt)]
 #[structopt(global_settings = &[AppSettings::ColoredHelp, AppSettings::ArgRequiredElseHelp])]
 struct Options {
@@ -64,24 +13,76 @@ struct Options {
     #[structopt(long, short)]
     definition_name: Option<String>,
 
-    /// The Solidity target file or folder to translate.
+    /// The names of the root definitions to translate along with the contracts, libraries and interfaces they depend on. (Optional; Conflicts with definition-name)
+    #[structopt(long, conflicts_with = "definition-name")]
+    closure: Vec<String>,
+
+    /// The Solidity target file or folder, or solc standard JSON input file to translate.
     #[structopt(long, short)]
     target: PathBuf,
 
+    /// The number of threads to translate the source units of the target folder with. (Optional; Defaults to 1)
+    #[structopt(long, short, default_value = "1")]
+    jobs: usize,
+
     /// The path to save the translated Forc project to. (Optional; Must be a directory)
     #[structopt(long, short)]
     output_directory: Option<PathBuf>,
+
+    /// Whether to save the translated definitions as members of a single Forc workspace. (Optional; Requires an output directory)
+    #[structopt(long)]
+    workspace: bool,
+
+    /// Whether to also save a library package holding only the ABI of each translated definition. (Optional; Requires an output directory)
+    #[structopt(long)]
+    abi_packages: bool,
+
+    /// The path to save a JSON report of the Solidity code which could not be fully translated to. (Optional)
+    #[structopt(long)]
+    report: Option<PathBuf>,
+
+    /// The path to save the dependency graph of the translated project to, in DOT if it has a `.dot` or `.gv` extension or JSON otherwise. (Optional)
+    #[structopt(long)]
+    graph: Option<PathBuf>,
+
+    /// Whether to fail when any of the Solidity code could not be fully translated. (Optional)
+    #[structopt(long)]
+    strict: bool,
 }
 
+/// The exit code used when the translation failed or reported errors.
+const EXIT_CODE_TRANSLATION_ERROR: i32 = 1;
+
+/// The exit code used when a Solidity source unit could not be parsed.
+const EXIT_CODE_PARSE_ERROR: i32 = 2;
+
+/// The exit code used in strict mode when any placeholder or `unsupported:` comment was produced.
+const EXIT_CODE_INCOMPLETE_TRANSLATION: i32 = 3;
+
 fn main() {
-    if let Err(e) = translate_project() {
-        eprintln!("{e}");
+    match translate_project() {
+        Ok(exit_code) => std::process::exit(exit_code),
+
+        Err(e) => {
+            eprintln!("{e}");
+
+            std::process::exit(match e {
+                Error::SolangDiagnostics(..) => EXIT_CODE_PARSE_ERROR,
+                _ => EXIT_CODE_TRANSLATION_ERROR,
+            });
+        }
     }
 }
 
-fn translate_project() -> Result<(), Error> {
-    let mut options = Options::from_args_safe()
-        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+fn translate_project() -> Result<i32, Error> {
+    let mut options = match Options::from_args_safe() {
+        Ok(options) => options,
+
+        // Let clap exit successfully after displaying the help or version information
+        Err(e) if !e.use_stderr() => e.exit(),
+
+        Err(e) => return Err(Error::Wrapped(Box::new(e))),
+    };
 
     // If an output directory was supplied, canonicalize it
     if let Some(output_directory) = options.output_directory.as_mut() {
@@ -89,130 +90,180 @@ fn translate_project() -> Result<(), Error> {
             .map_err(|e| Error::Wrapped(Box::new(e)))?;
     }
 
-    let source_unit_paths = collect_source_unit_paths(&options.target)
-        .map_err(|e| Error::Wrapped(Box::new(e)))?;
-    
-    for source_unit_path in &source_unit_paths {
-        let mut project = Project::default();
-    
-        if options.target.is_dir() {
-            project.detect_project_type(options.target.as_path())?;
-        } else if let Some(root_path) = crate::project::find_project_root_folder(options.target.as_path()) {
-            project.detect_project_type(root_path)?;
-        } else {
-            project.project_type = crate::project::ProjectType::Unknown;
-        }
-        
-        project.translate(options.definition_name.as_ref(), source_unit_path)?;
-
-        match options.output_directory.as_ref() {
-            Some(output_directory) => generate_forc_project(&mut project, output_directory, options.definition_name.as_ref(), source_unit_path)?,
-
-            None => {
-                for translated_definition in project.collect_translated_definitions(options.definition_name.as_ref(), source_unit_path) {
-                    println!("// Translated from {}", translated_definition.path.to_string_lossy());
-                    
-                    let module: sway::Module = translated_definition.into();
-                    println!("{}", sway::TabbedDisplayer(&module));
-                }
+    let translation = if options.closure.is_empty() {
+        charcoal::translate_path_with_jobs(&options.target, options.definition_name.as_ref(), options.jobs)?
+    } else {
+        charcoal::translate_dependency_closure(&options.target, options.closure.as_slice())?
+    };
+
+    for diagnostic in translation.diagnostics.iter() {
+        eprintln!("{diagnostic}");
+    }
+
+    // If a report path was supplied, write the translation report to it
+    if let Some(report_path) = options.report.as_ref() {
+        let report = serde_json::to_string_pretty(&translation.report())
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        std::fs::write(report_path, report)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+    }
+
+    // If a graph path was supplied, write the dependency graph to it
+    if let Some(graph_path) = options.graph.as_ref() {
+        let graph = match graph_path.extension().and_then(|e| e.to_str()) {
+            Some("dot" | "gv") => translation.graph.to_dot(),
+
+            _ => serde_json::to_string_pretty(&translation.graph)
+                .map_err(|e| Error::Wrapped(Box::new(e)))?,
+        };
+
+        std::fs::write(graph_path, graph)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+    }
+
+    let exit_code = if translation.diagnostics.iter().any(|d| d.is_parse_error) {
+        EXIT_CODE_PARSE_ERROR
+    } else if translation.diagnostics.iter().any(|d| d.level == DiagnosticLevel::Error) {
+        EXIT_CODE_TRANSLATION_ERROR
+    } else if options.strict && !translation.report_entries.is_empty() {
+        EXIT_CODE_INCOMPLETE_TRANSLATION
+    } else {
+        0
+    };
+
+    match options.output_directory.as_ref() {
+        Some(output_directory) if options.workspace => generate_forc_workspace(output_directory, translation.definitions.as_slice(), options.abi_packages)?,
+        Some(output_directory) => generate_forc_project(output_directory, translation.definitions, options.abi_packages)?,
+
+        None => {
+            for translated_definition in translation.definitions {
+                println!("// Translated from {}", translated_definition.path.to_string_lossy());
+
+                let module: sway::Module = translated_definition.into();
+                println!("{}", sway::TabbedDisplayer(&module));
             }
         }
     }
 
-    Ok(())
+    Ok(exit_code)
 }
 
-fn generate_forc_project<P1: AsRef<Path>, P2: AsRef<Path>>(
-    project: &mut Project,
-    output_directory: P1,
-    definition_name: Option<&String>,
-    source_unit_path: P2,
+fn generate_forc_project<P: AsRef<Path>>(
+    output_directory: P,
+    translated_definitions: Vec<TranslatedDefinition>,
+    abi_packages: bool,
 ) -> Result<(), Error> {
     let output_directory = get_canonical_path(output_directory, true, true)
         .map_err(|e| Error::Wrapped(Box::new(e)))?;
 
-    for translated_definition in project.collect_translated_definitions(definition_name, source_unit_path) {
+    for translated_definition in translated_definitions {
+        // Save the ABI package alongside the translated project if requested
+        if abi_packages {
+            if let Some(member) = WorkspaceMember::abi_package(&translated_definition) {
+                write_forc_package(output_directory.join(member.name.as_str()), member.name.as_str(), &member.module, member.dependencies.as_slice())?;
+            }
+        }
+
         let definition_snake_name = translate_naming_convention(translated_definition.name.as_str(), Case::Snake);
         let dependencies = translated_definition.dependencies.clone();
-        
+
         let module: sway::Module = translated_definition.into();
 
-        let project_path = get_canonical_path(output_directory.join(definition_snake_name.as_str()), true, true)
-            .map_err(|e| Error::Wrapped(Box::new(e)))?;
-        
-        let src_dir_path = get_canonical_path(project_path.join("src"), true, true)
-            .map_err(|e| Error::Wrapped(Box::new(e)))?;
-        
-        std::fs::write(
-            src_dir_path.join("main.sw"),
-            sway::TabbedDisplayer(&module).to_string(),
-        )
-        .map_err(|e| Error::Wrapped(Box::new(e)))?;
-    
-        std::fs::write(
-            project_path.join(".gitignore"),
-            "out\ntarget\nForc.lock\n",
-        )
-        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+        write_forc_package(output_directory.join(definition_snake_name.as_str()), definition_snake_name.as_str(), &module, dependencies.as_slice())?;
+    }
+
+    Ok(())
+}
 
-        std::fs::write(
-            project_path.join("Forc.toml"),
-            format!(
-                "[project]\n\
-                authors = [\"\"]\n\
-                entry = \"main.sw\"\n\
-                license = \"Apache-2.0\"\n\
-                name = \"{definition_snake_name}\"\n\
-                \n\
-                [dependencies]\n\
-                {}\
-                \n\
-                ",
-                dependencies.join("\n"),
-            ),
-        )
+fn generate_forc_workspace<P: AsRef<Path>>(
+    output_directory: P,
+    translated_definitions: &[TranslatedDefinition],
+    abi_packages: bool,
+) -> Result<(), Error> {
+    let output_directory = get_canonical_path(output_directory, true, true)
         .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    let mut workspace = Workspace::new(translated_definitions);
+
+    if abi_packages {
+        workspace.add_abi_packages(translated_definitions);
+    }
+
+    for member in workspace.members.iter() {
+        write_forc_package(output_directory.join(member.name.as_str()), member.name.as_str(), &member.module, member.dependencies.as_slice())?;
     }
 
+    std::fs::write(
+        output_directory.join(".gitignore"),
+        "out\ntarget\nForc.lock\n",
+    )
+    .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    std::fs::write(
+        output_directory.join("Forc.toml"),
+        workspace.manifest(),
+    )
+    .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
     Ok(())
 }
 
-/// Recursively search for .sol files in the given directory
-fn collect_source_unit_paths(path: &Path) -> std::io::Result<Vec<PathBuf>> {
-    if !path.is_dir() {
-        if path.extension().unwrap() != "sol" {
-            panic!("Only solidity files are supported.");
-        }
-        
-        if !path.exists() {
-            return Err(std::io::Error::new(
-                std::io::ErrorKind::NotFound,
-                format!("File not found: {}", path.to_string_lossy()),
-            ));
-        }
+fn write_forc_package<P: AsRef<Path>>(
+    project_path: P,
+    name: &str,
+    module: &sway::Module,
+    dependencies: &[String],
+) -> Result<(), Error> {
+    let project_path = get_canonical_path(project_path, true, true)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
 
-        return Ok(vec![
-            get_canonical_path(path, false, false)?,
-        ]);
-    }
+    let src_dir_path = get_canonical_path(project_path.join("src"), true, true)
+        .map_err(|e| Error::Wrapped(Box::new(e)))?;
 
-    let mut source_unit_paths = vec![];
+    let entry = match module.kind {
+        sway::ModuleKind::Library => "lib.sw",
+        _ => "main.sw",
+    };
 
-    for entry in std::fs::read_dir(path)? {
-        let entry = entry?;
-        let path = entry.path();
+    let (code, source_map) = source_map::display_with_source_map(module);
 
-        if path.is_dir() {
-            source_unit_paths.extend(collect_source_unit_paths(&path)?);
-            continue;
-        }
-        
-        if let Some(extension) = path.extension() {
-            if extension == "sol" {
-                source_unit_paths.push(get_canonical_path(path, false, false)?);
-            }
-        }
-    }
+    std::fs::write(
+        src_dir_path.join(entry),
+        code,
+    )
+    .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    // Write the source map linking the lines of the entry file back to the Solidity code
+    std::fs::write(
+        src_dir_path.join(format!("{entry}.map")),
+        serde_json::to_string_pretty(&source_map).map_err(|e| Error::Wrapped(Box::new(e)))?,
+    )
+    .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    std::fs::write(
+        project_path.join(".gitignore"),
+        "out\ntarget\nForc.lock\n",
+    )
+    .map_err(|e| Error::Wrapped(Box::new(e)))?;
 
-    Ok(source_unit_paths)
+    std::fs::write(
+        project_path.join("Forc.toml"),
+        format!(
+            "[project]\n\
+            authors = [\"\"]\n\
+            entry = \"{entry}\"\n\
+            license = \"Apache-2.0\"\n\
+            name = \"{name}\"\n\
+            \n\
+            [dependencies]\n\
+            {}\
+            \n\
+            ",
+            dependencies.join("\n"),
+        ),
+    )
+    .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+    Ok(())
 }
@@ -1,27 +1,34 @@
 use crate::{
-    errors::Error,
+    errors::{Diagnostic, DiagnosticLevel, Error},
+    report::{ReportEntry, ReportEntryKind},
+    source_map::SourceLocation,
+    sway,
     translate::{translate_contract_definition, TranslatedDefinition},
 };
-use solang_parser::pt as solidity;
+use solang_parser::{helpers::CodeLocation, pt as solidity};
 use std::{
-    cell::RefCell,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     path::{Component, Path, PathBuf},
-    rc::Rc,
+    sync::{Arc, RwLock},
 };
 
 /// Represents the type of project as [ProjectType] default is [ProjectType::Unknown]
 #[derive(Clone, Debug, Default)]
 pub enum ProjectType {
     Foundry {
-        remappings: HashMap<String, String>,
+        remappings: Vec<Remapping>,
     },
     Hardhat,
     Brownie {
-        remappings: HashMap<String, String>,
+        remappings: Vec<Remapping>,
     },
     Truffle,
     Dapp,
+    /// A solc standard JSON input, whose source unit names are resolved relative to `base_path`.
+    StandardJson {
+        base_path: PathBuf,
+        remappings: Vec<Remapping>,
+    },
     #[default]
     Unknown,
 }
@@ -34,13 +41,175 @@ impl ProjectType {
     pub const DAPP_CONFIG_FILE: &'static str = "Dappfile";
 }
 
+/// An import remapping in the form of `context:prefix=target`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Remapping {
+    /// The path prefix of the importing files the remapping applies to, or [None] if it applies to all files.
+    pub context: Option<String>,
+    pub prefix: String,
+    pub target: String,
+}
+
+impl Remapping {
+    /// Parses a remapping from a `context:prefix=target` or `prefix=target` string.
+    pub fn parse(remapping: &str) -> Result<Self, Error> {
+        let remapping = remapping.trim();
+
+        let Some((lhs, target)) = remapping.split_once('=') else {
+            return Err(Error::Wrapped(format!("Invalid remapping: `{remapping}`").into()));
+        };
+
+        let (context, prefix) = match lhs.split_once(':') {
+            Some((context, prefix)) => (Some(context.trim_start_matches("./").to_string()).filter(|c| !c.is_empty()), prefix),
+            None => (None, lhs),
+        };
+
+        if prefix.is_empty() {
+            return Err(Error::Wrapped(format!("Invalid remapping: `{remapping}`").into()));
+        }
+
+        Ok(Self {
+            context,
+            prefix: prefix.to_string(),
+            target: target.to_string(),
+        })
+    }
+
+    /// Finds the remapping which applies to `filename` when imported from `context`.
+    /// The remapping with the longest context wins, followed by the one with the longest prefix.
+    pub fn find<'a>(remappings: &'a [Remapping], context: &str, filename: &str) -> Option<&'a Remapping> {
+        let mut result: Option<&Remapping> = None;
+
+        for remapping in remappings {
+            if !filename.starts_with(&remapping.prefix) {
+                continue;
+            }
+
+            if let Some(remapping_context) = remapping.context.as_ref() {
+                if !context.starts_with(remapping_context.as_str()) {
+                    continue;
+                }
+            }
+
+            let key = |r: &Remapping| (r.context.as_ref().map(|c| c.len()).unwrap_or(0), r.prefix.len());
+
+            if result.map(|r| key(remapping) > key(r)).unwrap_or(true) {
+                result = Some(remapping);
+            }
+        }
+
+        result
+    }
+}
+
+/// A name which is bound by an import directive in the importing file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImportAlias {
+    /// `import "x.sol" as X;` or `import * as X from "x.sol";`, where `X` refers to the imported file.
+    SourceUnit(PathBuf),
+    /// `import { A as B } from "x.sol";`, where `B` refers to `A`.
+    Symbol(String),
+}
+
+/// The kind of a symbol in a [SymbolIndex].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    Definition,
+    Struct,
+    Enum,
+    Event,
+    Error,
+    Function,
+}
+
+/// An index of the symbols declared by the translated definitions of a project, qualified by the path of the file they were declared in.
+///
+/// Each symbol maps to the indices of the translated definitions which declare it, in the order they were translated in.
+#[derive(Clone, Debug, Default)]
+pub struct SymbolIndex {
+    symbols: HashMap<PathBuf, HashMap<SymbolKind, HashMap<String, Vec<usize>>>>,
+    definitions: HashMap<PathBuf, Vec<usize>>,
+}
+
+impl SymbolIndex {
+    /// Adds the symbols declared by the `translated_definition` at `index`.
+    pub fn insert(&mut self, index: usize, translated_definition: &TranslatedDefinition) {
+        self.definitions.entry(translated_definition.path.clone()).or_default().push(index);
+
+        let symbols = self.symbols.entry(translated_definition.path.clone()).or_default();
+
+        let mut insert = |kind: SymbolKind, name: &str| {
+            let indices = symbols.entry(kind).or_default().entry(name.to_string()).or_default();
+
+            if !indices.contains(&index) {
+                indices.push(index);
+            }
+        };
+
+        insert(SymbolKind::Definition, translated_definition.name.as_str());
+
+        for x in translated_definition.structs.iter() {
+            insert(SymbolKind::Struct, x.name.as_str());
+        }
+
+        for x in translated_definition.enums.iter() {
+            if let sway::TypeName::Identifier { name, .. } = &x.type_definition.name {
+                insert(SymbolKind::Enum, name.as_str());
+            }
+        }
+
+        for (events_enum, _) in translated_definition.events_enums.iter() {
+            for variant in events_enum.variants.iter() {
+                insert(SymbolKind::Event, variant.name.as_str());
+            }
+        }
+
+        for (errors_enum, _) in translated_definition.errors_enums.iter() {
+            for variant in errors_enum.variants.iter() {
+                insert(SymbolKind::Error, variant.name.as_str());
+            }
+        }
+
+        // Functions are indexed by their Solidity names, which are kept by their declarations in the toplevel scope
+        for x in translated_definition.functions.iter() {
+            let Some(function) = translated_definition.toplevel_scope.read().unwrap().find_function(|f| f.read().unwrap().new_name == x.name) else { continue };
+            let function = function.read().unwrap();
+
+            if !function.old_name.is_empty() {
+                insert(SymbolKind::Function, function.old_name.as_str());
+            }
+        }
+    }
+
+    /// Gets the indices of the translated definitions in the file at `path` which declare the symbol of the supplied `kind` named `name`.
+    pub fn get(&self, path: &Path, kind: SymbolKind, name: &str) -> &[usize] {
+        self.symbols.get(path)
+            .and_then(|symbols| symbols.get(&kind))
+            .and_then(|symbols| symbols.get(name))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Gets the indices of the translated definitions in the file at `path`.
+    pub fn get_definitions(&self, path: &Path) -> &[usize] {
+        self.definitions.get(path).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
 #[derive(Default)]
 pub struct Project {
     pub line_ranges: HashMap<PathBuf, Vec<(usize, usize)>>,
-    pub solidity_source_units: Rc<RefCell<HashMap<PathBuf, solidity::SourceUnit>>>,
+    pub solidity_sources: HashMap<PathBuf, String>,
+    pub solidity_comments: HashMap<PathBuf, Vec<solidity::Comment>>,
+    pub solidity_source_units: Arc<RwLock<HashMap<PathBuf, solidity::SourceUnit>>>,
     pub translated_definitions: Vec<TranslatedDefinition>,
+    pub symbol_index: SymbolIndex,
+    pub visited_definitions: HashSet<(PathBuf, String)>,
     pub import_directives: HashMap<PathBuf, HashMap<PathBuf, Option<Vec<String>>>>,
+    pub import_aliases: HashMap<PathBuf, HashMap<String, ImportAlias>>,
     pub project_type: ProjectType,
+    pub diagnostics: Vec<Diagnostic>,
+    pub report_entries: Vec<ReportEntry>,
 }
 
 impl Project {
@@ -62,20 +231,128 @@ impl Project {
         let source = std::fs::read_to_string(path.clone())
             .map_err(|e| Error::Wrapped(Box::new(e)))?;
         
-        self.load_line_ranges(path.clone(), source.as_str());
+        self.load_solidity_source_unit(path, source.as_str())
+    }
 
-        let line_ranges = self.line_ranges.get(&path).unwrap();
+    /// Attempts to parse the supplied `source` text as the file at `path`, which does not need to exist on disk.
+    pub fn load_solidity_source_unit<P: AsRef<Path>>(&mut self, path: P, source: &str) -> Result<(), Error> {
+        let path = path.as_ref().to_path_buf();
 
-        let (source_unit, _comments) = solang_parser::parse(source.as_str(), 0)
-            .map_err(|e| Error::SolangDiagnostics(path.clone(), line_ranges.clone(), e))?;
+        self.line_ranges.remove(&path);
+        self.load_line_ranges(path.clone(), source);
 
-        // TODO: do we need the comments for anything?
+        let line_ranges = self.line_ranges.get(&path).cloned().unwrap_or_default();
 
-        self.solidity_source_units.borrow_mut().insert(path, source_unit);
+        let (source_unit, comments) = solang_parser::parse(source, 0)
+            .map_err(|e| Error::SolangDiagnostics(path.clone(), line_ranges, e))?;
+
+        self.solidity_source_units.write().unwrap().insert(path.clone(), source_unit);
+        self.solidity_sources.insert(path.clone(), source.to_string());
+        self.solidity_comments.insert(path, comments);
 
         Ok(())
     }
 
+    /// Groups the source units at `source_unit_paths` by the files they import, either directly or indirectly.
+    ///
+    /// Source units which share an imported file end up in the same group, so each group can be translated independently of the others.
+    /// Each group holds its source units in their original order, along with the paths of every file they import. Every file is parsed once along the way.
+    pub fn group_source_units_by_imports(&mut self, source_unit_paths: &[PathBuf]) -> Vec<(Vec<PathBuf>, HashSet<PathBuf>)> {
+        // Visit every file reachable from the source units, collecting the imports between them
+        let mut paths = vec![];
+        let mut indices = HashMap::new();
+        let mut imports = vec![];
+        let mut queue = source_unit_paths.to_vec();
+
+        while let Some(path) = queue.pop() {
+            if indices.contains_key(&path) {
+                continue;
+            }
+
+            indices.insert(path.clone(), paths.len());
+            paths.push(path.clone());
+
+            // Files which fail to parse are left on their own, and reported once they are translated
+            if !self.solidity_source_units.read().unwrap().contains_key(&path) && self.parse_solidity_source_unit(&path).is_err() {
+                continue;
+            }
+
+            let Some(source_unit) = self.solidity_source_units.read().unwrap().get(&path).cloned() else { continue };
+            let source_unit_directory = path.parent().map(PathBuf::from).unwrap_or_default();
+
+            for source_unit_part in source_unit.0.iter() {
+                let solidity::SourceUnitPart::ImportDirective(import_directive) = source_unit_part else { continue };
+
+                let (solidity::Import::Plain(import_path, _)
+                | solidity::Import::GlobalSymbol(import_path, _, _)
+                | solidity::Import::Rename(import_path, _, _)) = import_directive;
+
+                let Ok(import_path) = self.resolve_import_path(source_unit_directory.as_path(), import_path) else { continue };
+
+                imports.push((path.clone(), import_path.clone()));
+                queue.push(import_path);
+            }
+        }
+
+        // Join the groups of each importing and imported file
+        let mut parents = (0..paths.len()).collect::<Vec<_>>();
+
+        fn find(parents: &mut [usize], mut index: usize) -> usize {
+            while parents[index] != index {
+                parents[index] = parents[parents[index]];
+                index = parents[index];
+            }
+
+            index
+        }
+
+        for (path, import_path) in imports.iter() {
+            let (a, b) = (find(&mut parents, indices[path]), find(&mut parents, indices[import_path]));
+            parents[a.max(b)] = a.min(b);
+        }
+
+        // Collect the source units and files of each group
+        let mut groups: Vec<(usize, Vec<PathBuf>, HashSet<PathBuf>)> = vec![];
+
+        for source_unit_path in source_unit_paths.iter() {
+            let root = find(&mut parents, indices[source_unit_path]);
+
+            match groups.iter_mut().find(|(r, _, _)| *r == root) {
+                Some((_, source_unit_paths, _)) => source_unit_paths.push(source_unit_path.clone()),
+                None => groups.push((root, vec![source_unit_path.clone()], HashSet::new())),
+            }
+        }
+
+        for (index, path) in paths.into_iter().enumerate() {
+            let root = find(&mut parents, index);
+
+            if let Some((_, _, group_paths)) = groups.iter_mut().find(|(r, _, _)| *r == root) {
+                group_paths.insert(path);
+            }
+        }
+
+        groups.into_iter().map(|(_, source_unit_paths, paths)| (source_unit_paths, paths)).collect()
+    }
+
+    /// Creates a project of the same type which holds what this project has parsed for the files at `paths`, so they aren't parsed again.
+    pub fn fork(&self, paths: &HashSet<PathBuf>) -> Project {
+        fn filter<T: Clone>(map: &HashMap<PathBuf, T>, paths: &HashSet<PathBuf>) -> HashMap<PathBuf, T> {
+            map.iter()
+                .filter(|(path, _)| paths.contains(*path))
+                .map(|(path, value)| (path.clone(), value.clone()))
+                .collect()
+        }
+
+        Project {
+            line_ranges: filter(&self.line_ranges, paths),
+            solidity_sources: filter(&self.solidity_sources, paths),
+            solidity_comments: filter(&self.solidity_comments, paths),
+            solidity_source_units: Arc::new(RwLock::new(filter(&self.solidity_source_units.read().unwrap(), paths))),
+            project_type: self.project_type.clone(),
+            ..Default::default()
+        }
+    }
+
     /// Loads line ranges in a specific file `path` from the provided `source` text.
     #[inline]
     fn load_line_ranges(&mut self, path: PathBuf, source: &str) {
@@ -116,30 +393,510 @@ impl Project {
         None
     }
 
+    /// Gets the original source text of the code at `loc` in the file at `path`.
+    #[inline]
+    pub fn loc_to_source_text<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> Option<&str> {
+        let source = self.solidity_sources.get(path.as_ref())?;
+
+        let solidity::Loc::File(_, start, end) = loc else { return None };
+
+        source.get(*start..*end)
+    }
+
+    /// Gets the comments directly preceding the code at `loc` in the file at `path`, which are only separated from it by whitespace.
+    pub fn get_preceding_comments<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> Vec<&solidity::Comment> {
+        let (Some(source), Some(comments)) = (self.solidity_sources.get(path.as_ref()), self.solidity_comments.get(path.as_ref())) else {
+            return vec![];
+        };
+
+        let solidity::Loc::File(_, start, _) = loc else { return vec![] };
+
+        let mut result = vec![];
+        let mut end = *start;
+
+        for comment in comments.iter().rev() {
+            let comment_loc = comment.loc();
+
+            if comment_loc.end() > end {
+                continue;
+            }
+
+            if !source.get(comment_loc.end()..end).map(|s| s.trim().is_empty()).unwrap_or(false) {
+                break;
+            }
+
+            result.insert(0, comment);
+            end = comment_loc.start();
+        }
+
+        result
+    }
+
+    /// Gets the text of the NatSpec doc comments directly preceding the code at `loc` in the file at `path`.
+    pub fn get_doc_comment<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> Option<String> {
+        let doc_comment = self.get_preceding_comments(path, loc).into_iter()
+            .filter(|comment| comment.is_doc())
+            .map(get_comment_text)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if doc_comment.is_empty() {
+            return None;
+        }
+
+        Some(doc_comment)
+    }
+
+    /// Gets the location of the code at `loc` in the file at `path` for use in source maps.
+    #[inline]
+    pub fn loc_to_source_location<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> Option<SourceLocation> {
+        let solidity::Loc::File(file_no, start, end) = loc else { return None };
+
+        let (start_line, start_column) = self.loc_to_line_and_column(&path, loc)?;
+
+        // The end of the location is exclusive, so find the line and column of its last character instead
+        let last = end.saturating_sub(1).max(*start);
+        let (end_line, end_column) = self.loc_to_line_and_column(&path, &solidity::Loc::File(*file_no, last, last))?;
+
+        Some(SourceLocation {
+            path: path.as_ref().to_path_buf(),
+            start_line,
+            start_column,
+            end_line,
+            end_column: end_column + 1,
+        })
+    }
+
+    /// Records a warning about the code at `loc` in the file at `path`.
+    pub fn report_warning<P: AsRef<Path>, S: ToString>(&mut self, path: P, loc: &solidity::Loc, message: S) {
+        self.report_diagnostic(DiagnosticLevel::Warning, path, Some(loc), message.to_string(), false);
+    }
+
+    /// Records an error which prevented part of the file at `path` from being translated.
+    pub fn report_error<P: AsRef<Path>>(&mut self, path: P, error: &Error) {
+        // Parser errors are reported individually at their own locations, in the file which failed to parse (i.e. an import of `path`)
+        if let Error::SolangDiagnostics(error_path, _, diagnostics) = error {
+            for diagnostic in diagnostics.iter() {
+                self.report_diagnostic(DiagnosticLevel::Error, error_path, Some(&diagnostic.loc), diagnostic.message.clone(), true);
+            }
+
+            return;
+        }
+
+        self.report_diagnostic(DiagnosticLevel::Error, path, error.loc(), error.message(), false);
+    }
+
+    fn report_diagnostic<P: AsRef<Path>>(&mut self, level: DiagnosticLevel, path: P, loc: Option<&solidity::Loc>, message: String, is_parse_error: bool) {
+        let diagnostic = Diagnostic {
+            level,
+            path: path.as_ref().to_path_buf(),
+            line_and_column: loc.and_then(|loc| self.loc_to_line_and_column(&path, loc)),
+            message,
+            is_parse_error,
+        };
+
+        if !self.diagnostics.contains(&diagnostic) {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+
+    /// Records code at `loc` in the translated definition's file which could not be fully translated.
+    pub fn report_unsupported<S: ToString>(&mut self, translated_definition: &TranslatedDefinition, loc: &solidity::Loc, kind: ReportEntryKind, message: S) {
+        let line_and_column = self.loc_to_line_and_column(&translated_definition.path, loc);
+        let (contract_name, function_name) = self.find_enclosing_definition_names(&translated_definition.path, loc);
+
+        let entry = ReportEntry {
+            kind,
+            path: translated_definition.path.clone(),
+            line: line_and_column.map(|(line, _)| line),
+            column: line_and_column.map(|(_, column)| column),
+            contract_name: contract_name.or_else(|| Some(translated_definition.name.clone())),
+            function_name,
+            message: message.to_string(),
+        };
+
+        if !self.report_entries.contains(&entry) {
+            self.report_entries.push(entry);
+        }
+    }
+
+    /// Finds the names of the Solidity contract and function containing `loc` in the file at `path`.
+    fn find_enclosing_definition_names<P: AsRef<Path>>(&self, path: P, loc: &solidity::Loc) -> (Option<String>, Option<String>) {
+        let solidity::Loc::File(_, offset, _) = loc else { return (None, None) };
+
+        let solidity_source_units = self.solidity_source_units.read().unwrap();
+        let Some(source_unit) = solidity_source_units.get(path.as_ref()) else { return (None, None) };
+
+        let contains = |start: usize, end: usize| *offset >= start && *offset < end;
+
+        let function_name = |function_definition: &solidity::FunctionDefinition| -> Option<String> {
+            let end = function_definition.body.as_ref()
+                .map(|body| body.loc().end())
+                .unwrap_or(function_definition.loc.end());
+
+            if !contains(function_definition.loc.start(), end) {
+                return None;
+            }
+
+            match function_definition.name.as_ref() {
+                Some(name) => Some(name.name.clone()),
+                None => Some(function_definition.ty.to_string()),
+            }
+        };
+
+        for source_unit_part in source_unit.0.iter() {
+            match source_unit_part {
+                solidity::SourceUnitPart::ContractDefinition(contract_definition) => {
+                    if !contains(contract_definition.loc.start(), contract_definition.loc.end()) {
+                        continue;
+                    }
+
+                    let contract_name = contract_definition.name.as_ref().map(|name| name.name.clone());
+
+                    for contract_part in contract_definition.parts.iter() {
+                        let solidity::ContractPart::FunctionDefinition(function_definition) = contract_part else { continue };
+
+                        if let Some(function_name) = function_name(function_definition) {
+                            return (contract_name, Some(function_name));
+                        }
+                    }
+
+                    return (contract_name, None);
+                }
+
+                solidity::SourceUnitPart::FunctionDefinition(function_definition) => {
+                    if let Some(function_name) = function_name(function_definition) {
+                        return (None, Some(function_name));
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        (None, None)
+    }
+
     pub fn collect_translated_definitions<P: AsRef<Path>>(&self, definition_name: Option<&String>, source_unit_path: P) -> Vec<TranslatedDefinition> {
+        self.symbol_index.get_definitions(source_unit_path.as_ref()).iter()
+            .map(|&index| &self.translated_definitions[index])
+            .filter(|translated_definition| definition_name.map(|n| translated_definition.name == *n).unwrap_or(true))
+            .cloned()
+            .collect()
+    }
+
+    /// Adds the `translated_definition` to the project and indexes the symbols it declares.
+    pub fn add_translated_definition(&mut self, translated_definition: TranslatedDefinition) {
+        self.symbol_index.insert(self.translated_definitions.len(), &translated_definition);
+        self.translated_definitions.push(translated_definition);
+    }
+
+    /// Gets the translated definition named `name` which was declared in the file at `source_unit_path`.
+    pub fn get_translated_definition(&self, source_unit_path: &Path, name: &str) -> Option<&TranslatedDefinition> {
+        self.symbol_index.get(source_unit_path, SymbolKind::Definition, name).first()
+            .map(|&index| &self.translated_definitions[index])
+    }
+
+    /// Gets the canonical path of the file imported by the supplied `import_path` from a file in `source_unit_directory`.
+    pub fn resolve_import_path(&self, source_unit_directory: &Path, import_path: &solidity::ImportPath) -> Result<PathBuf, Error> {
+        let filename = match import_path {
+            solidity::ImportPath::Filename(filename) => filename.string.clone(),
+
+            // `import std.stub;` imports `std/stub.sol`
+            solidity::ImportPath::Path(identifier_path) => format!(
+                "{}.sol",
+                identifier_path.identifiers.iter().map(|i| i.name.as_str()).collect::<Vec<_>>().join("/"),
+            ),
+        };
+
+        let path = if filename.starts_with('.') {
+            source_unit_directory.join(filename.as_str())
+        } else {
+            self.get_project_type_path(source_unit_directory, filename.as_str())?
+        };
+
+        // Source units loaded from memory (i.e: standard JSON input) may not exist on disk
+        let normalized_path = normalize_path(&path);
+
+        if self.solidity_source_units.read().unwrap().contains_key(&normalized_path) {
+            return Ok(normalized_path);
+        }
+
+        if !path.exists() {
+            return Err(Error::Wrapped(Box::new(
+                std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("File not found: {}", path.to_string_lossy()),
+                )
+            )));
+        }
+
+        crate::get_canonical_path(path, false, false)
+            .map_err(|e| Error::Wrapped(Box::new(e)))
+    }
+
+    /// Resolves the import aliases at the start of the `identifiers` of a path used in the file at `source_unit_path`.
+    ///
+    /// File aliases (`X` in `X.Foo`) are removed and symbol aliases (`B` in `import { A as B }`) are replaced with the names they refer to.
+    /// Returns `None` if the path does not start with an import alias.
+    pub fn resolve_import_aliases(&self, source_unit_path: &Path, identifiers: &[solidity::Identifier]) -> Option<Vec<solidity::Identifier>> {
+        let mut path = source_unit_path.to_path_buf();
+        let mut identifiers = identifiers.to_vec();
+        let mut changed = false;
+
+        while let Some(alias) = identifiers.first().and_then(|i| self.import_aliases.get(&path)?.get(&i.name)) {
+            match alias {
+                ImportAlias::SourceUnit(alias_path) if identifiers.len() > 1 => {
+                    path = alias_path.clone();
+                    identifiers.remove(0);
+                }
+
+                ImportAlias::Symbol(name) => {
+                    identifiers[0].name = name.clone();
+                    changed = true;
+                    break;
+                }
+
+                _ => break,
+            }
+
+            changed = true;
+        }
+
+        if changed {
+            Some(identifiers)
+        } else {
+            None
+        }
+    }
+
+    /// Collects the paths of the file at `source_unit_path` and the files it imports, either directly or indirectly.
+    pub fn collect_visible_source_unit_paths<'a>(&'a self, source_unit_path: &'a Path) -> Vec<&'a Path> {
         let mut result = vec![];
-        
-        for translated_definition in self.translated_definitions.iter() {
-            if let Some(definition_name) = definition_name {
-                if translated_definition.name != *definition_name {
-                    continue;
+        let mut queue = vec![source_unit_path];
+
+        while let Some(current_path) = queue.pop() {
+            if result.contains(&current_path) {
+                continue;
+            }
+
+            result.push(current_path);
+
+            if let Some(import_directives) = self.import_directives.get(current_path) {
+                queue.extend(import_directives.keys().map(PathBuf::as_path));
+            }
+        }
+
+        result
+    }
+
+    /// Finds the translated definition named `name` which is visible from the file at `source_unit_path`.
+    ///
+    /// Definitions in the file itself are preferred over definitions in imported files.
+    pub fn find_translated_definition(&self, source_unit_path: &Path, name: &str) -> Option<&TranslatedDefinition> {
+        self.find_symbol(source_unit_path, SymbolKind::Definition, name, |_| true)
+    }
+
+    /// Gets the index of the translated definition named `name` which is visible from the file at `source_unit_path`.
+    pub fn find_translated_definition_index(&self, source_unit_path: &Path, name: &str) -> Option<usize> {
+        self.find_symbol_index(source_unit_path, SymbolKind::Definition, name, |_| true)
+    }
+
+    /// Finds the translated definition which declares the symbol of the supplied `kind` named `name`, is visible from the file at `source_unit_path` and matches `predicate`.
+    pub fn find_symbol<F: Fn(&TranslatedDefinition) -> bool>(&self, source_unit_path: &Path, kind: SymbolKind, name: &str, predicate: F) -> Option<&TranslatedDefinition> {
+        self.find_symbol_index(source_unit_path, kind, name, predicate)
+            .map(|index| &self.translated_definitions[index])
+    }
+
+    /// Finds a translated definition which holds the free function named `name` that is visible from the file at `source_unit_path`.
+    ///
+    /// Free functions are translated along with each definition in the file which declares them, so any of those definitions holds it.
+    pub fn find_free_function(&self, source_unit_path: &Path, name: &str) -> Option<&TranslatedDefinition> {
+        let solidity_source_units = self.solidity_source_units.read().unwrap();
+
+        self.find_symbol(source_unit_path, SymbolKind::Function, name, |d| {
+            solidity_source_units.get(&d.path).map(|source_unit| source_unit.0.iter().any(|source_unit_part| {
+                matches!(source_unit_part, solidity::SourceUnitPart::FunctionDefinition(f) if f.name.as_ref().map(|n| n.name == name).unwrap_or(false))
+            })).unwrap_or(false)
+        })
+    }
+
+    /// Gets the index of the translated definition which declares the symbol of the supplied `kind` named `name`, is visible from the file at `source_unit_path` and matches `predicate`.
+    ///
+    /// Definitions in the file itself are preferred, followed by the earliest translated definition in the files it imports.
+    pub fn find_symbol_index<F: Fn(&TranslatedDefinition) -> bool>(&self, source_unit_path: &Path, kind: SymbolKind, name: &str, predicate: F) -> Option<usize> {
+        let matches = |path: &Path| {
+            self.symbol_index.get(path, kind, name).iter()
+                .copied()
+                .filter(|&index| predicate(&self.translated_definitions[index]))
+                .min()
+        };
+
+        if let Some(index) = matches(source_unit_path) {
+            return Some(index);
+        }
+
+        self.collect_visible_source_unit_paths(source_unit_path).into_iter()
+            .skip(1)
+            .filter_map(matches)
+            .min()
+    }
+
+    /// Collects the definitions named in `root_names` along with the contracts, libraries and interfaces they depend on, either directly or indirectly.
+    ///
+    /// Root definitions are looked up in the files at `source_unit_paths`, and then in the files they import. Each definition is returned as the path of its file and its name.
+    pub fn collect_dependency_closure(&self, root_names: &[String], source_unit_paths: &[PathBuf]) -> Result<HashSet<(PathBuf, String)>, Error> {
+        let mut queue = vec![];
+
+        for root_name in root_names {
+            let mut roots = source_unit_paths.iter()
+                .flat_map(|path| self.symbol_index.get(path, SymbolKind::Definition, root_name))
+                .map(|&index| &self.translated_definitions[index])
+                .collect::<Vec<_>>();
+
+            // Fall back to the definitions imported by the files at `source_unit_paths`
+            if roots.is_empty() {
+                for path in source_unit_paths.iter() {
+                    let Some(root) = self.find_translated_definition(path, root_name) else { continue };
+
+                    if !roots.iter().any(|d| d.path == root.path && d.name == root.name) {
+                        roots.push(root);
+                    }
                 }
             }
 
-            if translated_definition.path == source_unit_path.as_ref() {
-                result.push(translated_definition.clone());
+            if roots.is_empty() {
+                return Err(Error::Wrapped(format!("Definition not found: {root_name}").into()));
+            }
+
+            queue.extend(roots.into_iter().map(|d| (d.path.clone(), d.name.clone())));
+        }
+
+        let mut result = HashSet::new();
+
+        while let Some((path, name)) = queue.pop() {
+            if !result.insert((path.clone(), name.clone())) {
+                continue;
+            }
+
+            for dependency in self.get_definition_dependencies(&path, &name) {
+                if dependency.path != path || dependency.name != name {
+                    queue.push((dependency.path.clone(), dependency.name.clone()));
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Gets the translated contracts, libraries and interfaces which the translated definition named `definition_name` in the file at `source_unit_path` depends on.
+    ///
+    /// These are the definitions imported by its file, along with the definitions it inherits, uses as libraries or calls through their ABI.
+    fn get_definition_dependencies(&self, source_unit_path: &Path, definition_name: &str) -> Vec<&TranslatedDefinition> {
+        let Some(translated_definition) = self.translated_definitions.iter().find(|d| d.path == source_unit_path && d.name == definition_name) else {
+            return vec![];
+        };
+
+        let mut dependencies: Vec<&TranslatedDefinition> = vec![];
+
+        // Add each definition in the files imported by the definition's file, limited to the imported names
+        if let Some(import_directives) = self.import_directives.get(source_unit_path) {
+            for (import_path, import_names) in import_directives.iter() {
+                for &index in self.symbol_index.get_definitions(import_path) {
+                    let imported_definition = &self.translated_definitions[index];
+
+                    if imported_definition.kind.is_none() {
+                        continue;
+                    }
+
+                    if import_names.as_ref().map(|names| !names.contains(&imported_definition.name)).unwrap_or(false) {
+                        continue;
+                    }
+
+                    dependencies.push(imported_definition);
+                }
+            }
+        }
+
+        let names = translated_definition.inherits.iter()
+            .chain(translated_definition.using_directives.iter().map(|using_directive| &using_directive.library_name))
+            .chain(translated_definition.abis.iter().map(|abi| &abi.name));
+
+        for name in names {
+            if let Some(dependency) = self.find_symbol(source_unit_path, SymbolKind::Definition, name, |d| d.kind.is_some()) {
+                dependencies.push(dependency);
+            }
+        }
+
+        let mut result: Vec<&TranslatedDefinition> = vec![];
+
+        for dependency in dependencies {
+            if !result.iter().any(|d| d.path == dependency.path && d.name == dependency.name) {
+                result.push(dependency);
             }
         }
 
         result
     }
 
-    pub fn find_definition_with_abi(&self, name: &str) -> Option<&TranslatedDefinition> {
-        if let Some(external_definition) = self.translated_definitions.iter().find(|d| d.name == name && d.abi.is_some()) {
-            return Some(external_definition);
+    /// Collects the toplevel constants of the files imported by the file at `source_unit_path`, either directly or indirectly.
+    ///
+    /// Each constant is returned along with the path of the file it was declared in.
+    pub fn collect_imported_toplevel_constants(&self, source_unit_path: &Path) -> Vec<(PathBuf, solidity::VariableDefinition)> {
+        let mut result: Vec<(PathBuf, solidity::VariableDefinition)> = vec![];
+        let mut queue: Vec<(PathBuf, Option<Vec<String>>)> = vec![(source_unit_path.to_path_buf(), None)];
+        let mut visited = HashSet::new();
+
+        while let Some((path, names)) = queue.pop() {
+            if !visited.insert((path.clone(), names.clone())) {
+                continue;
+            }
+
+            if path != source_unit_path {
+                if let Some(source_unit) = self.solidity_source_units.read().unwrap().get(&path) {
+                    for source_unit_part in source_unit.0.iter() {
+                        let solidity::SourceUnitPart::VariableDefinition(variable_definition) = source_unit_part else { continue };
+
+                        if !variable_definition.attrs.iter().any(|x| matches!(x, solidity::VariableAttribute::Constant(_))) {
+                            continue;
+                        }
+
+                        let Some(name) = variable_definition.name.as_ref().map(|n| &n.name) else { continue };
+
+                        // Named imports only import the constants they name
+                        if names.as_ref().map(|names| !names.contains(name)).unwrap_or(false) {
+                            continue;
+                        }
+
+                        if !result.iter().any(|(p, v)| *p == path && v.name == variable_definition.name) {
+                            result.push((path.clone(), variable_definition.as_ref().clone()));
+                        }
+                    }
+                }
+            }
+
+            let Some(import_directives) = self.import_directives.get(&path) else { continue };
+
+            for (import_path, import_names) in import_directives.iter() {
+                let import_names = match (names.as_ref(), import_names.as_ref()) {
+                    (None, import_names) => import_names.cloned(),
+                    (Some(names), None) => Some(names.clone()),
+                    (Some(names), Some(import_names)) => Some(names.iter().filter(|n| import_names.contains(n)).cloned().collect()),
+                };
+
+                if import_names.as_ref().map(|n| n.is_empty()).unwrap_or(false) {
+                    continue;
+                }
+
+                queue.push((import_path.clone(), import_names));
+            }
         }
 
-        None
+        result
+    }
+
+    pub fn find_definition_with_abi(&self, source_unit_path: &Path, name: &str) -> Option<&TranslatedDefinition> {
+        self.find_symbol(source_unit_path, SymbolKind::Definition, name, |d| d.abi.is_some())
     }
 
     pub fn translate(&mut self, definition_name: Option<&String>, source_unit_path: &Path) -> Result<(), Error> {
@@ -147,12 +904,12 @@ impl Project {
         let solidity_source_units = self.solidity_source_units.clone();
 
         // Ensure the source unit has been parsed
-        if !solidity_source_units.borrow().contains_key(source_unit_path) {
+        if !solidity_source_units.read().unwrap().contains_key(source_unit_path) {
             self.parse_solidity_source_unit(source_unit_path)?;
         }
         
         // Get the parsed source unit
-        let source_unit = solidity_source_units.borrow().get(source_unit_path).unwrap().clone();
+        let source_unit = solidity_source_units.read().unwrap().get(source_unit_path).unwrap().clone();
 
         // Collect toplevel items ahead of time for contextual reasons
         let mut import_directives = vec![];
@@ -163,12 +920,18 @@ impl Project {
         let mut toplevel_events = vec![];
         let mut toplevel_errors = vec![];
         let mut toplevel_functions = vec![];
+        let mut toplevel_constants = vec![];
         let mut contract_names = vec![];
+        let mut version_requirements = vec![];
 
         for source_unit_part in source_unit.0.iter() {
             match source_unit_part {
+                solidity::SourceUnitPart::PragmaDirective(_, Some(name), Some(value)) if name.name == "solidity" => {
+                    version_requirements.push(value.string.clone());
+                }
+
                 solidity::SourceUnitPart::PragmaDirective(_, _, _) => {
-                    // NOTE: we don't need to do anything with pragma directives
+                    // NOTE: we don't need to do anything with other pragma directives
                 }
 
                 solidity::SourceUnitPart::ImportDirective(import_directive) => {
@@ -198,7 +961,14 @@ impl Project {
                 solidity::SourceUnitPart::FunctionDefinition(function_definition) => {
                     toplevel_functions.push(function_definition.as_ref().clone());
                 },
-                solidity::SourceUnitPart::VariableDefinition(_) => todo!("toplevel variable definition"),
+                solidity::SourceUnitPart::VariableDefinition(variable_definition) => {
+                    // NOTE: only constants can be declared at the file level
+                    if variable_definition.attrs.iter().any(|x| matches!(x, solidity::VariableAttribute::Constant(_))) {
+                        toplevel_constants.push(variable_definition.as_ref().clone());
+                    } else {
+                        self.report_error(source_unit_path, &Error::UnsupportedConstruct(variable_definition.loc, "toplevel variable definition which is not constant".into()));
+                    }
+                }
 
                 solidity::SourceUnitPart::TypeDefinition(type_definition) => {
                     toplevel_type_definitions.push(type_definition.as_ref().clone());
@@ -220,62 +990,96 @@ impl Project {
 
         // Extend the import directive tree
         for import_directive in import_directives.iter() {
-            let mut translate_import_directive = |definition_name: Option<&String>, filename: &solidity::StringLiteral| -> Result<(), Error> {
-                let mut import_path = PathBuf::from(filename.string.clone());
+            let (import_path, definition_names) = match import_directive {
+                solidity::Import::Plain(import_path, _) => (import_path, None),
 
-                if !import_path.to_string_lossy().starts_with('.') {
-                    import_path = self.get_project_type_path(source_unit_directory.as_path(), filename.string.as_str())?;
-                } else {
-                    import_path = source_unit_directory.join(import_path);
-                }
-                
-                import_path = crate::get_canonical_path(import_path, false, false)
-                    .map_err(|e| Error::Wrapped(Box::new(e))).unwrap();
-                
-                let import_directives = self.import_directives.entry(source_unit_path.into()).or_default();
-                let definition_names = import_directives.entry(import_path).or_default();
+                solidity::Import::GlobalSymbol(import_path, identifier, _) => {
+                    let import_path = match self.resolve_import_path(source_unit_directory.as_path(), import_path) {
+                        Ok(import_path) => import_path,
 
-                if let Some(definition_name) = definition_name {
-                    if definition_names.is_none() {
-                        *definition_names = Some(vec![]);
-                    }
+                        Err(error) => {
+                            self.report_error(source_unit_path, &Error::UnresolvedReference(import_directive.loc(), error.message()));
+                            continue;
+                        }
+                    };
+
+                    self.import_aliases.entry(source_unit_path.into()).or_default()
+                        .insert(identifier.name.clone(), ImportAlias::SourceUnit(import_path.clone()));
+
+                    // Everything in the file is reachable through its alias
+                    self.import_directives.entry(source_unit_path.into()).or_default()
+                        .insert(import_path, None);
+
+                    continue;
+                }
 
-                    let definition_names = definition_names.as_mut().unwrap();
+                solidity::Import::Rename(import_path, identifiers, _) => {
+                    for (identifier, alias) in identifiers.iter() {
+                        let Some(alias) = alias.as_ref() else { continue };
 
-                    if !definition_names.contains(definition_name) {
-                        definition_names.push(definition_name.clone());
+                        self.import_aliases.entry(source_unit_path.into()).or_default()
+                            .insert(alias.name.clone(), ImportAlias::Symbol(identifier.name.clone()));
                     }
+
+                    (import_path, Some(identifiers.iter().map(|(i, _)| i.name.clone()).collect::<Vec<_>>()))
                 }
+            };
+
+            let import_path = match self.resolve_import_path(source_unit_directory.as_path(), import_path) {
+                Ok(import_path) => import_path,
 
-                Ok(())
+                Err(error) => {
+                    self.report_error(source_unit_path, &Error::UnresolvedReference(import_directive.loc(), error.message()));
+                    continue;
+                }
             };
 
-            match import_directive {
-                solidity::Import::Plain(solidity::ImportPath::Filename(filename), _) => {
-                    translate_import_directive(None, filename)?;
+            let import_directives = self.import_directives.entry(source_unit_path.into()).or_default();
+
+            // Importing a file without naming any symbols imports all of them, even if it is also imported by name
+            match (import_directives.get_mut(&import_path), definition_names) {
+                (None, definition_names) => {
+                    import_directives.insert(import_path, definition_names);
                 }
 
-                solidity::Import::Rename(solidity::ImportPath::Filename(filename), identifiers, _) => {
-                    for (identifier, _) in identifiers.iter() {
-                        translate_import_directive(Some(&identifier.name), filename)?;
-                    }
+                (Some(imported_definition_names), None) => {
+                    *imported_definition_names = None;
                 }
 
-                _ => panic!("Unsupported import directive: {import_directive:#?}"),
+                (Some(None), Some(_)) => {}
+
+                (Some(Some(imported_definition_names)), Some(definition_names)) => {
+                    for definition_name in definition_names {
+                        if !imported_definition_names.contains(&definition_name) {
+                            imported_definition_names.push(definition_name);
+                        }
+                    }
+                }
             }
         }
 
+        // Solidity didn't check arithmetic for overflow before 0.8, so raw operators wrap even when the file relies on SafeMath for its checks
+        let unchecked = version_requirements.iter().any(|r| !allows_checked_arithmetic(r));
+
         // Translate any contract definitions in the file
         for source_unit_part in source_unit.0.iter() {
             let solidity::SourceUnitPart::ContractDefinition(contract_definition) = source_unit_part else { continue };
 
+            let contract_name = &contract_definition.name.as_ref().unwrap().name;
+
             if let Some(definition_name) = definition_name {
-                if contract_definition.name.as_ref().unwrap().name != *definition_name {
+                if contract_name != definition_name {
                     continue;
                 }
             }
 
-            translate_contract_definition(
+            // Skip contracts which have already been translated (or attempted) by this project
+            if !self.visited_definitions.insert((source_unit_path.to_path_buf(), contract_name.clone())) {
+                continue;
+            }
+
+            // Record the error and move on to the next contract if the contract fails to translate
+            if let Err(error) = translate_contract_definition(
                 self,
                 source_unit_path,
                 import_directives.as_slice(),
@@ -286,9 +1090,13 @@ impl Project {
                 toplevel_events.as_slice(),
                 toplevel_errors.as_slice(),
                 toplevel_functions.as_slice(),
+                toplevel_constants.as_slice(),
                 contract_names.as_slice(),
+                unchecked,
                 contract_definition,
-            )?;
+            ) {
+                self.report_error(source_unit_path, &error.with_loc(&contract_definition.loc));
+            }
         }
 
         Ok(())
@@ -299,7 +1107,7 @@ impl Project {
         let path = path.as_ref();
         if path.join(ProjectType::FOUNDRY_CONFIG_FILE).exists() {
             self.project_type = ProjectType::Foundry {
-                remappings: HashMap::new(),
+                remappings: vec![],
             };
 
             let remappings = self.get_remappings(path).map_err(|e| Error::Wrapped(
@@ -310,7 +1118,7 @@ impl Project {
         } else if path.join(ProjectType::HARDHAT_CONFIG_FILE).exists() {
             self.project_type = ProjectType::Hardhat;
         } else if path.join(ProjectType::BROWNIE_CONFIG_FILE).exists() {
-            self.project_type = ProjectType::Brownie { remappings: HashMap::new() };
+            self.project_type = ProjectType::Brownie { remappings: vec![] };
 
             self.project_type = ProjectType::Brownie { remappings: self.get_remappings(path).map_err(|e| Error::Wrapped(
                 format!("Failed to get remappings for Brownie project: {:#?}", e).into()
@@ -328,6 +1136,27 @@ impl Project {
 
     /// Get the project type path from the [ProjectType] and return a [PathBuf]
     pub fn get_project_type_path(&self, source_unit_directory: &Path, filename: &str) -> Result<PathBuf, Error> {
+        // Standard JSON source unit names are resolved relative to the base path instead of a project root
+        if let ProjectType::StandardJson { base_path, remappings } = &self.project_type {
+            let context = match source_unit_directory.strip_prefix(base_path) {
+                Ok(path) if path.as_os_str().is_empty() => String::new(),
+                Ok(path) => format!("{}/", path.to_string_lossy()),
+                Err(_) => String::new(),
+            };
+
+            return match Remapping::find(remappings, &context, filename) {
+                Some(remapping) => Ok(normalize_path(base_path.join(format!("{}{}", remapping.target, &filename[remapping.prefix.len()..])))),
+                None => Ok(normalize_path(base_path.join(filename))),
+            };
+        }
+
+        // Node packages are resolved relative to the importing file, which may be outside of the project root when packages are hoisted or symlinked
+        if matches!(self.project_type, ProjectType::Hardhat | ProjectType::Truffle) && !filename.starts_with('.') {
+            if let Some(path) = find_node_module(source_unit_directory, filename) {
+                return Ok(path);
+            }
+        }
+
         let project_root_folder = find_project_root_folder(source_unit_directory);
 
         let Some(project_root_folder) = project_root_folder else {
@@ -338,14 +1167,19 @@ impl Project {
         match &self.project_type {
             // Remappings in foundry and brownie are handled using the same pattern
             ProjectType::Foundry { remappings } | ProjectType::Brownie { remappings } => {
-                for (k, v) in remappings {
-                    if filename.starts_with(k) {
-                        let project_full_path = project_root_folder.join(v);
-                        return Ok(PathBuf::from(filename.replace(k, project_full_path.to_string_lossy().as_ref())))
-                    }
+                // Contexts are matched against the directory of the importing file relative to the project root
+                let context = match source_unit_directory.strip_prefix(&project_root_folder) {
+                    Ok(path) if path.as_os_str().is_empty() => String::new(),
+                    Ok(path) => format!("{}/", path.to_string_lossy()),
+                    Err(_) => String::new(),
+                };
+
+                if let Some(remapping) = Remapping::find(remappings, &context, filename) {
+                    let remapped = format!("{}{}", remapping.target, &filename[remapping.prefix.len()..]);
+                    return Ok(project_root_folder.join(remapped));
                 }
 
-                Ok(PathBuf::from(source_unit_directory.join(filename)))
+                Ok(source_unit_directory.join(filename))
             }
 
             // Remappings in hardhat and truffle are done using the @ symbol and the node_modules folder
@@ -362,13 +1196,13 @@ impl Project {
                 let mut components: Vec<_> = filename.components().collect();
                 
                 if components.len() <= 1 {
-                    panic!("Dapp filename should have more than one component")
+                    return Err(Error::Wrapped("Dapp filename should have more than one component".into()));
                 }
 
                 match &components[0] {
                     Component::Normal(_) => {
                         components.insert(1, Component::Normal("src".as_ref()));
-                        let component = PathBuf::from(components.iter().map(|c| c.as_os_str()).collect::<PathBuf>());
+                        let component = components.iter().map(|c| c.as_os_str()).collect::<PathBuf>();
                         Ok(project_root_folder.join("lib").join(component))
                     }
 
@@ -379,50 +1213,95 @@ impl Project {
             }
 
             // If we find that the project type is unknown we return the filename as is
-            ProjectType::Unknown => Ok(PathBuf::from(filename)),
+            ProjectType::StandardJson { .. } | ProjectType::Unknown => Ok(PathBuf::from(filename)),
         }
     }
 
     /// Get the re mappings from the re mappings file on the root folder of the project represented by the [PathBuf]
-    fn get_remappings(&self, root_folder_path: &Path) -> Result<HashMap<String, String>, Error> {
+    fn get_remappings(&self, root_folder_path: &Path) -> Result<Vec<Remapping>, Error> {
         match &self.project_type {
             ProjectType::Foundry { .. } => {
-                let remappings_filename = "remappings.txt";
-                
-                let lines: Vec<String> = if root_folder_path.join(remappings_filename).exists() {
-                    // Get the remappings.txt file from the root of the project folder
-                    let remappings_content = std::fs::read_to_string(root_folder_path.join(remappings_filename))
-                        .map_err(|e| Error::Wrapped(e.into()))?;
+                // Get foundry toml file from the root of the project folder
+                let config_str = std::fs::read_to_string(root_folder_path.join(ProjectType::FOUNDRY_CONFIG_FILE))
+                    .map_err(|e| Error::Wrapped(e.into()))?;
 
-                    remappings_content.lines().map(str::to_string).collect()
-                } else {
-                    // Get foundry toml file from the root of the project folder
-                    let remappings_from_toml_str = std::fs::read_to_string(root_folder_path.join(ProjectType::FOUNDRY_CONFIG_FILE))
-                        .map_err(|e| Error::Wrapped(e.into()))?;
-        
-                    let remappings_from_toml: toml::Value = toml::from_str(&remappings_from_toml_str)
-                        .map_err(|e| Error::Wrapped(e.into()))?;
+                let config: toml::Value = toml::from_str(&config_str)
+                    .map_err(|e| Error::Wrapped(e.into()))?;
 
-                    let value = find_in_toml_value(&remappings_from_toml, remappings_filename.strip_suffix(".txt").unwrap());
+                let mut remappings: Vec<Remapping> = vec![];
 
-                    let Some(value) = value.as_ref() else { return Ok(HashMap::new()) };
+                // Remappings declared in foundry.toml take precedence over the ones declared in remappings.txt
+                let mut add_remapping = |remapping: Remapping| {
+                    if !remappings.iter().any(|r| r.context == remapping.context && r.prefix == remapping.prefix) {
+                        remappings.push(remapping);
+                    }
+                };
 
+                if let Some(value) = find_foundry_config_value(&config, "remappings") {
                     let toml::Value::Array(arr) = value else {
-                        panic!("remappings key in foundry.toml should be an array")
+                        return Err(Error::Wrapped("remappings key in foundry.toml should be an array".into()));
                     };
 
-                    arr.iter().map(|x| x.as_str().unwrap().to_string()).collect::<Vec<_>>()
+                    for x in arr.iter() {
+                        let Some(x) = x.as_str() else {
+                            return Err(Error::Wrapped("remappings in foundry.toml should be strings".into()));
+                        };
+
+                        add_remapping(Remapping::parse(x)?);
+                    }
+                }
+
+                // Get the remappings.txt file from the root of the project folder
+                let remappings_path = root_folder_path.join("remappings.txt");
+
+                if remappings_path.exists() {
+                    let remappings_content = std::fs::read_to_string(remappings_path)
+                        .map_err(|e| Error::Wrapped(e.into()))?;
+
+                    for line in remappings_content.lines() {
+                        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                            continue;
+                        }
+
+                        add_remapping(Remapping::parse(line)?);
+                    }
+                }
+
+                // Generate remappings for any libraries in the library folders which were not remapped explicitly
+                let library_folders = match find_foundry_config_value(&config, "libs") {
+                    Some(toml::Value::Array(arr)) => arr.iter().filter_map(|x| x.as_str().map(str::to_string)).collect(),
+                    _ => vec!["lib".to_string()],
                 };
 
-                let mut remappings = HashMap::new();
+                for library_folder in library_folders {
+                    let Ok(entries) = std::fs::read_dir(root_folder_path.join(&library_folder)) else { continue };
+
+                    let mut library_paths = entries.filter_map(|e| e.ok().map(|e| e.path()))
+                        .filter(|p| p.is_dir())
+                        .collect::<Vec<_>>();
+
+                    library_paths.sort();
+
+                    for library_path in library_paths {
+                        let Some(library_name) = library_path.file_name().map(|n| n.to_string_lossy().to_string()) else { continue };
 
-                for line in lines {
-                    let mut split = line.split('=');
-                    let from = split.next().unwrap().to_string();
-                    let to = split.next().unwrap().to_string();
-                    remappings.insert(from, to);
+                        let prefix = format!("{library_name}/");
+
+                        if remappings.iter().any(|r| r.prefix == prefix) {
+                            continue;
+                        }
+
+                        // Libraries usually keep their sources in a `src` folder
+                        let target = if library_path.join("src").is_dir() {
+                            format!("{library_folder}/{library_name}/src/")
+                        } else {
+                            format!("{library_folder}/{library_name}/")
+                        };
+
+                        remappings.push(Remapping { context: None, prefix, target });
+                    }
                 }
-                
+
                 Ok(remappings)
             }
 
@@ -449,19 +1328,15 @@ impl Project {
                     return Err(Error::Wrapped("solidity.remappings should be a sequence".into()));
                 };
                     
-                let mut remappings = HashMap::new();
-                
-                for v in seq {
-                    let mut split = v.as_str().unwrap().split('=');
-                    let from = split.next().unwrap().to_string();
-                    let to = split.next().unwrap().to_string();
-                    remappings.insert(from, to);
-                }
-
-                Ok(remappings)
+                seq.iter()
+                    .map(|v| match v.as_str() {
+                        Some(v) => Remapping::parse(v),
+                        None => Err(Error::Wrapped("solidity.remappings should be a sequence of strings".into())),
+                    })
+                    .collect()
             }
 
-            _ => Ok(HashMap::new())
+            _ => Ok(vec![])
         }
     }
 }
@@ -483,20 +1358,111 @@ pub fn find_project_root_folder<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
     None
 }
 
-/// Find a key in a [toml::Table] and return the [toml::Value]
-fn find_in_toml_value(value: &toml::Value, key: &str) -> Option<toml::Value> {
-    match value {
-        toml::Value::Table(table) => {
-            for (k, v) in table {
-                if k == key {
-                    return Some(v.clone());
-                }
-                if let Some(val) = find_in_toml_value(v, key) {
-                    return Some(val);
+/// Lexically removes `.` and `..` components from `path` without accessing the disk.
+pub fn normalize_path<P: AsRef<Path>>(path: P) -> PathBuf {
+    let mut result = PathBuf::new();
+
+    for component in path.as_ref().components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !result.pop() {
+                    result.push(component);
                 }
             }
-            None
-        },
-        _ => None,
+            _ => result.push(component),
+        }
     }
+
+    result
+}
+
+/// Finds `filename` in the closest `node_modules` folder of `directory` or any of its parents, like Node does.
+///
+/// Symlinked packages (i.e: pnpm or yarn workspaces) are followed when the returned path is canonicalized.
+pub fn find_node_module<P: AsRef<Path>>(directory: P, filename: &str) -> Option<PathBuf> {
+    for directory in directory.as_ref().ancestors() {
+        // Node never looks for `node_modules/node_modules`
+        if directory.file_name().map(|name| name == "node_modules").unwrap_or(false) {
+            continue;
+        }
+
+        let path = directory.join("node_modules").join(filename);
+
+        if path.exists() {
+            return Some(path);
+        }
+    }
+
+    None
+}
+
+/// Gets the text of the `comment` without its delimiters or leading asterisks.
+pub fn get_comment_text(comment: &solidity::Comment) -> String {
+    let text = match comment {
+        solidity::Comment::Line(_, text) => text.trim_start_matches("//"),
+        solidity::Comment::DocLine(_, text) => text.trim_start_matches("///"),
+        solidity::Comment::Block(_, text) => text.trim_start_matches("/*").trim_end_matches("*/"),
+        solidity::Comment::DocBlock(_, text) => text.trim_start_matches("/**").trim_end_matches("*/"),
+    };
+
+    let lines = text.lines()
+        .map(|line| {
+            let line = line.trim();
+            let line = if matches!(comment, solidity::Comment::Block(..) | solidity::Comment::DocBlock(..)) {
+                line.trim_start_matches('*')
+            } else {
+                line
+            };
+            line.strip_prefix(' ').unwrap_or(line).trim_end()
+        })
+        .collect::<Vec<_>>();
+
+    // Remove any leading and trailing empty lines
+    let start = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|line| !line.is_empty()).map(|i| i + 1).unwrap_or(start);
+
+    lines[start..end].join("\n")
+}
+
+/// Checks if a `pragma solidity` version requirement allows a compiler version of 0.8 or later, which checks arithmetic for overflow.
+fn allows_checked_arithmetic(version_requirement: &str) -> bool {
+    version_requirement.split("||").any(|range| {
+        // Attach operators which are separated from their versions, i.e. `>= 0.6.0`
+        let mut comparators: Vec<String> = vec![];
+
+        for part in range.split_whitespace() {
+            match comparators.last_mut() {
+                Some(comparator) if comparator.chars().all(|c| "^~=<>".contains(c)) => comparator.push_str(part),
+                _ => comparators.push(part.to_string()),
+            }
+        }
+
+        // The range only allows older versions if one of its comparators has an upper bound below 0.8
+        !comparators.iter().any(|comparator| {
+            let version = comparator.trim_start_matches(['^', '~', '=', '<', '>']);
+            let operator = &comparator[..comparator.len() - version.len()];
+
+            let parts = version.split('.').map(|p| p.parse::<u64>().ok()).collect::<Vec<_>>();
+
+            let Some(Some(major)) = parts.first().copied() else { return false };
+            let minor = parts.get(1).copied().flatten().unwrap_or(0);
+            let patch = parts.get(2).copied().flatten().unwrap_or(0);
+
+            match operator {
+                "<" => (major, minor, patch) <= (0, 8, 0),
+                "" | "=" | "<=" | "^" | "~" => (major, minor) < (0, 8),
+                _ => false,
+            }
+        })
+    })
+}
+
+/// Find a key in the active profile of a foundry.toml [toml::Value], falling back to the default profile
+fn find_foundry_config_value<'a>(config: &'a toml::Value, key: &str) -> Option<&'a toml::Value> {
+    let profile = std::env::var("FOUNDRY_PROFILE").unwrap_or_else(|_| "default".to_string());
+    let profiles = config.get("profile")?;
+
+    profiles.get(profile.as_str()).and_then(|p| p.get(key))
+        .or_else(|| profiles.get("default").and_then(|p| p.get(key)))
 }
@@ -0,0 +1,92 @@
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportEntryKind {
+    /// A `todo!()` expression was emitted in place of the translated code.
+    Todo,
+    /// An `unimplemented!()` expression was emitted in place of the translated code.
+    Unimplemented,
+    /// An `unsupported:` comment was emitted in place of the translated code.
+    Unsupported,
+}
+
+/// A single piece of Solidity code which could not be fully translated.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ReportEntry {
+    pub kind: ReportEntryKind,
+    pub path: PathBuf,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub contract_name: Option<String>,
+    pub function_name: Option<String>,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct FunctionReport {
+    pub name: String,
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ContractReport {
+    pub path: PathBuf,
+    pub name: String,
+    pub count: usize,
+    pub functions: Vec<FunctionReport>,
+}
+
+/// A summary of the Solidity code which could not be fully translated.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub count: usize,
+    pub contracts: Vec<ContractReport>,
+    pub entries: Vec<ReportEntry>,
+}
+
+impl Report {
+    /// Creates a report from the supplied `entries`, counting them per contract and per function.
+    pub fn new(entries: &[ReportEntry]) -> Self {
+        let mut contracts: Vec<ContractReport> = vec![];
+
+        for entry in entries.iter() {
+            let Some(contract_name) = entry.contract_name.as_ref() else { continue };
+
+            let contract = match contracts.iter_mut().position(|c| c.path == entry.path && c.name == *contract_name) {
+                Some(index) => &mut contracts[index],
+
+                None => {
+                    contracts.push(ContractReport {
+                        path: entry.path.clone(),
+                        name: contract_name.clone(),
+                        count: 0,
+                        functions: vec![],
+                    });
+
+                    contracts.last_mut().unwrap()
+                }
+            };
+
+            contract.count += 1;
+
+            let Some(function_name) = entry.function_name.as_ref() else { continue };
+
+            match contract.functions.iter_mut().find(|f| f.name == *function_name) {
+                Some(function) => function.count += 1,
+
+                None => contract.functions.push(FunctionReport {
+                    name: function_name.clone(),
+                    count: 1,
+                }),
+            }
+        }
+
+        Self {
+            count: entries.len(),
+            contracts,
+            entries: entries.to_vec(),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use crate::sway::{TabbedDisplay, TabbedDisplayer};
+use serde::Serialize;
+use std::{cell::RefCell, fmt::Write, path::PathBuf};
+
+/// The location of the Solidity code that a Sway node was translated from.
+///
+/// Lines and columns start at 1, and the end column is exclusive.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SourceLocation {
+    pub path: PathBuf,
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+/// A range of generated Sway lines and the Solidity code they were translated from.
+#[derive(Clone, Debug, Serialize)]
+pub struct SourceMapEntry {
+    pub start_line: usize,
+    pub end_line: usize,
+    pub solidity: SourceLocation,
+}
+
+/// Links the lines of a generated Sway file back to the Solidity code they were translated from.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct SourceMap {
+    pub entries: Vec<SourceMapEntry>,
+}
+
+impl SourceMap {
+    /// Gets the innermost Solidity location that the generated Sway `line` was translated from.
+    pub fn find(&self, line: usize) -> Option<&SourceLocation> {
+        self.entries.iter()
+            .filter(|e| e.start_line <= line && line <= e.end_line)
+            .min_by_key(|e| e.end_line - e.start_line)
+            .map(|e| &e.solidity)
+    }
+}
+
+struct SourceMapRecorder {
+    line: usize,
+    entries: Vec<SourceMapEntry>,
+}
+
+thread_local! {
+    static SOURCE_MAP_RECORDER: RefCell<Option<SourceMapRecorder>> = const { RefCell::new(None) };
+}
+
+/// Keeps track of the current line of the generated Sway code while it is being displayed.
+struct LineCountingWriter(String);
+
+impl Write for LineCountingWriter {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.0.push_str(s);
+
+        SOURCE_MAP_RECORDER.with_borrow_mut(|recorder| {
+            if let Some(recorder) = recorder.as_mut() {
+                recorder.line += s.matches('\n').count();
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Displays `value` and records the lines it spans with `location` if a source map is being generated.
+pub(crate) fn record<F: FnOnce() -> std::fmt::Result>(location: Option<&SourceLocation>, display: F) -> std::fmt::Result {
+    let Some(location) = location else { return display() };
+
+    let start_line = SOURCE_MAP_RECORDER.with_borrow(|recorder| recorder.as_ref().map(|r| r.line));
+
+    display()?;
+
+    if let Some(start_line) = start_line {
+        SOURCE_MAP_RECORDER.with_borrow_mut(|recorder| {
+            if let Some(recorder) = recorder.as_mut() {
+                recorder.entries.push(SourceMapEntry {
+                    start_line,
+                    end_line: recorder.line,
+                    solidity: location.clone(),
+                });
+            }
+        });
+    }
+
+    Ok(())
+}
+
+/// Displays `value` as Sway code along with a source map of the lines that were translated from Solidity code.
+pub fn display_with_source_map<T: TabbedDisplay>(value: &T) -> (String, SourceMap) {
+    SOURCE_MAP_RECORDER.with_borrow_mut(|recorder| {
+        *recorder = Some(SourceMapRecorder {
+            line: 1,
+            entries: vec![],
+        });
+    });
+
+    let mut writer = LineCountingWriter(String::new());
+    let result = write!(writer, "{}", TabbedDisplayer(value));
+
+    let mut entries = SOURCE_MAP_RECORDER.with_borrow_mut(|recorder| recorder.take())
+        .map(|recorder| recorder.entries)
+        .unwrap_or_default();
+
+    // Displaying to a string never fails unless a node's implementation does
+    result.expect("Failed to display Sway code");
+
+    entries.sort_by_key(|e| (e.start_line, usize::MAX - e.end_line));
+
+    (writer.0, SourceMap { entries })
+}
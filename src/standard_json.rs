@@ -0,0 +1,83 @@
+use crate::{errors::Error, project::Remapping};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// The input of `solc --standard-json`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct StandardJsonInput {
+    pub language: String,
+    /// The source units keyed by their source unit names.
+    pub sources: BTreeMap<String, StandardJsonSource>,
+    #[serde(default)]
+    pub settings: StandardJsonSettings,
+}
+
+/// A source unit in the `sources` object of a [StandardJsonInput].
+#[derive(Clone, Debug, Deserialize)]
+pub struct StandardJsonSource {
+    /// The inline contents of the source unit.
+    pub content: Option<String>,
+    /// The URLs the contents of the source unit can be loaded from when they are not inline.
+    #[serde(default)]
+    pub urls: Vec<String>,
+}
+
+/// The `settings` object of a [StandardJsonInput]. Only the settings which affect import resolution are kept.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct StandardJsonSettings {
+    #[serde(default)]
+    pub remappings: Vec<String>,
+}
+
+impl StandardJsonInput {
+    /// Reads the standard JSON input from the file at `path`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let input = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        let input: Self = serde_json::from_str(&input)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+        if input.language != "Solidity" {
+            return Err(Error::Wrapped(format!("Unsupported standard JSON input language: {}", input.language).into()));
+        }
+
+        Ok(input)
+    }
+
+    /// Parses the remappings in the `settings` object.
+    pub fn remappings(&self) -> Result<Vec<Remapping>, Error> {
+        self.settings.remappings.iter().map(|x| Remapping::parse(x)).collect()
+    }
+
+    /// Gets the contents of each source unit along with its source unit name.
+    ///
+    /// Sources without inline `content` are read from the first of their `urls` which is a local file, relative to `base_path`.
+    pub fn source_contents(&self, base_path: &Path) -> Result<Vec<(String, String)>, Error> {
+        let mut result = vec![];
+
+        for (source_unit_name, source) in self.sources.iter() {
+            if let Some(content) = source.content.as_ref() {
+                result.push((source_unit_name.clone(), content.clone()));
+                continue;
+            }
+
+            let Some(url) = source.urls.iter()
+                .map(|url| base_path.join(url.trim_start_matches("file://")))
+                .find(|path: &PathBuf| path.is_file())
+            else {
+                return Err(Error::Wrapped(format!("Failed to load standard JSON source: {source_unit_name}").into()));
+            };
+
+            let content = std::fs::read_to_string(url)
+                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+
+            result.push((source_unit_name.clone(), content));
+        }
+
+        Ok(result)
+    }
+}
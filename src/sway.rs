@@ -1,3 +1,4 @@
+use crate::source_map::{self, SourceLocation};
 use num_bigint::BigUint;
 use std::fmt::Display;
 
@@ -24,6 +25,23 @@ impl<T: TabbedDisplay> Display for TabbedDisplayer<'_, T> {
     }
 }
 
+/// Writes each line of `doc_comment` as a `///` doc comment, followed by the indentation of the item it documents.
+fn write_doc_comment(doc_comment: Option<&String>, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Some(doc_comment) = doc_comment else { return Ok(()) };
+
+    for line in doc_comment.lines() {
+        if line.is_empty() {
+            writeln!(f, "///")?;
+        } else {
+            writeln!(f, "/// {line}")?;
+        }
+
+        "".tabbed_fmt(depth, f)?;
+    }
+
+    Ok(())
+}
+
 // -------------------------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(Clone, Debug, PartialEq)]
@@ -68,6 +86,7 @@ impl Module {
             abi.name == abi_name
         }) {
             self.items.push(ModuleItem::Abi(Abi {
+                doc_comment: None,
                 name: abi_name.into(),
                 inherits: vec![],
                 functions: vec![],
@@ -369,10 +388,7 @@ impl Display for TypeName {
 impl TypeName {
     /// Checks if the type name is an unsigned integer type
     pub fn is_uint(&self) -> bool {
-        match self {
-            TypeName::Identifier { name, generic_parameters: None } => matches!(name.as_str(), "u8" | "u16" | "u32" | "u64" | "u256" | "U128" | "U256"),
-            _ => false,
-        }
+        self.uint_bits().is_some()
     }
 
     /// Checks if the type name is a signed integer type
@@ -383,6 +399,60 @@ impl TypeName {
         }
     }
 
+    /// Gets the bit width of the type name if it is an unsigned integer type.
+    /// This includes the type definitions of the widths which Sway doesn't have, i.e. `u24`.
+    pub fn uint_bits(&self) -> Option<usize> {
+        match self {
+            TypeName::Identifier { name, generic_parameters: None } => match name.as_str() {
+                "U128" => Some(128),
+                "U256" => Some(256),
+                _ => name.strip_prefix('u')
+                    .and_then(|bits| bits.parse::<usize>().ok())
+                    .filter(|bits| bits % 8 == 0 && (8..=256).contains(bits)),
+            },
+            _ => None,
+        }
+    }
+
+    /// Checks if the type name is the type definition of an unsigned integer width which Sway doesn't have, i.e. `u24`
+    pub fn is_non_standard_uint(&self) -> bool {
+        self.uint_bits().is_some_and(|bits| !matches!(bits, 8 | 16 | 32 | 64 | 256))
+            && !matches!(self, TypeName::Identifier { name, .. } if name == "U128")
+    }
+
+    /// Gets the bit width of the type name if it is a signed integer type
+    pub fn int_bits(&self) -> Option<usize> {
+        match self {
+            TypeName::Identifier { name, generic_parameters: None } => match name.as_str() {
+                "I8" => Some(8),
+                "I16" => Some(16),
+                "I32" => Some(32),
+                "I64" => Some(64),
+                "I128" => Some(128),
+                "I256" => Some(256),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Gets the bit width of the type name if it is the type definition of a signed integer width which Sway doesn't have, i.e. `24` for `i24`
+    pub fn non_standard_int_bits(&self) -> Option<usize> {
+        match self {
+            TypeName::Identifier { name, generic_parameters: None } => name.strip_prefix('i')
+                .and_then(|bits| bits.parse::<usize>().ok())
+                .filter(|bits| bits % 8 == 0 && (8..256).contains(bits) && !matches!(bits, 8 | 16 | 32 | 64 | 128)),
+            _ => None,
+        }
+    }
+
+    /// Gets the number of decimals of the type name if it is the type definition of a fixed point type, i.e. `18` for `ufixed128x18`
+    pub fn fixed_point_decimals(&self) -> Option<usize> {
+        let TypeName::Identifier { name, generic_parameters: None } = self else { return None };
+        let (_, decimals) = name.strip_prefix('u').unwrap_or(name).strip_prefix("fixed")?.split_once('x')?;
+        decimals.parse().ok()
+    }
+
     /// Checks to see if the type name is compatible with another type name
     pub fn is_compatible_with(&self, other: &TypeName) -> bool {
         // HACK: Don't check uint value types
@@ -390,10 +460,19 @@ impl TypeName {
             return true;
         }
 
+        // HACK: Signed integer widths which Sway doesn't have are compatible with the type which holds them, i.e. `i24` and `I32`
+        let int_storage_bits = |type_name: &TypeName| type_name.int_bits().or_else(|| type_name.non_standard_int_bits().map(usize::next_power_of_two));
+
+        if let (Some(lhs_bits), Some(rhs_bits)) = (int_storage_bits(self), int_storage_bits(other)) {
+            if lhs_bits == rhs_bits {
+                return true;
+            }
+        }
+
         match self {
             TypeName::Array { type_name: lhs_type_name, length: lhs_length } => match other {
                 TypeName::Array { type_name: rhs_type_name, length: rhs_length } => {
-                    if !lhs_type_name.is_compatible_with(&rhs_type_name) {
+                    if !lhs_type_name.is_compatible_with(rhs_type_name) {
                         return false;
                     }
 
@@ -526,6 +605,7 @@ impl Display for TypeDefinition {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Constant {
+    pub doc_comment: Option<String>,
     pub is_public: bool,
     pub name: String,
     pub type_name: TypeName,
@@ -534,6 +614,8 @@ pub struct Constant {
 
 impl TabbedDisplay for Constant {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_doc_comment(self.doc_comment.as_ref(), depth, f)?;
+
         if self.is_public {
             write!(f, "pub ")?;
         }
@@ -574,6 +656,7 @@ impl TabbedDisplay for Literal {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Struct {
+    pub doc_comment: Option<String>,
     pub attributes: Option<AttributeList>,
     pub is_public: bool,
     pub name: String,
@@ -583,6 +666,8 @@ pub struct Struct {
 
 impl TabbedDisplay for Struct {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_doc_comment(self.doc_comment.as_ref(), depth, f)?;
+
         if let Some(attributes) = self.attributes.as_ref() {
             writeln!(f, "{attributes}")?;
             "".tabbed_fmt(depth, f)?;
@@ -677,12 +762,15 @@ impl TabbedDisplay for Enum {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct EnumVariant {
+    pub doc_comment: Option<String>,
     pub name: String,
     pub type_name: TypeName,
 }
 
-impl Display for EnumVariant {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl TabbedDisplay for EnumVariant {
+    fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        "".tabbed_fmt(depth, f)?;
+        write_doc_comment(self.doc_comment.as_ref(), depth, f)?;
         write!(f, "{}: {}", self.name, self.type_name)
     }
 }
@@ -691,6 +779,7 @@ impl Display for EnumVariant {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Abi {
+    pub doc_comment: Option<String>,
     pub name: String,
     pub inherits: Vec<String>,
     pub functions: Vec<Function>,
@@ -698,6 +787,8 @@ pub struct Abi {
 
 impl TabbedDisplay for Abi {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_doc_comment(self.doc_comment.as_ref(), depth, f)?;
+
         write!(f, "abi {}", self.name)?;
 
         if !self.inherits.is_empty() {
@@ -806,6 +897,7 @@ impl TabbedDisplay for Storage {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct StorageField {
+    pub doc_comment: Option<String>,
     pub name: String,
     pub type_name: TypeName,
     pub value: Expression,
@@ -813,6 +905,7 @@ pub struct StorageField {
 
 impl TabbedDisplay for StorageField {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_doc_comment(self.doc_comment.as_ref(), depth, f)?;
         write!(f, "{}: {} = ", self.name, self.type_name)?;
         self.value.tabbed_fmt(depth, f)
     }
@@ -843,6 +936,7 @@ impl TabbedDisplay for Configurable {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ConfigurableField {
+    pub doc_comment: Option<String>,
     pub name: String,
     pub type_name: TypeName,
     pub value: Expression,
@@ -850,6 +944,7 @@ pub struct ConfigurableField {
 
 impl TabbedDisplay for ConfigurableField {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_doc_comment(self.doc_comment.as_ref(), depth, f)?;
         write!(f, "{}: {} = ", self.name, self.type_name)?;
         self.value.tabbed_fmt(depth, f)
     }
@@ -859,6 +954,7 @@ impl TabbedDisplay for ConfigurableField {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Function {
+    pub doc_comment: Option<String>,
     pub attributes: Option<AttributeList>,
     pub is_public: bool,
     pub name: String,
@@ -866,10 +962,38 @@ pub struct Function {
     pub parameters: ParameterList,
     pub return_type: Option<TypeName>,
     pub body: Option<Block>,
+    pub location: Option<SourceLocation>,
 }
 
 impl TabbedDisplay for Function {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        source_map::record(self.location.as_ref(), || self.tabbed_fmt_declaration(depth, f))
+    }
+}
+
+impl Function {
+    /// Checks if the function has the same name, parameter types and return type as `other`, regardless of its location, doc comment or body.
+    pub fn has_same_signature(&self, other: &Function) -> bool {
+        self.name == other.name
+            && self.parameters.entries.len() == other.parameters.entries.len()
+            && self.parameters.entries.iter().zip(other.parameters.entries.iter()).all(|(a, b)| a.type_name == b.type_name)
+            && self.return_type == other.return_type
+    }
+
+    /// Takes the doc comment of `other` if it has the same signature, unless it only inherits its documentation with `@inheritdoc`.
+    pub fn merge_doc_comment(&mut self, other: &Function) {
+        if !self.has_same_signature(other) {
+            return;
+        }
+
+        if let Some(doc_comment) = other.doc_comment.as_ref().filter(|d| !d.trim_start().starts_with("@inheritdoc")) {
+            self.doc_comment = Some(doc_comment.clone());
+        }
+    }
+
+    fn tabbed_fmt_declaration(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_doc_comment(self.doc_comment.as_ref(), depth, f)?;
+
         if let Some(attributes) = self.attributes.as_ref() {
             writeln!(f, "{attributes}")?;
             "".tabbed_fmt(depth, f)?;
@@ -1005,6 +1129,16 @@ pub enum ImplItem {
     Function(Function),
 }
 
+impl ImplItem {
+    /// Checks if the item declares the same thing as `other`, comparing functions by signature.
+    pub fn has_same_signature(&self, other: &ImplItem) -> bool {
+        match (self, other) {
+            (ImplItem::Function(a), ImplItem::Function(b)) => a.has_same_signature(b),
+            _ => self == other,
+        }
+    }
+}
+
 impl TabbedDisplay for ImplItem {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1049,10 +1183,29 @@ impl TabbedDisplay for Block {
 pub enum Statement {
     Let(Let),
     Expression(Expression),
-    Commented(String, Option<Box<Statement>>)
+    Commented(String, Option<Box<Statement>>),
+    Located(SourceLocation, Box<Statement>),
     // TODO: finish
 }
 
+impl Statement {
+    /// Gets the statement without the location of the Solidity code it was translated from.
+    pub fn unlocated(&self) -> &Statement {
+        match self {
+            Statement::Located(_, statement) => statement.unlocated(),
+            _ => self,
+        }
+    }
+
+    /// Gets the statement without the location of the Solidity code it was translated from.
+    pub fn unlocated_mut(&mut self) -> &mut Statement {
+        match self {
+            Statement::Located(_, statement) => statement.unlocated_mut(),
+            _ => self,
+        }
+    }
+}
+
 impl TabbedDisplay for Statement {
     fn tabbed_fmt(&self, depth: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1071,14 +1224,33 @@ impl TabbedDisplay for Statement {
                 Ok(())
             }
             Statement::Commented(comment, statement) => {
+                write!(f, "/* ")?;
+
+                // Keep each line of multi-line comments aligned with the statement
+                for (i, line) in comment.split('\n').enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+
+                        if !line.is_empty() {
+                            line.tabbed_fmt(depth, f)?;
+                        }
+                    } else {
+                        write!(f, "{line}")?;
+                    }
+                }
+
                 match statement.as_ref() {
                     Some(statement) => {
-                        writeln!(f, "/* {comment} */")?;
+                        writeln!(f, " */")?;
                         statement.tabbed_fmt(depth, f)
                     },
-                    None => write!(f, "/* {comment} */"),
+                    None => write!(f, " */"),
                 }
             }
+
+            Statement::Located(location, statement) => {
+                source_map::record(Some(location), || statement.tabbed_fmt(depth, f))
+            }
         }
     }
 }
@@ -1189,6 +1361,7 @@ pub enum Expression {
     Break,
     AsmBlock(Box<AsmBlock>),
     Commented(String, Box<Expression>),
+    Located(SourceLocation, Box<Expression>),
     // TODO: finish
 }
 
@@ -1234,6 +1407,9 @@ impl TabbedDisplay for Expression {
                 write!(f, "/*{comment}*/ ")?;
                 x.tabbed_fmt(depth, f)
             }
+            Expression::Located(location, x) => {
+                source_map::record(Some(location), || x.tabbed_fmt(depth, f))
+            }
         }
     }
 }
@@ -1726,6 +1902,7 @@ mod tests {
         //     return;
         // }
         module.items.push(ModuleItem::Function(Function {
+            doc_comment: None,
             attributes: None,
             is_public: true,
             name: "test".into(),
@@ -1738,6 +1915,7 @@ mod tests {
                 ],
                 final_expr: None,
             }),
+            location: None,
         }));
 
         // Display the generated contract module
@@ -1,5 +1,5 @@
 use super::{
-    create_value_expression, finalize_block_translation, TranslatedDefinition, TranslatedVariable,
+    create_todo_expression, create_value_expression, finalize_block_translation, TranslatedDefinition, TranslatedVariable,
     TranslationScope,
 };
 use crate::{errors::Error, project::Project, sway};
@@ -7,18 +7,18 @@ use convert_case::Case;
 use num_bigint::BigUint;
 use num_traits::{Num, Zero};
 use solang_parser::{helpers::CodeLocation, pt as solidity};
-use std::{cell::RefCell, rc::Rc};
+use std::sync::{Arc, RwLock};
 
 #[inline]
 pub fn translate_assembly_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     _dialect: &Option<solidity::StringLiteral>,
     _flags: &Option<Vec<solidity::StringLiteral>>,
     yul_block: &solidity::YulBlock,
 ) -> Result<sway::Statement, Error> {
-    let scope = Rc::new(RefCell::new(TranslationScope {
+    let scope = Arc::new(RwLock::new(TranslationScope {
         parent: Some(scope.clone()),
         ..Default::default()
     }));
@@ -35,7 +35,7 @@ pub fn translate_assembly_statement(
 pub fn translate_yul_block(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     yul_block: &solidity::YulBlock,
 ) -> Result<sway::Block, Error> {
     let mut block = sway::Block::default();
@@ -58,10 +58,10 @@ pub fn translate_yul_block(
                     return;
                 }
 
-                let scope = scope.borrow();
+                let scope = scope.read().unwrap();
 
-                let scope_entry = scope.variables.iter().rev().find(|v| v.borrow().new_name == id.name).unwrap();
-                let mut scope_entry = scope_entry.borrow_mut();
+                let scope_entry = scope.variables.iter().rev().find(|v| v.read().unwrap().new_name == id.name).unwrap();
+                let mut scope_entry = scope_entry.write().unwrap();
 
                 scope_entry.statement_index = Some(statement_index);
             };
@@ -82,7 +82,7 @@ pub fn translate_yul_block(
 pub fn translate_yul_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     yul_statement: &solidity::YulStatement,
 ) -> Result<sway::Statement, Error> {
     match yul_statement {
@@ -91,13 +91,13 @@ pub fn translate_yul_statement(
         solidity::YulStatement::If(_, condition, then_block) => translate_yul_if_statement(project, translated_definition, scope.clone(), condition, then_block),
         solidity::YulStatement::For(yul_for) => translate_yul_for_statement(project, translated_definition, scope.clone(), yul_for),
         solidity::YulStatement::Switch(yul_switch) => translate_yul_switch_statement(project, translated_definition, scope.clone(), yul_switch),
-        solidity::YulStatement::Leave(_) => todo!("yul leave statement: {yul_statement} - {yul_statement:#?}"),
+        solidity::YulStatement::Leave(_) => Err(Error::UnsupportedConstruct(yul_statement.loc(), format!("yul leave statement: {yul_statement}"))),
         solidity::YulStatement::Break(_) => Ok(sway::Statement::from(sway::Expression::Break)),
         solidity::YulStatement::Continue(_) => Ok(sway::Statement::from(sway::Expression::Continue)),
         solidity::YulStatement::Block(block) => Ok(sway::Statement::from(sway::Expression::from(translate_yul_block(project, translated_definition, scope.clone(), block)?))),
-        solidity::YulStatement::FunctionDefinition(_) => todo!("yul function definition statement: {yul_statement} - {yul_statement:#?}"),
+        solidity::YulStatement::FunctionDefinition(_) => Err(Error::UnsupportedConstruct(yul_statement.loc(), format!("yul function definition statement: {yul_statement}"))),
         solidity::YulStatement::FunctionCall(yul_function_call) => translate_yul_function_call_statement(project, translated_definition, scope.clone(), yul_function_call),
-        solidity::YulStatement::Error(_) => todo!("yul error statement: {yul_statement} - {yul_statement:#?}"),
+        solidity::YulStatement::Error(_) => Err(Error::UnsupportedConstruct(yul_statement.loc(), format!("yul error statement: {yul_statement}"))),
     }
 }
 
@@ -105,7 +105,7 @@ pub fn translate_yul_statement(
 pub fn translate_yul_assign_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     identifiers: &[solidity::YulExpression],
     value: &solidity::YulExpression,
 ) -> Result<sway::Statement, Error> {
@@ -116,17 +116,15 @@ pub fn translate_yul_assign_statement(
     for (i, identifier) in translated_identifiers.iter().enumerate() {
         let sway::Expression::Identifier(name) = identifier else { continue };
 
-        let Some(variable) = scope.borrow().get_variable_from_new_name(name) else {
-            panic!(
-                "{}error: Variable not found in scope: \"{name}\"",
+        let Some(variable) = scope.read().unwrap().get_variable_from_new_name(name) else {
+            return Err(Error::UnresolvedReference(value.loc(), format!("{}error: Variable not found in scope: \"{name}\"",
                 match project.loc_to_line_and_column(&translated_definition.path, &identifiers[i].loc()) {
                     Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
                     None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                }
-            );
+                })));
         };
 
-        variable.borrow_mut().mutation_count += 1;
+        variable.write().unwrap().mutation_count += 1;
     }
 
     let value = translate_yul_expression(project, translated_definition, scope.clone(), value)?;
@@ -146,7 +144,7 @@ pub fn translate_yul_assign_statement(
 pub fn translate_yul_variable_declaration_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     identifiers: &[solidity::YulTypedIdentifier],
     value: &Option<solidity::YulExpression>,
 ) -> Result<sway::Statement, Error> {
@@ -154,7 +152,7 @@ pub fn translate_yul_variable_declaration_statement(
     let mut variables = vec![];
 
     for p in identifiers.iter() {
-        variables.push(Rc::new(RefCell::new(TranslatedVariable {
+        variables.push(Arc::new(RwLock::new(TranslatedVariable {
             old_name: p.id.name.clone(),
             new_name: crate::translate_naming_convention(p.id.name.as_str(), Case::Snake),
             type_name: sway::TypeName::Identifier {
@@ -165,21 +163,21 @@ pub fn translate_yul_variable_declaration_statement(
         })));
     }
 
-    scope.borrow_mut().variables.extend(variables.clone());
+    scope.write().unwrap().variables.extend(variables.clone());
 
     // Create the variable declaration statement
     Ok(sway::Statement::from(sway::Let {
         pattern: if variables.len() == 1 {
             sway::LetPattern::Identifier(sway::LetIdentifier {
                 is_mutable: false,
-                name: variables[0].borrow().new_name.clone(),
+                name: variables[0].read().unwrap().new_name.clone(),
             })
         } else {
             sway::LetPattern::Tuple(
                 variables.iter()
                     .map(|p| sway::LetIdentifier {
                         is_mutable: false,
-                        name: p.borrow().new_name.clone(),
+                        name: p.read().unwrap().new_name.clone(),
                     })
                     .collect()
             )
@@ -198,7 +196,7 @@ pub fn translate_yul_variable_declaration_statement(
                     generic_parameters: None,
                 },
                 None,
-            )
+            )?
         },
     }))
 }
@@ -207,7 +205,7 @@ pub fn translate_yul_variable_declaration_statement(
 pub fn translate_yul_if_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     condition: &solidity::YulExpression,
     then_block: &solidity::YulBlock,
 ) -> Result<sway::Statement, Error> {
@@ -225,7 +223,7 @@ pub fn translate_yul_if_statement(
 pub fn translate_yul_for_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     yul_for: &solidity::YulFor,
 ) -> Result<sway::Statement, Error> {
     // {
@@ -237,7 +235,7 @@ pub fn translate_yul_for_statement(
     // }
 
     // Create a scope for the block that will contain the for loop logic
-    let scope = Rc::new(RefCell::new(TranslationScope {
+    let scope = Arc::new(RwLock::new(TranslationScope {
         parent: Some(scope.clone()),
         ..Default::default()
     }));
@@ -252,17 +250,18 @@ pub fn translate_yul_for_statement(
 
         // Store the statement index of variable declaration statements in their scope entries
         if let sway::Statement::Let(sway::Let { pattern, .. }) = &mut statement {
-            let store_let_identifier_statement_index = |id: &mut sway::LetIdentifier| {
-                let Some(variable) = scope.borrow().get_variable_from_new_name(&id.name) else {
-                    panic!("error: Variable not found in scope: \"{}\"", id.name);
+            let store_let_identifier_statement_index = |id: &mut sway::LetIdentifier| -> Result<(), Error> {
+                let Some(variable) = scope.read().unwrap().get_variable_from_new_name(&id.name) else {
+                    return Err(Error::UnresolvedReference(yul_for.loc, format!("Variable not found in scope: \"{}\"", id.name)));
                 };
                 
-                variable.borrow_mut().statement_index = Some(statement_index);
+                variable.write().unwrap().statement_index = Some(statement_index);
+                Ok(())
             };
 
             match pattern {
-                sway::LetPattern::Identifier(id) => store_let_identifier_statement_index(id),
-                sway::LetPattern::Tuple(ids) => ids.iter_mut().for_each(store_let_identifier_statement_index),
+                sway::LetPattern::Identifier(id) => store_let_identifier_statement_index(id)?,
+                sway::LetPattern::Tuple(ids) => ids.iter_mut().try_for_each(store_let_identifier_statement_index)?,
             }
         }
 
@@ -312,7 +311,7 @@ pub fn translate_yul_for_statement(
 pub fn translate_yul_switch_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     yul_switch: &solidity::YulSwitch,
 ) -> Result<sway::Statement, Error> {
     let expression = translate_yul_expression(project, translated_definition, scope.clone(), &yul_switch.condition)?;
@@ -341,7 +340,7 @@ pub fn translate_yul_switch_statement(
 pub fn translate_yul_function_call_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     yul_function_call: &solidity::YulFunctionCall,
 ) -> Result<sway::Statement, Error> {
     Ok(sway::Statement::from(
@@ -352,7 +351,7 @@ pub fn translate_yul_function_call_statement(
 pub fn translate_yul_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::YulExpression,
 ) -> Result<sway::Expression, Error> {
     match expression {
@@ -363,7 +362,7 @@ pub fn translate_yul_expression(
         solidity::YulExpression::StringLiteral(string_literal, _) => Ok(sway::Expression::from(sway::Literal::String(string_literal.string.clone()))),
         solidity::YulExpression::Variable(solidity::Identifier { name, .. }) => translate_yul_variable_expression(project, translated_definition, scope.clone(), expression, name.as_str()),
         solidity::YulExpression::FunctionCall(function_call) => translate_yul_function_call_expression(project, translated_definition, scope.clone(), function_call),
-        solidity::YulExpression::SuffixAccess(_, _, _) => Ok(sway::Expression::create_todo(Some(expression.to_string()))),
+        solidity::YulExpression::SuffixAccess(_, _, _) => Ok(create_todo_expression(project, translated_definition, &expression.loc(), Some(expression.to_string()))),
     }
 }
 
@@ -371,21 +370,19 @@ pub fn translate_yul_expression(
 pub fn translate_yul_variable_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::YulExpression,
     name: &str,
 ) -> Result<sway::Expression, Error> {
-    let Some(variable) = scope.borrow().get_variable_from_old_name(name) else {
-        panic!(
-            "{}error: Variable not found in scope: \"{name}\"",
+    let Some(variable) = scope.read().unwrap().get_variable_from_old_name(name) else {
+        return Err(Error::UnresolvedReference(expression.loc(), format!("{}error: Variable not found in scope: \"{name}\"",
             match project.loc_to_line_and_column(&translated_definition.path, &expression.loc()) {
                 Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
                 None => format!("{} - ", translated_definition.path.to_string_lossy()),
-            }
-        );
+            })));
     };
 
-    let variable = variable.borrow();
+    let variable = variable.read().unwrap();
 
     if variable.is_storage {
         Ok(sway::Expression::from(sway::FunctionCall {
@@ -408,7 +405,7 @@ pub fn translate_yul_variable_expression(
 pub fn translate_yul_function_call_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     function_call: &solidity::YulFunctionCall,
 ) -> Result<sway::Expression, Error> {
     let parameters = function_call.arguments.iter()
@@ -418,14 +415,14 @@ pub fn translate_yul_function_call_expression(
     match function_call.id.name.as_str() {
         "stop" => {
             // TODO: stop() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "add" => {
             // add(a, b) => a + b
 
             if parameters.len() != 2 {
-                panic!("Invalid yul add function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul add function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -439,7 +436,7 @@ pub fn translate_yul_function_call_expression(
             // mul(a, b) => a * b
 
             if parameters.len() != 2 {
-                panic!("Invalid yul mul function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul mul function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -453,7 +450,7 @@ pub fn translate_yul_function_call_expression(
             // sub(a, b) => a - b
 
             if parameters.len() != 2 {
-                panic!("Invalid yul sub function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul sub function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -467,7 +464,7 @@ pub fn translate_yul_function_call_expression(
             // div(a, b) => a / b
             
             if parameters.len() != 2 {
-                panic!("Invalid yul div function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul div function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -481,7 +478,7 @@ pub fn translate_yul_function_call_expression(
             // sdiv(a, b) => a / b
 
             if parameters.len() != 2 {
-                panic!("Invalid yul div function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul div function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -495,7 +492,7 @@ pub fn translate_yul_function_call_expression(
             // mod(a, b) => a % b
 
             if parameters.len() != 2 {
-                panic!("Invalid yul mod function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul mod function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -509,7 +506,7 @@ pub fn translate_yul_function_call_expression(
             // smod(a, b) => a % b
 
             if parameters.len() != 2 {
-                panic!("Invalid yul smod function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul smod function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -526,7 +523,7 @@ pub fn translate_yul_function_call_expression(
             // }
 
             if parameters.len() != 2 {
-                panic!("Invalid yul exp function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul exp function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             // Ensure std::math::Power is imported for the pow function
@@ -548,7 +545,7 @@ pub fn translate_yul_function_call_expression(
             // not(a) => !a
 
             if parameters.len() != 1 {
-                panic!("Invalid yul not function call, expected 1 parameter, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul not function call, expected 1 parameter, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::UnaryExpression {
@@ -561,7 +558,7 @@ pub fn translate_yul_function_call_expression(
             // lt(a, b) => a < b
 
             if parameters.len() != 2 {
-                panic!("Invalid yul lt function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul lt function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -575,7 +572,7 @@ pub fn translate_yul_function_call_expression(
             // gt(a, b) => a > b
 
             if parameters.len() != 2 {
-                panic!("Invalid yul gt function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul gt function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -589,7 +586,7 @@ pub fn translate_yul_function_call_expression(
             // slt(a, b) => a < b
 
             if parameters.len() != 2 {
-                panic!("Invalid yul slt function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul slt function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -603,7 +600,7 @@ pub fn translate_yul_function_call_expression(
             // sgt(a, b) => a > b
             
             if parameters.len() != 2 {
-                panic!("Invalid yul sgt function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul sgt function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -617,7 +614,7 @@ pub fn translate_yul_function_call_expression(
             // eq(a, b) => a == b
             
             if parameters.len() != 2 {
-                panic!("Invalid yul eq function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul eq function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -631,7 +628,7 @@ pub fn translate_yul_function_call_expression(
             // eq(a) => a == 0
             
             if parameters.len() != 1 {
-                panic!("Invalid yul iszero function call, expected 1 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul iszero function call, expected 1 parameters, found {}", parameters.len())));
             }
 
             let type_name = translated_definition.get_expression_type(scope.clone(), &parameters[0])?;
@@ -639,7 +636,7 @@ pub fn translate_yul_function_call_expression(
             Ok(sway::Expression::from(sway::BinaryExpression {
                 operator: "==".into(),
                 lhs: parameters[0].clone(),
-                rhs: create_value_expression(translated_definition, scope.clone(), &type_name, None),
+                rhs: create_value_expression(translated_definition, scope.clone(), &type_name, None)?,
             }))
         }
 
@@ -647,7 +644,7 @@ pub fn translate_yul_function_call_expression(
             // and(a, b) => a & b
             
             if parameters.len() != 2 {
-                panic!("Invalid yul and function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul and function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -661,7 +658,7 @@ pub fn translate_yul_function_call_expression(
             // or(a, b) => a | b
             
             if parameters.len() != 2 {
-                panic!("Invalid yul or function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul or function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -675,7 +672,7 @@ pub fn translate_yul_function_call_expression(
             // xor(a, b) => a ^ b
             
             if parameters.len() != 2 {
-                panic!("Invalid yul xor function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul xor function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -687,14 +684,14 @@ pub fn translate_yul_function_call_expression(
 
         "byte" => {
             // TODO: byte(i, x) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "shl" => {
             // shl(shift, value) => value << shift
             
             if parameters.len() != 2 {
-                panic!("Invalid yul shl function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul shl function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -708,7 +705,7 @@ pub fn translate_yul_function_call_expression(
             // shr(shift, value) => value >> shift
             
             if parameters.len() != 2 {
-                panic!("Invalid yul shr function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul shr function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -722,7 +719,7 @@ pub fn translate_yul_function_call_expression(
             // sar(shift, value) => value >> shift
             
             if parameters.len() != 2 {
-                panic!("Invalid yul sar function call, expected 2 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul sar function call, expected 2 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -736,7 +733,7 @@ pub fn translate_yul_function_call_expression(
             // addmod(a, b, c) => (a + b) % c
 
             if parameters.len() != 3 {
-                panic!("Invalid yul addmod function call, expected 3 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul addmod function call, expected 3 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -756,7 +753,7 @@ pub fn translate_yul_function_call_expression(
             // mulmod(a, b, c) => (a * b) % c
 
             if parameters.len() != 3 {
-                panic!("Invalid yul addmod function call, expected 3 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul addmod function call, expected 3 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::BinaryExpression {
@@ -774,79 +771,79 @@ pub fn translate_yul_function_call_expression(
 
         "signextend" => {
             // TODO: signextend(b, x) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "sha3" => {
             // TODO: sha3(offset, length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "keccak256" => {
             // TODO: keccak256(offset, length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "pc" => {
             // TODO: pc() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "pop" => {
             // TODO: pop(x) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "mload" => {
             // TODO: mload(offset) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "mstore" => {
             // TODO: mstore(offset, value) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "mstore8" => {
             // TODO: mstore8(offset, value) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "sload" => {
             // TODO: sload(key) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "sstore" => {
             // TODO: sstore(key, value) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "tload" => {
             // TODO: tload(p) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "tstore" => {
             // TODO: tstore(p, v) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "msize" => {
             // TODO: msize() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "gas" => {
             // TODO: gas() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "address" => {
             // address() => Identity::from(ContractId::this())
 
             if !parameters.is_empty() {
-                panic!("Invalid yul address function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul address function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::FunctionCall {
@@ -864,14 +861,14 @@ pub fn translate_yul_function_call_expression(
 
         "balance" => {
             // TODO: balance(addr) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "selfbalance" => {
             // selfbalance() => std::context::this_balance(AssetId::default()).as_u256()
 
             if !parameters.is_empty() {
-                panic!("Invalid yul balance function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul balance function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::FunctionCall {
@@ -898,7 +895,7 @@ pub fn translate_yul_function_call_expression(
             // caller() => msg_sender().unwrap()
 
             if !parameters.is_empty() {
-                panic!("Invalid yul caller function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul caller function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::FunctionCall {
@@ -919,7 +916,7 @@ pub fn translate_yul_function_call_expression(
             // callvalue() => std::context::msg_amount()
             
             if !parameters.is_empty() {
-                panic!("Invalid yul callvalue function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul callvalue function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::FunctionCall {
@@ -931,14 +928,14 @@ pub fn translate_yul_function_call_expression(
 
         "calldataload" => {
             // TODO: calldataload(i) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "calldatasize" => {
             // calldatasize() => std::inputs::input_message_data_length(0)
             
             if !parameters.is_empty() {
-                panic!("Invalid yul calldatasize function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul calldatasize function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::FunctionCall {
@@ -952,34 +949,34 @@ pub fn translate_yul_function_call_expression(
 
         "calldatacopy" => {
             // TODO: calldatacopy(dest_offset, offset, length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "codesize" => {
             // TODO: codesize() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "codecopy" => {
             // TODO: codecopy(dest_offset, offset, length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "extcodesize" => {
             // TODO: extcodesize(addr) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "extcodecopy" => {
             // TODO: extcodecopy(addr, dest_offset, offset, length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "returndatasize" => {
             // returndatasize() => std::registers::return_length()
 
             if !parameters.is_empty() {
-                panic!("Invalid yul returndatasize function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul returndatasize function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::FunctionCall {
@@ -991,92 +988,92 @@ pub fn translate_yul_function_call_expression(
 
         "returndatacopy" => {
             // TODO: returndatacopy(dest_offset, offset, length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "mcopy" => {
             // TODO: mcopy(t, f, s) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "extcodehash" => {
             // TODO: extcodehash(addr) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "create" => {
             // TODO: create(value, offset, length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "create2" => {
             // TODO: create2(value, offset, length, salt) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "call" => {
             // TODO: call(gas, addr, value, args_offset, args_length, ret_offset, ret_length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "callcode" => {
             // TODO: callcode(gas, addr, value, args_offset, args_length, ret_offset, ret_length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "delegatecall" => {
             // TODO: delegatecall(gas, addr, args_offset, args_length, ret_offset, ret_length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "staticcall" => {
             // TODO: staticcall(gas, addr, args_offset, args_length, ret_offset, ret_length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "return" => {
             // TODO: return(offset, length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "revert" => {
             // TODO: revert(offset, length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "selfdestruct" => {
             // TODO: selfdestruct(addr) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
         
         "invalid" => {
             // TODO: invalid() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
         
         "log0" => {
             // TODO: log0(offset, length) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "log1" => {
             // TODO: log1(offset, length, topic0) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "log2" => {
             // TODO: log2(offset, length, topic0, topic1) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "log3" => {
             // TODO: log3(offset, length, topic0, topic1, topic2) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "log4" => {
             // TODO: log4(offset, length, topic0, topic1, topic2, topic3) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "chainid" => {
@@ -1086,7 +1083,7 @@ pub fn translate_yul_function_call_expression(
             // }
 
             if !parameters.is_empty() {
-                panic!("Invalid yul chainid function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul chainid function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::AsmBlock {
@@ -1117,24 +1114,24 @@ pub fn translate_yul_function_call_expression(
 
         "basefee" => {
             // TODO: basefee() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
         
         "blobbasefee" => {
             // TODO: blobbasefee() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
         
         "origin" => {
             // TODO: origin() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "gasprice" => {
             // gasprice() => std::tx::tx_gas_price().unwrap_or(0)
             
             if !parameters.is_empty() {
-                panic!("Invalid yul gasprice function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul gasprice function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::FunctionCall {
@@ -1155,12 +1152,12 @@ pub fn translate_yul_function_call_expression(
 
         "blockhash" => {
             // TODO: blockhash(block_number) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "blobhash" => {
             // TODO: blobhash(i) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "coinbase" => {
@@ -1173,7 +1170,7 @@ pub fn translate_yul_function_call_expression(
             // }
 
             if !parameters.is_empty() {
-                panic!("Invalid yul coinbase function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul coinbase function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::Block {
@@ -1268,7 +1265,7 @@ pub fn translate_yul_function_call_expression(
             // timestamp() => std::block::timestamp().as_u256()
             
             if !parameters.is_empty() {
-                panic!("Invalid yul timestamp function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul timestamp function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::FunctionCall {
@@ -1289,7 +1286,7 @@ pub fn translate_yul_function_call_expression(
             // number() => std::block::height()
             
             if !parameters.is_empty() {
-                panic!("Invalid yul number function call, expected 0 parameters, found {}", parameters.len());
+                return Err(Error::InvalidConstruct(function_call.loc, format!("Invalid yul number function call, expected 0 parameters, found {}", parameters.len())));
             }
 
             Ok(sway::Expression::from(sway::FunctionCall {
@@ -1301,52 +1298,52 @@ pub fn translate_yul_function_call_expression(
 
         "difficulty" => {
             // TODO: difficulty() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "prevrandao" => {
             // TODO: prevrandao() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "gaslimit" => {
             // TODO: gaslimit() => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "datasize" => {
             // TODO: datasize(x) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "dataoffset" => {
             // TODO: dataoffset(x) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "datacopy" => {
             // TODO: datacopy(t, f, l) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "setimmutable" => {
             // TODO: setimmutable(offset, "name", value) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "loadimmutable" => {
             // TODO: loadimmutable("name") => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "linkersymbol" => {
             // TODO: linkersymbol("library_id") => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         "memoryguard" => {
             // TODO: memoryguard(size) => ???
-            Ok(sway::Expression::create_todo(Some(function_call.to_string())))
+            Ok(create_todo_expression(project, translated_definition, &function_call.loc, Some(function_call.to_string())))
         }
 
         // TODO: verbatim_<n>i_<m>o("<data>", ...), where
@@ -1354,6 +1351,6 @@ pub fn translate_yul_function_call_expression(
         // m is a decimal between 0 and 99 that specifies the number of output stack slots / variables
         // data is a string literal that contains the sequence of bytes
 
-        name => todo!("look up yul function in scope: \"{name}\"")
+        name => Err(Error::UnsupportedConstruct(function_call.loc, format!("look up yul function in scope: \"{name}\"")))
     }
 }
@@ -6,13 +6,12 @@ use super::{
     translate_struct_definition, translate_type_definition, translate_type_name,
     TranslatedDefinition, TranslatedUsingDirective,
 };
-use crate::{project::Project, sway, Error};
+use crate::{project::{Project, SymbolKind}, sway, Error};
 use convert_case::Case;
 use solang_parser::pt as solidity;
 use std::{
-    cell::RefCell,
     path::{Path, PathBuf},
-    rc::Rc,
+    sync::{Arc, RwLock},
 };
 
 #[inline]
@@ -23,17 +22,18 @@ pub fn translate_using_directive(
 ) -> Result<(), Error> {
     let for_type = using_directive.ty.as_ref()
         .map(|t| translate_type_name(project, translated_definition, t, false, false))
-        .map_or(Ok(None), |t| Ok(Some(t)))?;
+        .transpose()?;
 
     match &using_directive.list {
         solidity::UsingList::Library(using_library) => {
-            let library_name = using_library.identifiers.iter().map(|i| i.name.clone()).collect::<Vec<_>>().join(".");
+            let identifiers = project.resolve_import_aliases(&translated_definition.path, &using_library.identifiers).unwrap_or_else(|| using_library.identifiers.clone());
+            let library_name = identifiers.iter().map(|i| i.name.clone()).collect::<Vec<_>>().join(".");
 
             // Find the translated library definition
-            let Some(library_definition) = project.translated_definitions.iter().find(|d| {
-                d.name == library_name && matches!(d.kind.as_ref().unwrap(), solidity::ContractTy::Library(_))
+            let Some(library_definition) = project.find_symbol(&translated_definition.path, SymbolKind::Definition, &library_name, |d| {
+                matches!(d.kind.as_ref().unwrap(), solidity::ContractTy::Library(_))
             }) else {
-                panic!("Failed to find translated library: \"{library_name}\"");
+                return Err(Error::UnresolvedReference(using_directive.loc, format!("Failed to find translated library: \"{library_name}\"")));
             };
 
             let mut translated_using_directive = TranslatedUsingDirective {
@@ -50,21 +50,21 @@ pub fn translate_using_directive(
                 }
 
                 // Get the scope entry for the library function
-                let Some(scope_entry) = library_definition.toplevel_scope.borrow().find_function(|f| f.borrow().new_name == function.name) else {
-                    panic!("Failed to find function in scope: \"{}\"", function.name);
+                let Some(scope_entry) = library_definition.toplevel_scope.read().unwrap().find_function(|f| f.read().unwrap().new_name == function.name) else {
+                    return Err(Error::UnresolvedReference(using_directive.loc, format!("Failed to find function in scope: \"{}\"", function.name)));
                 };
 
                 // Add the function to the current definition's toplevel scope
-                if !translated_definition.toplevel_scope.borrow().functions.iter().any(|f| {
-                    f.borrow().old_name == scope_entry.borrow().old_name
-                    && f.borrow().parameters == scope_entry.borrow().parameters
-                    && f.borrow().return_type == scope_entry.borrow().return_type
+                if !translated_definition.toplevel_scope.read().unwrap().functions.iter().any(|f| {
+                    f.read().unwrap().old_name == scope_entry.read().unwrap().old_name
+                    && f.read().unwrap().parameters == scope_entry.read().unwrap().parameters
+                    && f.read().unwrap().return_type == scope_entry.read().unwrap().return_type
                 }) {
-                    translated_definition.toplevel_scope.borrow_mut().functions.push(Rc::new(RefCell::new(scope_entry.borrow().clone())));
+                    translated_definition.toplevel_scope.write().unwrap().functions.push(Arc::new(RwLock::new(scope_entry.read().unwrap().clone())));
                 }
 
                 // Add the function to the translated using directive so we know where it came from
-                translated_using_directive.functions.push(scope_entry.borrow().clone());
+                translated_using_directive.functions.push(scope_entry.read().unwrap().clone());
 
                 // Add the function name to the current definition's function name list
                 *translated_definition.function_name_counts.entry(function.name.clone()).or_insert(0) += 1;
@@ -89,9 +89,9 @@ pub fn translate_using_directive(
             translated_definition.using_directives.push(translated_using_directive);
         }
 
-        solidity::UsingList::Functions(_) => todo!("using directive function list: {}", using_directive.to_string()),
+        solidity::UsingList::Functions(_) => return Err(Error::UnsupportedConstruct(using_directive.loc, format!("using directive function list: {}", using_directive))),
 
-        solidity::UsingList::Error => panic!("Failed to parse using directive"),
+        solidity::UsingList::Error => return Err(Error::InvalidConstruct(using_directive.loc, "Failed to parse using directive".into())),
     }
 
     Ok(())
@@ -109,11 +109,16 @@ pub fn translate_contract_definition(
     toplevel_events: &[solidity::EventDefinition],
     toplevel_errors: &[solidity::ErrorDefinition],
     toplevel_functions: &[solidity::FunctionDefinition],
+    toplevel_constants: &[solidity::VariableDefinition],
     contract_names: &[String],
+    unchecked: bool,
     contract_definition: &solidity::ContractDefinition,
 ) -> Result<(), Error> {
     let definition_name = contract_definition.name.as_ref().unwrap().name.clone();
-    let inherits: Vec<String> = contract_definition.base.iter().map(|b| b.name.identifiers.iter().map(|i| i.name.clone()).collect::<Vec<_>>().join(".")).collect();
+    let inherits: Vec<String> = contract_definition.base.iter().map(|b| {
+        let identifiers = project.resolve_import_aliases(source_unit_path, &b.name.identifiers).unwrap_or_else(|| b.name.identifiers.clone());
+        identifiers.iter().map(|i| i.name.clone()).collect::<Vec<_>>().join(".")
+    }).collect();
 
     // Create a new translation container
     let mut translated_definition = TranslatedDefinition {
@@ -126,6 +131,9 @@ pub fn translate_contract_definition(
         )
     };
 
+    // Arithmetic wraps on overflow everywhere in the definition when the source unit predates Solidity 0.8
+    translated_definition.toplevel_scope.write().unwrap().unchecked = unchecked;
+
     // Translate import directives
     translate_import_directives(project, &mut translated_definition, import_directives)?;
 
@@ -144,6 +152,19 @@ pub fn translate_contract_definition(
         translate_enum_definition(project, &mut translated_definition, enum_definition)?;
     }
 
+    // Translate toplevel constants from imported files in the context of the file they were declared in
+    for (path, variable_definition) in project.collect_imported_toplevel_constants(source_unit_path) {
+        let source_unit_path = std::mem::replace(&mut translated_definition.path, path);
+        let result = translate_state_variable(project, &mut translated_definition, &variable_definition);
+        translated_definition.path = source_unit_path;
+        result?;
+    }
+
+    // Translate toplevel constants
+    for variable_definition in toplevel_constants {
+        translate_state_variable(project, &mut translated_definition, variable_definition)?;
+    }
+
     // Collect toplevel struct names ahead of time for contextual reasons
     for struct_definition in toplevel_structs {
         let struct_name = struct_definition.name.as_ref().unwrap().name.clone();
@@ -184,8 +205,8 @@ pub fn translate_contract_definition(
         let mut function_exists = false;
 
 
-        for f in translated_definition.toplevel_scope.borrow().functions.iter() {
-            let mut f = f.borrow_mut();
+        for f in translated_definition.toplevel_scope.read().unwrap().functions.iter() {
+            let mut f = f.write().unwrap();
 
             if ((!f.old_name.is_empty() && (f.old_name == function.old_name)) || (f.new_name == function.new_name)) && f.parameters == function.parameters && f.return_type == function.return_type {
                 f.new_name = function.new_name.clone();
@@ -195,13 +216,15 @@ pub fn translate_contract_definition(
         }
 
         if !function_exists {
-            translated_definition.toplevel_scope.borrow_mut().functions.push(Rc::new(RefCell::new(function)));
+            translated_definition.toplevel_scope.write().unwrap().functions.push(Arc::new(RwLock::new(function)));
         }
     }
 
     // Translate toplevel function definitions
     for function_definition in toplevel_functions {
-        translate_function_definition(project, &mut translated_definition, function_definition)?;
+        if let Err(error) = translate_function_definition(project, &mut translated_definition, function_definition) {
+            project.report_error(source_unit_path, &error.with_loc(&function_definition.loc));
+        }
     }
 
     // Propagate inherited definitions
@@ -270,6 +293,12 @@ pub fn translate_contract_definition(
     // Translate contract state variables
     for part in contract_definition.parts.iter() {
         let solidity::ContractPart::VariableDefinition(variable_definition) = part else { continue };
+
+        // Function type state variables can refer to functions, so they are translated after the functions are collected
+        if matches!(variable_definition.ty, solidity::Expression::Type(_, solidity::Type::Function { .. })) {
+            continue;
+        }
+
         translate_state_variable(project, &mut translated_definition, variable_definition)?;
     }
     
@@ -288,8 +317,8 @@ pub fn translate_contract_definition(
         
         let mut function_exists = false;
 
-        for f in translated_definition.toplevel_scope.borrow().functions.iter() {
-            let mut f = f.borrow_mut();
+        for f in translated_definition.toplevel_scope.read().unwrap().functions.iter() {
+            let mut f = f.write().unwrap();
 
             if ((!f.old_name.is_empty() && (f.old_name == function.old_name)) || (f.new_name == function.new_name)) && f.parameters == function.parameters && f.return_type == function.return_type {
                 f.new_name = function.new_name.clone();
@@ -299,10 +328,21 @@ pub fn translate_contract_definition(
         }
 
         if !function_exists {
-            translated_definition.toplevel_scope.borrow_mut().functions.push(Rc::new(RefCell::new(function)));
+            translated_definition.toplevel_scope.write().unwrap().functions.push(Arc::new(RwLock::new(function)));
         }
     }
 
+    // Translate contract function type state variables
+    for part in contract_definition.parts.iter() {
+        let solidity::ContractPart::VariableDefinition(variable_definition) = part else { continue };
+
+        if !matches!(variable_definition.ty, solidity::Expression::Type(_, solidity::Type::Function { .. })) {
+            continue;
+        }
+
+        translate_state_variable(project, &mut translated_definition, variable_definition)?;
+    }
+
     // Translate each modifier
     for part in contract_definition.parts.iter() {
         let solidity::ContractPart::FunctionDefinition(function_definition) = part else { continue };
@@ -313,7 +353,9 @@ pub fn translate_contract_definition(
             continue;
         }
         
-        translate_modifier_definition(project, &mut translated_definition, function_definition)?;
+        if let Err(error) = translate_modifier_definition(project, &mut translated_definition, function_definition) {
+            project.report_error(source_unit_path, &error.with_loc(&function_definition.loc));
+        }
     }
 
     // Translate each function
@@ -326,7 +368,9 @@ pub fn translate_contract_definition(
             continue;
         }
 
-        translate_function_definition(project, &mut translated_definition, function_definition)?;
+        if let Err(error) = translate_function_definition(project, &mut translated_definition, function_definition) {
+            project.report_error(source_unit_path, &error.with_loc(&function_definition.loc));
+        }
     }
 
     // Propagate deferred initializations into the constructor
@@ -342,7 +386,7 @@ pub fn translate_contract_definition(
             });
             
             let value_type_name = translated_definition.get_expression_type(translated_definition.toplevel_scope.clone(), &deferred_initialization.value)?;
-            let variable = translated_definition.toplevel_scope.borrow().get_variable_from_new_name(&deferred_initialization.name).unwrap();
+            let variable = translated_definition.toplevel_scope.read().unwrap().get_variable_from_new_name(&deferred_initialization.name).unwrap();
 
             match &deferred_initialization.value {
                 sway::Expression::Array(sway::Array { elements }) => {
@@ -380,6 +424,7 @@ pub fn translate_contract_definition(
         // Create the constructor if it doesn't exist
         if constructor_function.is_none() {
             let mut function = sway::Function {
+                doc_comment: None,
                 attributes: None,
                 is_public: false,
                 name: "constructor".into(),
@@ -387,6 +432,7 @@ pub fn translate_contract_definition(
                 parameters: sway::ParameterList::default(),
                 return_type: None,
                 body: None,
+                location: None,
             };
     
             translated_definition.get_abi().functions.insert(0, function.clone());
@@ -399,6 +445,7 @@ pub fn translate_contract_definition(
             
             // Add the `constructor_called` field to the storage block
             translated_definition.get_storage().fields.push(sway::StorageField {
+                doc_comment: None,
                 name: constructor_called_variable_name.clone(),
                 type_name: sway::TypeName::Identifier {
                     name: "bool".into(),
@@ -471,7 +518,7 @@ pub fn translate_contract_definition(
 
         // Skip past the initial constructor requirements
         for (i, statement) in constructor_body.statements.iter().enumerate() {
-            let sway::Statement::Expression(sway::Expression::FunctionCall(function_call)) = statement else {
+            let sway::Statement::Expression(sway::Expression::FunctionCall(function_call)) = statement.unlocated() else {
                 statement_index = i;
                 break;
             };
@@ -514,7 +561,12 @@ pub fn translate_contract_definition(
         }
     }
     
-    project.translated_definitions.push(translated_definition);
+    // Keep the NatSpec doc comment of the contract on its abi
+    if let Some(abi) = translated_definition.abi.as_mut() {
+        abi.doc_comment = project.get_doc_comment(source_unit_path, &contract_definition.loc);
+    }
+
+    project.add_translated_definition(translated_definition);
     
     Ok(())
 }
@@ -533,33 +585,19 @@ pub fn propagate_inherited_definitions(
 
         // Find inherited import directive
         for import_directive in import_directives.iter() {
-            let filename = match import_directive {
-                solidity::Import::Plain(solidity::ImportPath::Filename(filename), _) => filename,
+            let import_path = match import_directive {
+                solidity::Import::Plain(import_path, _) | solidity::Import::GlobalSymbol(import_path, _, _) => import_path,
                 
-                solidity::Import::Rename(solidity::ImportPath::Filename(filename), identifiers, _) => {
+                solidity::Import::Rename(import_path, identifiers, _) => {
                     if !identifiers.iter().any(|i| i.0.name == *inherit) {
                         continue;
                     }
 
-                    filename
+                    import_path
                 }
-
-                _ => panic!("Unsupported import directive: {import_directive:#?}"),
             };
             
-            let import_path = project.get_project_type_path(&source_unit_directory, filename.string.as_str())?;
-            
-            if !import_path.exists() {
-                return Err(Error::Wrapped(Box::new(
-                    std::io::Error::new(
-                        std::io::ErrorKind::NotFound,
-                        format!("File not found: {}", import_path.to_string_lossy()),
-                    )
-                )));
-            }
-
-            let import_path = crate::get_canonical_path(import_path, false, false)
-                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+            let import_path = project.resolve_import_path(&source_unit_directory, import_path)?;
 
             if let Some(t) = resolve_import(project, inherit, &import_path)? {
                 inherited_definition = Some(t);
@@ -575,12 +613,12 @@ pub fn propagate_inherited_definitions(
         }
 
         let Some(inherited_definition) = inherited_definition else {
-            panic!("Failed to find inherited definition \"{inherit}\" for \"{}\"", translated_definition.name);
+            return Err(Error::UnresolvedReference(solidity::Loc::Implicit, format!("Failed to find inherited definition \"{inherit}\" for \"{}\"", translated_definition.name)));
         };
 
         // Extend the toplevel scope
-        translated_definition.toplevel_scope.borrow_mut().variables.extend(inherited_definition.toplevel_scope.borrow().variables.clone());
-        translated_definition.toplevel_scope.borrow_mut().functions.extend(inherited_definition.toplevel_scope.borrow().functions.clone());
+        translated_definition.toplevel_scope.write().unwrap().variables.extend(inherited_definition.toplevel_scope.read().unwrap().variables.clone());
+        translated_definition.toplevel_scope.write().unwrap().functions.extend(inherited_definition.toplevel_scope.read().unwrap().functions.clone());
 
         // Extend the use statements
         for inherited_use in inherited_definition.uses.iter() {
@@ -638,6 +676,13 @@ pub fn propagate_inherited_definitions(
             }
         }
 
+        // Extend the function type enums
+        for inherited_enum in inherited_definition.function_enums.iter() {
+            if !translated_definition.function_enums.contains(inherited_enum) {
+                translated_definition.function_enums.push(inherited_enum.clone());
+            }
+        }
+
         // Extend the constants
         for constant in inherited_definition.constants.iter() {
             if !translated_definition.constants.contains(constant) {
@@ -654,7 +699,9 @@ pub fn propagate_inherited_definitions(
 
                 let abi = translated_definition.get_abi();
 
-                if !abi.functions.contains(inherited_function) {
+                if let Some(f) = abi.functions.iter_mut().find(|f| f.has_same_signature(inherited_function)) {
+                    f.merge_doc_comment(inherited_function);
+                } else {
                     abi.functions.push(inherited_function.clone());
                 }
             }
@@ -733,7 +780,7 @@ pub fn propagate_inherited_definitions(
 
                 let contract_impl = translated_definition.get_contract_impl();
 
-                if !contract_impl.items.contains(inherited_impl_item) {
+                if !contract_impl.items.iter().any(|i| i.has_same_signature(inherited_impl_item)) {
                     contract_impl.items.push(inherited_impl_item.clone());
                 }
             }
@@ -34,6 +34,7 @@ pub fn translate_enum_definition(
     // Add each variant to the variants impl block
     for (i, value) in enum_definition.values.iter().enumerate() {
         variants_impl.items.push(sway::ImplItem::Constant(sway::Constant {
+            doc_comment: None,
             is_public: false,
             name: crate::translate_naming_convention(value.as_ref().unwrap().name.as_str(), Case::ScreamingSnake),
             type_name: type_definition.name.clone(),
@@ -59,8 +60,8 @@ pub fn translate_event_definition(
     let events_enum_name = format!("{}Event", translated_definition.name);
 
     let type_name = if event_definition.fields.len() == 1 {
-        match translate_type_name(project, translated_definition, &event_definition.fields[0].ty, false, false) {
-            sway::TypeName::Identifier { name, .. } if project.find_definition_with_abi(name.as_str()).is_some() => {
+        match translate_type_name(project, translated_definition, &event_definition.fields[0].ty, false, false)? {
+            sway::TypeName::Identifier { name, .. } if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_some() => {
                 sway::TypeName::Identifier {
                     name: "Identity".into(),
                     generic_parameters: None,
@@ -72,8 +73,8 @@ pub fn translate_event_definition(
     } else {
         sway::TypeName::Tuple {
             type_names: event_definition.fields.iter().map(|f| {
-                match translate_type_name(project, translated_definition, &f.ty, false, false) {
-                    sway::TypeName::Identifier { name, .. } if project.find_definition_with_abi(name.as_str()).is_some() => {
+                Ok(match translate_type_name(project, translated_definition, &f.ty, false, false)? {
+                    sway::TypeName::Identifier { name, .. } if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_some() => {
                         sway::TypeName::Identifier {
                             name: "Identity".into(),
                             generic_parameters: None,
@@ -81,8 +82,8 @@ pub fn translate_event_definition(
                     }
 
                     type_name => type_name,
-                }
-            }).collect(),
+                })
+            }).collect::<Result<Vec<_>, Error>>()?,
         }
     };
 
@@ -113,6 +114,7 @@ pub fn translate_event_definition(
     };
 
     let variant = sway::EnumVariant {
+        doc_comment: project.get_doc_comment(&translated_definition.path, &event_definition.loc),
         name: event_definition.name.as_ref().unwrap().name.clone(),
         type_name,
     };
@@ -133,12 +135,12 @@ pub fn translate_error_definition(
     let errors_enum_name = format!("{}Error", translated_definition.name);
 
     let type_name = if error_definition.fields.len() == 1 {
-        translate_type_name(project, translated_definition, &error_definition.fields[0].ty, false, false)
+        translate_type_name(project, translated_definition, &error_definition.fields[0].ty, false, false)?
     } else {
         sway::TypeName::Tuple {
             type_names: error_definition.fields.iter().map(|f| {
                 translate_type_name(project, translated_definition, &f.ty, false, false)
-            }).collect(),
+            }).collect::<Result<Vec<_>, _>>()?,
         }
     };
 
@@ -169,6 +171,7 @@ pub fn translate_error_definition(
     };
 
     let variant = sway::EnumVariant {
+        doc_comment: project.get_doc_comment(&translated_definition.path, &error_definition.loc),
         name: error_definition.name.as_ref().unwrap().name.clone(),
         type_name,
     };
@@ -213,7 +216,7 @@ pub fn generate_enum_abi_encode_function(
             ],
         })));
 
-        let mut add_encode_statement_to_block = |name: &str, type_name: &sway::TypeName| {
+        let mut add_encode_statement_to_block = |name: &str, type_name: &sway::TypeName| -> Result<(), Error> {
             block.statements.push(sway::Statement::from(match type_name {
                 sway::TypeName::Identifier { name: type_name, .. } => match type_name.as_str() {
                     "bool" | "I8" | "I16" | "I32" | "I64" | "I128" | "I256" | "u8" | "u16" | "u32" | "u64" | "u256" | "b256" | "Bytes" | "Vec" => sway::Expression::from(sway::FunctionCall {
@@ -259,7 +262,7 @@ pub fn generate_enum_abi_encode_function(
                         })
                     },
 
-                    _ => todo!("encode enum member type: {type_name}"),
+                    _ => return Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("encode enum member type: {type_name}"))),
                 }
                 
                 sway::TypeName::StringSlice => sway::Expression::from(sway::FunctionCall {
@@ -280,8 +283,10 @@ pub fn generate_enum_abi_encode_function(
                     ],
                 }),
 
-                _ => todo!("ABI encoding for enum parameter type: {type_name}"),
+                _ => return Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("ABI encoding for enum parameter type: {type_name}"))),
             }));
+
+            Ok(())
         };
 
         let parameter_count = match &variant.type_name {
@@ -295,19 +300,19 @@ pub fn generate_enum_abi_encode_function(
             .collect();
 
         match &variant.type_name {
-            sway::TypeName::Undefined => panic!("Undefined type name"),
+            sway::TypeName::Undefined => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, "Undefined type name".into())),
             
-            sway::TypeName::Identifier { .. } => add_encode_statement_to_block(&parameter_names[0], &variant.type_name),
+            sway::TypeName::Identifier { .. } => add_encode_statement_to_block(&parameter_names[0], &variant.type_name)?,
             
             sway::TypeName::Tuple { type_names } => {
                 for (name, type_name) in parameter_names.iter().zip(type_names) {
-                    add_encode_statement_to_block(name.as_str(), type_name);
+                    add_encode_statement_to_block(name.as_str(), type_name)?;
                 }
             }
 
-            sway::TypeName::StringSlice => add_encode_statement_to_block(&parameter_names[0], &variant.type_name),
+            sway::TypeName::StringSlice => add_encode_statement_to_block(&parameter_names[0], &variant.type_name)?,
 
-            type_name => todo!("ABI encoding for enum parameter type: {type_name}"),
+            type_name => return Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("ABI encoding for enum parameter type: {type_name}"))),
         }
 
         match_expr.branches.push(sway::MatchBranch {
@@ -329,6 +334,7 @@ pub fn generate_enum_abi_encode_function(
 
     // Add the `abi_encode` function to the `core::codec::AbiEncode` impl
     abi_encode_impl.items.push(sway::ImplItem::Function(sway::Function {
+        doc_comment: None,
         attributes: None,
         is_public: false,
         name: "abi_encode".into(),
@@ -358,6 +364,7 @@ pub fn generate_enum_abi_encode_function(
             ],
             final_expr: None,
         }),
+        location: None,
     }));
 
     Ok(())
@@ -1,19 +1,19 @@
-use super::{translate_type_name, TranslatedDefinition, TranslatedVariable, TranslationScope};
-use crate::{project::Project, sway, translate::resolve_import, Error};
+use super::{get_int_storage_bits, parse_fixed_point_type_name, translate_type_name, TranslatedDefinition, TranslatedFunction, TranslatedVariable, TranslationScope};
+use crate::{project::{Project, SymbolKind}, report::ReportEntryKind, sway, translate::{resolve_expression_import_aliases, resolve_import}, Error};
 use convert_case::Case;
-use num_bigint::BigUint;
-use num_traits::{Num, One, Zero};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{Num, One, Signed, Zero};
 use solang_parser::{helpers::CodeLocation, pt as solidity};
-use std::{cell::RefCell, rc::Rc};
+use std::sync::{Arc, RwLock};
 
 pub fn create_value_expression(
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     type_name: &sway::TypeName,
     value: Option<&sway::Expression>,
-) -> sway::Expression {
-    match type_name {
-        sway::TypeName::Undefined => panic!("Undefined type name"),
+) -> Result<sway::Expression, Error> {
+    Ok(match type_name {
+        sway::TypeName::Undefined => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, "Undefined type name".into())),
         
         sway::TypeName::Identifier { name, .. } => match name.as_str() {
             "bool" => match value {
@@ -22,15 +22,15 @@ pub fn create_value_expression(
                 
                 Some(sway::Expression::UnaryExpression(unary_expression)) => match unary_expression.operator.as_str() {
                     "!" => value.unwrap().clone(),
-                    _ => panic!("Invalid bool value expression: {value:#?}"),
+                    _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid bool value expression: {}", sway::TabbedDisplayer(value.unwrap())))),
                 }
                 
                 Some(sway::Expression::BinaryExpression(binary_expression)) => match binary_expression.operator.as_str() {
                     "==" | "!=" | ">" | "<" | ">=" | "<=" | "&&" | "||" => value.unwrap().clone(),
-                    _ => panic!("Invalid bool value expression: {value:#?}"),
+                    _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid bool value expression: {}", sway::TabbedDisplayer(value.unwrap())))),
                 }
 
-                Some(value) => panic!("Invalid bool value expression: {value:#?}"),
+                Some(value) => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid bool value expression: {}", sway::TabbedDisplayer(value)))),
             }
 
             "b256" => match value {
@@ -43,7 +43,7 @@ pub fn create_value_expression(
 
                 Some(value) => {
                     if matches!(value, sway::Expression::Literal(sway::Literal::DecInt(_) | sway::Literal::HexInt(_))) {
-                        return value.clone();
+                        return Ok(value.clone());
                     }
 
                     let value_type_name = translated_definition.get_expression_type(scope.clone(), value).unwrap();
@@ -51,23 +51,27 @@ pub fn create_value_expression(
                     match value_type_name {
                         sway::TypeName::Identifier { name, generic_parameters } => match (name.as_str(), generic_parameters.as_ref()) {
                             ("b256", None) => value.clone(),
-                            _ => panic!("Invalid {name} value expression: {value:#?}"),
+                            _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {}", sway::TabbedDisplayer(value)))),
                         }
 
-                        _ => panic!("Invalid {name} value expression: {value:#?}"),
+                        _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {}", sway::TabbedDisplayer(value)))),
                     }
                 }
             }
 
             "I8" | "I16" | "I32" | "I64" | "I128" | "I256" => {
+                let bits = type_name.int_bits().unwrap();
+
                 let value = match value.as_ref() {
                     Some(value) => *value,
-                    None => return sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+                    None => return Ok(create_signed_literal_expression(translated_definition, bits, &BigUint::zero(), false)),
                 };
 
+                if let Some((literal_value, negative)) = get_integer_literal_value(value) {
+                    return Ok(create_signed_literal_expression(translated_definition, bits, &literal_value, negative));
+                }
+
                 match value {
-                    _ if matches!(value, sway::Expression::Literal(sway::Literal::DecInt(_) | sway::Literal::HexInt(_))) => (*value).clone(),
-                    
                     sway::Expression::FunctionCall(function_call) => match &function_call.function {
                         sway::Expression::Identifier(name) if name == "todo!" => value.clone(),
     
@@ -75,7 +79,7 @@ pub fn create_value_expression(
                             let value_type_name = translated_definition.get_expression_type(scope.clone(), value).unwrap();
         
                             if !value_type_name.is_int() {
-                                panic!("Invalid {name} value expression: {value:#?} ({value_type_name})")
+                                return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {} ({value_type_name})", sway::TabbedDisplayer(value))))
                             }
         
                             (*value).clone()
@@ -85,12 +89,12 @@ pub fn create_value_expression(
                     x if matches!(x, sway::Expression::BinaryExpression(_)) => (*x).clone(),
     
                     sway::Expression::Identifier(name) => {
-                        let Some(variable) = scope.borrow().get_variable_from_new_name(name) else {
-                            panic!("error: Variable not found in scope: \"{name}\"");
+                        let Some(variable) = scope.read().unwrap().get_variable_from_new_name(name) else {
+                            return Err(Error::UnresolvedReference(solidity::Loc::Implicit, format!("Variable not found in scope: \"{name}\"")));
                         };
                 
-                        if variable.borrow().type_name != *type_name {
-                            panic!("Invalid {name} value expression: {value:#?}");
+                        if variable.read().unwrap().type_name != *type_name {
+                            return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {}", sway::TabbedDisplayer(value))));
                         }
     
                         sway::Expression::Identifier(name.clone())
@@ -100,7 +104,7 @@ pub fn create_value_expression(
                         let value_type_name = translated_definition.get_expression_type(scope.clone(), value).unwrap();
     
                         if value_type_name != *type_name {
-                            panic!("Invalid {name} value expression: {value:#?}")
+                            return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {}", sway::TabbedDisplayer(value))))
                         }
     
                         (*value).clone()
@@ -118,7 +122,7 @@ pub fn create_value_expression(
                         "todo!" => value.unwrap().clone(),
                         s if s == format!("{name}::max") => value.unwrap().clone(),
                         s if s == format!("{name}::min") => value.unwrap().clone(),
-                        _ => panic!("Invalid {name} value expression: {value:#?}"),
+                        _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {}", sway::TabbedDisplayer(value.unwrap())))),
                     }
 
                     sway::Expression::MemberAccess(member_access) => match member_access.member.as_str() {
@@ -126,33 +130,33 @@ pub fn create_value_expression(
                             let type_name = translated_definition.get_expression_type(scope.clone(), &member_access.expression).unwrap();
                     
                             if !type_name.is_uint() {
-                                panic!("Invalid {name} value expression: {value:#?}")
+                                return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {}", sway::TabbedDisplayer(value.unwrap()))))
                             }
                     
                             value.unwrap().clone()
                         }
                     
-                        _ => panic!("Invalid {name} value expression: {value:#?}"),
+                        _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {}", sway::TabbedDisplayer(value.unwrap())))),
                     }
 
-                    _ => panic!("Invalid {name} value expression: {value:#?}"),
+                    _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {}", sway::TabbedDisplayer(value.unwrap())))),
                 }
 
                 Some(x) if matches!(x, sway::Expression::BinaryExpression(_)) => (*x).clone(),
 
                 Some(sway::Expression::Identifier(name)) => {
-                    let Some(variable) = scope.borrow().get_variable_from_new_name(name) else {
-                        panic!("error: Variable not found in scope: \"{name}\"");
+                    let Some(variable) = scope.read().unwrap().get_variable_from_new_name(name) else {
+                        return Err(Error::UnresolvedReference(solidity::Loc::Implicit, format!("Variable not found in scope: \"{name}\"")));
                     };
             
-                    if variable.borrow().type_name != *type_name {
-                        panic!("Invalid {name} value expression: {value:#?}");
+                    if variable.read().unwrap().type_name != *type_name {
+                        return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {}", sway::TabbedDisplayer(value.unwrap()))));
                     }
 
                     sway::Expression::Identifier(name.clone())
                 }
                 
-                Some(value) => panic!("Invalid {name} value expression: {value:#?}"),
+                Some(value) => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {name} value expression: {}", sway::TabbedDisplayer(*value)))),
             }
 
             "Bytes" => sway::Expression::from(sway::FunctionCall {
@@ -193,7 +197,7 @@ pub fn create_value_expression(
                     fields: vec![],
                 }),
 
-                Some(value) => panic!("Invalid StorageMap value expression: {value:#?}"),
+                Some(value) => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid StorageMap value expression: {}", sway::TabbedDisplayer(value)))),
             }
 
             "StorageString" => match value {
@@ -205,7 +209,7 @@ pub fn create_value_expression(
                     fields: vec![],
                 }),
 
-                Some(value) => panic!("Invalid StorageString value expression: {value:#?}"),
+                Some(value) => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid StorageString value expression: {}", sway::TabbedDisplayer(value)))),
             }
 
             "StorageVec" => match value {
@@ -217,7 +221,7 @@ pub fn create_value_expression(
                     fields: vec![],
                 }),
 
-                Some(value) => panic!("Invalid StorageVec value expression: {value:#?}"),
+                Some(value) => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid StorageVec value expression: {}", sway::TabbedDisplayer(value)))),
             }
 
             "Vec" => sway::Expression::from(sway::FunctionCall {
@@ -245,34 +249,38 @@ pub fn create_value_expression(
                         return create_value_expression(translated_definition, scope, &underlying_type, value);
                     };
 
-                    return sway::Expression::Identifier(format!("{}::{}", name, value.name));
+                    return Ok(sway::Expression::Identifier(format!("{}::{}", name, value.name)));
+                }
+                // Check to see if the type is an enum of internal functions, which are unassigned by default
+                else if translated_definition.function_enums.iter().any(|(e, _)| e.name == *name) {
+                    return Ok(value.cloned().unwrap_or_else(|| sway::Expression::Identifier(format!("{name}::Unassigned"))));
                 }
                 // Check to see if the type is a struct definition
                 else if let Some(struct_definition) = translated_definition.structs.iter().find(|s| s.name == *name).cloned() {
-                    return sway::Expression::from(sway::Constructor {
+                    return Ok(sway::Expression::from(sway::Constructor {
                         type_name: sway::TypeName::Identifier {
                             name: name.to_string(),
                             generic_parameters: None,
                         },
-                        fields: struct_definition.fields.iter().map(|f| sway::ConstructorField {
+                        fields: struct_definition.fields.iter().map(|f| Ok(sway::ConstructorField {
                             name: f.name.clone(),
-                            value: create_value_expression(translated_definition, scope.clone(), &f.type_name, value)
-                        }).collect(),
-                    });
+                            value: create_value_expression(translated_definition, scope.clone(), &f.type_name, value)?,
+                        })).collect::<Result<Vec<_>, _>>()?,
+                    }));
                 }
 
-                panic!("Unknown value type: {type_name}")
+                return Err(Error::UnresolvedReference(solidity::Loc::Implicit, format!("Unknown value type: {type_name}")))
             }
         },
 
         sway::TypeName::Array { type_name, length } => match value {
             None => sway::Expression::Array(sway::Array {
-                elements: (0..*length).map(|_| create_value_expression(translated_definition, scope.clone(), type_name, None)).collect(),
+                elements: (0..*length).map(|_| create_value_expression(translated_definition, scope.clone(), type_name, None)).collect::<Result<Vec<_>, _>>()?,
             }),
 
             Some(sway::Expression::Array(value)) => {
                 if value.elements.len() != *length {
-                    panic!("Invalid array value expression, expected {} elements, found {}: {value:#?}", *length, value.elements.len());
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid array value expression, expected {} elements, found {}: {}", sway::TabbedDisplayer(value), *length, value.elements.len())));
                 }
 
                 sway::Expression::Array(value.clone())
@@ -280,7 +288,7 @@ pub fn create_value_expression(
 
             Some(sway::Expression::Literal(sway::Literal::String(s))) => {
                 if s.len() != *length {
-                    panic!("Invalid array value string, expected {} characters, found {}: \"{s}\"", *length, s.len());
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid array value string, expected {} characters, found {}: \"{s}\"", *length, s.len())));
                 }
 
                 sway::Expression::Array(sway::Array {
@@ -297,30 +305,30 @@ pub fn create_value_expression(
                             })
                         }
 
-                        _ => panic!("Invalid {type_name} array value expression: {value:#?}"),
+                        _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {type_name} array value expression: {}", sway::TabbedDisplayer(value)))),
                     }
 
-                    _ => panic!("Invalid {type_name} array value expression: {value:#?}"),
+                    _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {type_name} array value expression: {}", sway::TabbedDisplayer(value)))),
                 }
 
-                _ => panic!("Invalid {type_name} array value expression: {value:#?}"),
+                _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid {type_name} array value expression: {}", sway::TabbedDisplayer(value)))),
             }
         }
 
         sway::TypeName::Tuple { type_names } => match value {
             None => sway::Expression::Tuple(
-                type_names.iter().map(|type_name| create_value_expression(translated_definition, scope.clone(), type_name, None)).collect()
+                type_names.iter().map(|type_name| create_value_expression(translated_definition, scope.clone(), type_name, None)).collect::<Result<Vec<_>, _>>()?
             ),
 
             Some(sway::Expression::Tuple(value)) if value.len() == type_names.len() => sway::Expression::Tuple(value.clone()),
 
-            Some(value) => panic!("Invalid tuple value expression: {value:#?}"),
+            Some(value) => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid tuple value expression: {}", sway::TabbedDisplayer(value)))),
         }
 
         sway::TypeName::StringSlice => match value {
             None => sway::Expression::from(sway::Literal::String(String::new())),
             Some(sway::Expression::Literal(sway::Literal::String(value))) => sway::Expression::from(sway::Literal::String(value.clone())),
-            Some(value) => panic!("Invalid string slice value expression: {value:#?}"),
+            Some(value) => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid string slice value expression: {}", sway::TabbedDisplayer(value)))),
         }
 
         sway::TypeName::StringArray { length } => match value {
@@ -334,71 +342,1008 @@ pub fn create_value_expression(
 
             Some(sway::Expression::Literal(sway::Literal::String(value))) => {
                 if value.len() > *length {
-                    panic!("Invalid string value expression, string is {} characters long, expected {}: {value}", value.len(), *length);
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid string value expression, string is {} characters long, expected {}: {value}", value.len(), *length)));
+                }
+
+                let mut value = value.clone();
+
+                while value.len() < *length {
+                    value.push(' ');
+                }
+
+                sway::Expression::FunctionCall(Box::new(sway::FunctionCall {
+                    function: sway::Expression::Identifier("__to_str_array".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::Literal(sway::Literal::String(value)),
+                    ],
+                }))
+            }
+
+            Some(sway::Expression::FunctionCall(f)) => {
+                let sway::Expression::Identifier(id) = &f.function else {
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid string value expression, expected `__to_str_array` function call, found: {}", sway::TabbedDisplayer(value.unwrap()))));
+                };
+
+                if id != "__to_str_array" {
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid string value expression, expected `__to_str_array` function call, found: {}", sway::TabbedDisplayer(value.unwrap()))));
+                }
+
+                if f.parameters.len() != 1 {
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid string value expression, invalid parameters supplied to `__to_str_array` function call, found: {}", sway::TabbedDisplayer(value.unwrap()))));
+                }
+
+                let sway::Expression::Literal(sway::Literal::String(value)) = &f.parameters[0] else {
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid string value expression, expected string literal to be supplied to `__to_str_array` function call, found: {}", sway::TabbedDisplayer(value.unwrap()))));
+                };
+
+                if value.len() > *length {
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid string value expression, string is {} characters long, expected {}: {value}", value.len(), *length)));
+                }
+
+                let mut value = value.clone();
+
+                while value.len() < *length {
+                    value.push(' ');
+                }
+
+                sway::Expression::FunctionCall(Box::new(sway::FunctionCall {
+                    function: sway::Expression::Identifier("__to_str_array".into()),
+                    generic_parameters: None,
+                    parameters: vec![
+                        sway::Expression::Literal(sway::Literal::String(value)),
+                    ],
+                }))
+            }
+
+            Some(value) => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Invalid string array value expression: {}", sway::TabbedDisplayer(value)))),
+        }
+    })
+}
+
+/// Creates a `todo!()` expression in place of the code at `loc` and records it in the project's report.
+#[inline]
+pub fn create_todo_expression(
+    project: &mut Project,
+    translated_definition: &TranslatedDefinition,
+    loc: &solidity::Loc,
+    message: Option<String>,
+) -> sway::Expression {
+    project.report_unsupported(translated_definition, loc, ReportEntryKind::Todo, message.clone().unwrap_or_default());
+    sway::Expression::create_todo(message)
+}
+
+/// Creates an `unimplemented!()` expression in place of the code at `loc` and records it in the project's report.
+#[inline]
+pub fn create_unimplemented_expression(
+    project: &mut Project,
+    translated_definition: &TranslatedDefinition,
+    loc: &solidity::Loc,
+    message: Option<String>,
+) -> sway::Expression {
+    project.report_unsupported(translated_definition, loc, ReportEntryKind::Unimplemented, message.clone().unwrap_or_default());
+    sway::Expression::create_unimplemented(message)
+}
+
+/// Gets the value of an integer literal expression or a signed integer literal, along with whether or not it is negative.
+pub fn get_integer_literal_value(expression: &sway::Expression) -> Option<(BigUint, bool)> {
+    match expression {
+        sway::Expression::Literal(sway::Literal::DecInt(value) | sway::Literal::HexInt(value)) => Some((value.clone(), false)),
+
+        // x.neg()
+        sway::Expression::FunctionCall(function_call) if function_call.parameters.is_empty() => {
+            let sway::Expression::MemberAccess(member_access) = &function_call.function else { return None };
+
+            if member_access.member != "neg" {
+                return None;
+            }
+
+            let (value, negative) = get_integer_literal_value(&member_access.expression)?;
+            Some((value, !negative))
+        }
+
+        // I{bits} { underlying: x }
+        sway::Expression::Constructor(constructor) if constructor.fields.len() == 1 && constructor.fields[0].name == "underlying" => {
+            let bits = constructor.type_name.int_bits()?;
+
+            let sway::Expression::Literal(sway::Literal::DecInt(underlying) | sway::Literal::HexInt(underlying)) = &constructor.fields[0].value else {
+                return None;
+            };
+
+            // The value is offset by `indent()`
+            let indent = BigUint::one() << (bits - 1);
+
+            if *underlying >= indent {
+                Some((underlying - indent, false))
+            } else {
+                Some((indent - underlying, true))
+            }
+        }
+
+        _ => None,
+    }
+}
+
+/// Gets the exact value of a number literal with its exponent and unit (i.e. `2.5e18` or `0.5 ether`) as a `(numerator, denominator)` pair.
+fn get_number_literal_value(integer: &str, fraction: &str, exponent: &str, unit: Option<&solidity::Identifier>) -> Option<(BigInt, BigUint)> {
+    let digits = format!("{integer}{fraction}");
+    let ten = BigUint::from(10u8);
+
+    let mut numerator = if digits.is_empty() { BigUint::zero() } else { digits.parse::<BigUint>().ok()? };
+    let mut denominator = ten.pow(fraction.len() as u32);
+
+    let exponent = if exponent.is_empty() { 0 } else { exponent.parse::<i32>().ok()? };
+
+    if exponent >= 0 {
+        numerator *= ten.pow(exponent.unsigned_abs());
+    } else {
+        denominator *= ten.pow(exponent.unsigned_abs());
+    }
+
+    if let Some(unit) = unit {
+        numerator *= match unit.name.as_str() {
+            "wei" | "seconds" => BigUint::one(),
+            "gwei" => ten.pow(9),
+            "szabo" => ten.pow(12),
+            "finney" => ten.pow(15),
+            "ether" => ten.pow(18),
+            "minutes" => BigUint::from(60u32),
+            "hours" => BigUint::from(3_600u32),
+            "days" => BigUint::from(86_400u32),
+            "weeks" => BigUint::from(604_800u32),
+            "years" => BigUint::from(31_536_000u32),
+            _ => return None,
+        };
+    }
+
+    Some((BigInt::from(numerator), denominator))
+}
+
+/// Evaluates a constant expression of number literals with exact rational arithmetic, like Solidity does at compile time.
+/// Returns the value as a `(numerator, denominator)` pair, or `None` if the expression isn't constant.
+pub fn evaluate_rational_expression(expression: &solidity::Expression) -> Option<(BigInt, BigUint)> {
+    match expression {
+        solidity::Expression::NumberLiteral(_, integer, exponent, unit) => get_number_literal_value(integer, "", exponent, unit.as_ref()),
+        solidity::Expression::RationalNumberLiteral(_, integer, fraction, exponent, unit) => get_number_literal_value(integer, fraction, exponent, unit.as_ref()),
+
+        solidity::Expression::HexNumberLiteral(_, value, None) => {
+            Some((BigInt::from(BigUint::from_str_radix(&value.trim_start_matches("0x").replace('_', ""), 16).ok()?), BigUint::one()))
+        }
+
+        solidity::Expression::Parenthesis(_, x) | solidity::Expression::UnaryPlus(_, x) => evaluate_rational_expression(x),
+
+        solidity::Expression::Negate(_, x) => {
+            let (numerator, denominator) = evaluate_rational_expression(x)?;
+            Some((-numerator, denominator))
+        }
+
+        solidity::Expression::Add(_, lhs, rhs) | solidity::Expression::Subtract(_, lhs, rhs) => {
+            let (lhs_numerator, lhs_denominator) = evaluate_rational_expression(lhs)?;
+            let (rhs_numerator, rhs_denominator) = evaluate_rational_expression(rhs)?;
+
+            let lhs_numerator = lhs_numerator * BigInt::from(rhs_denominator.clone());
+            let rhs_numerator = rhs_numerator * BigInt::from(lhs_denominator.clone());
+
+            Some((
+                if matches!(expression, solidity::Expression::Add(..)) { lhs_numerator + rhs_numerator } else { lhs_numerator - rhs_numerator },
+                lhs_denominator * rhs_denominator,
+            ))
+        }
+
+        solidity::Expression::Multiply(_, lhs, rhs) => {
+            let (lhs_numerator, lhs_denominator) = evaluate_rational_expression(lhs)?;
+            let (rhs_numerator, rhs_denominator) = evaluate_rational_expression(rhs)?;
+            Some((lhs_numerator * rhs_numerator, lhs_denominator * rhs_denominator))
+        }
+
+        solidity::Expression::Divide(_, lhs, rhs) => {
+            let (lhs_numerator, lhs_denominator) = evaluate_rational_expression(lhs)?;
+            let (rhs_numerator, rhs_denominator) = evaluate_rational_expression(rhs)?;
+
+            if rhs_numerator.is_zero() {
+                return None;
+            }
+
+            // Keep the sign in the numerator
+            let numerator = lhs_numerator * BigInt::from(rhs_denominator);
+            let numerator = if rhs_numerator.is_negative() { -numerator } else { numerator };
+
+            Some((numerator, lhs_denominator * rhs_numerator.magnitude()))
+        }
+
+        solidity::Expression::Power(_, lhs, rhs) => {
+            let (lhs_numerator, lhs_denominator) = evaluate_rational_expression(lhs)?;
+            let (rhs_numerator, rhs_denominator) = evaluate_rational_expression(rhs)?;
+
+            // Only integer exponents which don't produce values wider than 256 bits are constant
+            if rhs_numerator.is_negative() || !(rhs_numerator.magnitude() % &rhs_denominator).is_zero() {
+                return None;
+            }
+
+            let exponent = u32::try_from(rhs_numerator.magnitude() / &rhs_denominator).ok().filter(|x| *x <= 256)?;
+
+            Some((lhs_numerator.pow(exponent), lhs_denominator.pow(exponent)))
+        }
+
+        _ => None,
+    }
+}
+
+/// Checks if a constant expression needs rational arithmetic to be evaluated, i.e. it contains a rational number literal or a division.
+fn is_rational_expression(expression: &solidity::Expression) -> bool {
+    match expression {
+        solidity::Expression::RationalNumberLiteral(..) | solidity::Expression::Divide(..) => true,
+
+        solidity::Expression::Parenthesis(_, x)
+        | solidity::Expression::UnaryPlus(_, x)
+        | solidity::Expression::Negate(_, x) => is_rational_expression(x),
+
+        solidity::Expression::Add(_, lhs, rhs)
+        | solidity::Expression::Subtract(_, lhs, rhs)
+        | solidity::Expression::Multiply(_, lhs, rhs)
+        | solidity::Expression::Power(_, lhs, rhs) => is_rational_expression(lhs) || is_rational_expression(rhs),
+
+        _ => false,
+    }
+}
+
+/// Creates an integer literal expression from the value of a constant `expression`, which must be an integer.
+/// Negative values are `int256` literals, like the type Solidity gives them when nothing else does.
+fn create_rational_literal_expression(
+    translated_definition: &mut TranslatedDefinition,
+    expression: &solidity::Expression,
+    numerator: BigInt,
+    denominator: BigUint,
+) -> Result<sway::Expression, Error> {
+    if !(numerator.magnitude() % &denominator).is_zero() {
+        return Err(Error::UnsupportedConstruct(expression.loc(), format!("rational number which is not an integer: {expression}")));
+    }
+
+    let value = numerator.magnitude() / denominator;
+
+    if numerator.sign() == Sign::Minus {
+        translated_definition.ensure_use_declared("signed_integers::i256::*");
+        return Ok(create_signed_literal_expression(translated_definition, 256, &value, true));
+    }
+
+    Ok(sway::Expression::from(sway::Literal::DecInt(value)))
+}
+
+/// Translates a constant number expression which is assigned to a fixed point type into an integer literal of the type's representation,
+/// which is the value scaled by the type's decimals (i.e. `1.5` as `ufixed128x18` is `1500000000000000000`).
+/// Returns `None` if the type isn't a fixed point type or the expression isn't constant.
+pub fn translate_fixed_point_literal_expression(
+    translated_definition: &mut TranslatedDefinition,
+    type_name: &sway::TypeName,
+    expression: &solidity::Expression,
+) -> Result<Option<sway::Expression>, Error> {
+    let Some(decimals) = type_name.fixed_point_decimals() else { return Ok(None) };
+    let Some((numerator, denominator)) = evaluate_rational_expression(expression) else { return Ok(None) };
+
+    let numerator = numerator * BigInt::from(BigUint::from(10u8).pow(decimals as u32));
+
+    if !(numerator.magnitude() % &denominator).is_zero() {
+        return Err(Error::InvalidConstruct(expression.loc(), format!("Number literal has more decimals than {type_name}: {expression}")));
+    }
+
+    let value = numerator.magnitude() / denominator;
+    let negative = numerator.sign() == Sign::Minus;
+    let underlying_type_name = translated_definition.get_underlying_type(type_name);
+
+    if let Some(bits) = underlying_type_name.int_bits() {
+        return Ok(Some(create_signed_literal_expression(translated_definition, bits, &value, negative)));
+    }
+
+    match underlying_type_name.uint_bits() {
+        Some(bits) if !negative => Ok(Some(create_uint_literal_expression(translated_definition, bits, value))),
+        _ => Err(Error::InvalidConstruct(expression.loc(), format!("Invalid {type_name} value: {expression}"))),
+    }
+}
+
+/// Creates a literal of `10 ** decimals` in the integer representation of a fixed point or integer type.
+fn create_decimal_scale_expression(translated_definition: &mut TranslatedDefinition, type_name: &sway::TypeName, decimals: usize) -> Result<sway::Expression, Error> {
+    let value = BigUint::from(10u8).pow(decimals as u32);
+    let underlying_type_name = translated_definition.get_underlying_type(type_name);
+
+    if let Some(bits) = underlying_type_name.int_bits() {
+        return Ok(create_signed_literal_expression(translated_definition, bits, &value, false));
+    }
+
+    match underlying_type_name.uint_bits() {
+        Some(bits) => Ok(create_uint_literal_expression(translated_definition, bits, value)),
+        None => Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Expected integer or fixed point type, found {type_name}"))),
+    }
+}
+
+/// Translates the operands of a binary expression. When one of them is a fixed point value, a constant operand is translated
+/// into a literal of its type (i.e. `1` as `ufixed128x18` is `1000000000000000000`), and the fixed point type is returned along with the operands.
+fn translate_binary_operands(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    lhs: &solidity::Expression,
+    rhs: &solidity::Expression,
+) -> Result<(sway::Expression, sway::Expression, Option<sway::TypeName>), Error> {
+    let fixed_point_type_name = |translated_definition: &mut TranslatedDefinition, expression: &sway::Expression| {
+        translated_definition.get_expression_type(scope.clone(), expression).ok()
+            .filter(|type_name| type_name.fixed_point_decimals().is_some())
+    };
+
+    // Translate the constant operand last, so it can take the type of the other operand
+    if evaluate_rational_expression(lhs).is_some() && evaluate_rational_expression(rhs).is_none() {
+        let rhs = translate_expression(project, translated_definition, scope.clone(), rhs)?;
+        let type_name = fixed_point_type_name(translated_definition, &rhs);
+
+        let lhs = match type_name.as_ref() {
+            Some(type_name) => translate_fixed_point_literal_expression(translated_definition, type_name, lhs)?.unwrap(),
+            None => translate_expression(project, translated_definition, scope.clone(), lhs)?,
+        };
+
+        return Ok((lhs, rhs, type_name));
+    }
+
+    let lhs = translate_expression(project, translated_definition, scope.clone(), lhs)?;
+    let type_name = fixed_point_type_name(translated_definition, &lhs);
+
+    let rhs = match type_name.as_ref() {
+        Some(type_name) if evaluate_rational_expression(rhs).is_some() => translate_fixed_point_literal_expression(translated_definition, type_name, rhs)?.unwrap(),
+        _ => translate_expression(project, translated_definition, scope.clone(), rhs)?,
+    };
+
+    let type_name = type_name.or_else(|| fixed_point_type_name(translated_definition, &rhs));
+
+    Ok((lhs, rhs, type_name))
+}
+
+/// Creates a multiplication or division of fixed point values, which rescales the result by the decimals of the type:
+/// `a * b` is translated to `a * b / 10 ** decimals` and `a / b` to `a * 10 ** decimals / b`.
+/// Types narrower than 256 bits go through a generated function like `fixed128x18_mul`, which widens the operands so the intermediate product doesn't overflow.
+/// Returns `None` for other operators, which don't need rescaling.
+fn create_fixed_point_binary_expression(
+    translated_definition: &mut TranslatedDefinition,
+    type_name: &sway::TypeName,
+    operator: &str,
+    lhs: sway::Expression,
+    rhs: sway::Expression,
+) -> Result<Option<sway::Expression>, Error> {
+    if !matches!(operator, "*" | "/") {
+        return Ok(None);
+    }
+
+    let underlying_type_name = translated_definition.get_underlying_type(type_name);
+
+    if underlying_type_name.int_bits().or_else(|| underlying_type_name.uint_bits()) != Some(256) {
+        let function_name = translated_definition.ensure_fixed_point_function_declared(operator, type_name)?;
+
+        return Ok(Some(sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::Identifier(function_name),
+            generic_parameters: None,
+            parameters: vec![lhs, rhs],
+        })));
+    }
+
+    let decimals = type_name.fixed_point_decimals().unwrap();
+    let scale = create_decimal_scale_expression(translated_definition, type_name, decimals)?;
+
+    let operand = |expression: sway::Expression| match expression {
+        sway::Expression::BinaryExpression(_) => sway::Expression::Tuple(vec![expression]),
+        _ => expression,
+    };
+
+    let (lhs, rhs) = (operand(lhs), operand(rhs));
+
+    Ok(Some(sway::Expression::from(sway::BinaryExpression {
+        operator: "/".into(),
+        lhs: sway::Expression::from(sway::BinaryExpression {
+            operator: "*".into(),
+            lhs,
+            rhs: if operator == "*" { rhs.clone() } else { scale.clone() },
+        }),
+        rhs: if operator == "*" { scale } else { rhs },
+    })))
+}
+
+/// Converts an integer or fixed point value of `value_type_name` to the integer or fixed point type `type_name`,
+/// rescaling it by the difference of their decimals. Integer parts are kept and extra decimals are truncated.
+fn create_fixed_point_conversion_expression(
+    translated_definition: &mut TranslatedDefinition,
+    loc: &solidity::Loc,
+    value_type_name: &sway::TypeName,
+    type_name: &sway::TypeName,
+    value: sway::Expression,
+) -> Result<sway::Expression, Error> {
+    let from_decimals = value_type_name.fixed_point_decimals().unwrap_or(0);
+    let to_decimals = type_name.fixed_point_decimals().unwrap_or(0);
+
+    let from_type_name = translated_definition.get_underlying_type(value_type_name);
+    let to_type_name = translated_definition.get_underlying_type(type_name);
+
+    let operand = |expression: sway::Expression| match expression {
+        sway::Expression::BinaryExpression(_) => sway::Expression::Tuple(vec![expression]),
+        _ => expression,
+    };
+
+    let resize = |translated_definition: &mut TranslatedDefinition, value: sway::Expression| {
+        match (from_type_name.int_bits(), to_type_name.int_bits(), from_type_name.uint_bits(), to_type_name.uint_bits()) {
+            (Some(from_bits), Some(to_bits), _, _) => Ok(create_signed_resize_expression(translated_definition, from_bits, to_bits, value)),
+            (_, _, Some(from_bits), Some(to_bits)) => Ok(create_uint_resize_expression(translated_definition, from_bits, to_bits, value)),
+            _ => Err(Error::UnsupportedConstruct(*loc, format!("translate {value_type_name} to {type_name} conversion"))),
+        }
+    };
+
+    if to_decimals < from_decimals {
+        // (value / 10 ** (from_decimals - to_decimals)) resized to the new type
+        let scale = create_decimal_scale_expression(translated_definition, value_type_name, from_decimals - to_decimals)?;
+
+        let value = sway::Expression::from(sway::BinaryExpression {
+            operator: "/".into(),
+            lhs: operand(value),
+            rhs: scale,
+        });
+
+        return resize(translated_definition, value);
+    }
+
+    let value = resize(translated_definition, value)?;
+
+    if to_decimals == from_decimals {
+        return Ok(value);
+    }
+
+    // value resized to the new type * 10 ** (to_decimals - from_decimals)
+    let scale = create_decimal_scale_expression(translated_definition, type_name, to_decimals - from_decimals)?;
+
+    Ok(sway::Expression::from(sway::BinaryExpression {
+        operator: "*".into(),
+        lhs: operand(value),
+        rhs: scale,
+    }))
+}
+
+/// Translates an explicit conversion to a fixed point type, i.e. `ufixed128x18(x)`.
+fn translate_fixed_point_cast_expression(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    expression: &solidity::Expression,
+    type_name: &sway::TypeName,
+    arguments: &[solidity::Expression],
+) -> Result<sway::Expression, Error> {
+    if arguments.len() != 1 {
+        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid type cast: {expression}")));
+    }
+
+    if let Some(value) = translate_fixed_point_literal_expression(translated_definition, type_name, &arguments[0])? {
+        return Ok(value);
+    }
+
+    let value = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
+    let value_type_name = translated_definition.get_expression_type(scope.clone(), &value)?;
+
+    create_fixed_point_conversion_expression(translated_definition, &expression.loc(), &value_type_name, type_name, value)
+}
+
+/// Creates an expression of an unsigned integer literal of the specified bit width.
+pub fn create_uint_literal_expression(translated_definition: &mut TranslatedDefinition, bits: usize, value: BigUint) -> sway::Expression {
+    let literal = |value: BigUint| if value > BigUint::from(u64::MAX) {
+        sway::Expression::from(sway::Literal::HexInt(value))
+    } else {
+        sway::Expression::from(sway::Literal::DecInt(value))
+    };
+
+    if bits != 128 {
+        return literal(value);
+    }
+
+    // Ensure `std::u128::U128` is imported
+    translated_definition.ensure_use_declared("std::u128::U128");
+
+    // U128::from((upper, lower))
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier("U128::from".into()),
+        generic_parameters: None,
+        parameters: vec![
+            sway::Expression::Tuple(vec![
+                literal(&value >> 64),
+                literal(value & BigUint::from(u64::MAX)),
+            ]),
+        ],
+    })
+}
+
+/// Creates an expression of the `indent()` of a signed integer type, which is the offset added to the value stored in its `underlying` field.
+fn create_signed_indent_expression(bits: usize) -> sway::Expression {
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier(format!("I{bits}::indent")),
+        generic_parameters: None,
+        parameters: vec![],
+    })
+}
+
+/// Creates a signed integer expression from the two's complement bits of its value.
+/// The signed integer types of `sway-libs` store their value offset by `indent()`, which is the same as flipping the sign bit of its two's complement bits.
+pub fn create_signed_from_bits_expression(bits: usize, value: sway::Expression) -> sway::Expression {
+    // (x.underlying ^ I{bits}::indent()) ^ I{bits}::indent() => x
+    if let sway::Expression::Tuple(expressions) = &value {
+        if let [sway::Expression::BinaryExpression(binary_expression)] = expressions.as_slice() {
+            if binary_expression.operator == "^" && binary_expression.rhs == create_signed_indent_expression(bits) {
+                if let sway::Expression::MemberAccess(member_access) = &binary_expression.lhs {
+                    if member_access.member == "underlying" {
+                        return member_access.expression.clone();
+                    }
+                }
+            }
+        }
+    }
+
+    // I{bits} { underlying: value ^ I{bits}::indent() }
+    sway::Expression::from(sway::Constructor {
+        type_name: sway::TypeName::Identifier {
+            name: format!("I{bits}"),
+            generic_parameters: None,
+        },
+        fields: vec![
+            sway::ConstructorField {
+                name: "underlying".into(),
+                value: sway::Expression::from(sway::BinaryExpression {
+                    operator: "^".into(),
+                    lhs: match value {
+                        sway::Expression::BinaryExpression(_) | sway::Expression::If(_) => sway::Expression::Tuple(vec![value]),
+                        _ => value,
+                    },
+                    rhs: create_signed_indent_expression(bits),
+                }),
+            },
+        ],
+    })
+}
+
+/// Creates an expression which gets the two's complement bits of the value of a signed integer expression.
+pub fn create_signed_bits_expression(bits: usize, expression: sway::Expression) -> sway::Expression {
+    if let sway::Expression::Constructor(constructor) = &expression {
+        if constructor.type_name.is_int() && constructor.fields.len() == 1 && constructor.fields[0].name == "underlying" {
+            match &constructor.fields[0].value {
+                // I{bits} { underlying: x ^ I{bits}::indent() } => x
+                sway::Expression::BinaryExpression(binary_expression) if binary_expression.operator == "^" && binary_expression.rhs == create_signed_indent_expression(bits) => {
+                    return match &binary_expression.lhs {
+                        value @ sway::Expression::BinaryExpression(_) => sway::Expression::Tuple(vec![value.clone()]),
+                        value => value.clone(),
+                    };
                 }
 
-                let mut value = value.clone();
+                // I{bits} { underlying: literal } => literal ^ (1 << (bits - 1))
+                sway::Expression::Literal(sway::Literal::DecInt(value) | sway::Literal::HexInt(value)) => {
+                    let value = value ^ (BigUint::one() << (bits - 1));
+
+                    return if value > BigUint::from(u64::MAX) {
+                        sway::Expression::from(sway::Literal::HexInt(value))
+                    } else {
+                        sway::Expression::from(sway::Literal::DecInt(value))
+                    };
+                }
+
+                _ => {}
+            }
+        }
+    }
+
+    // (x.underlying ^ I{bits}::indent())
+    sway::Expression::Tuple(vec![
+        sway::Expression::from(sway::BinaryExpression {
+            operator: "^".into(),
+            lhs: sway::Expression::from(sway::MemberAccess {
+                expression: match expression {
+                    sway::Expression::BinaryExpression(_) | sway::Expression::UnaryExpression(_) => sway::Expression::Tuple(vec![expression]),
+                    _ => expression,
+                },
+                member: "underlying".into(),
+            }),
+            rhs: create_signed_indent_expression(bits),
+        }),
+    ])
+}
+
+/// Creates a signed integer literal of the specified bit width, wrapping the value to the width.
+pub fn create_signed_literal_expression(translated_definition: &mut TranslatedDefinition, bits: usize, value: &BigUint, negative: bool) -> sway::Expression {
+    let modulus = BigUint::one() << bits;
+    let mut value = value % &modulus;
+
+    if negative && !value.is_zero() {
+        value = modulus - value;
+    }
+
+    // Flip the sign bit of the two's complement bits to offset the value by `indent()`
+    let underlying = value ^ (BigUint::one() << (bits - 1));
+
+    sway::Expression::from(sway::Constructor {
+        type_name: sway::TypeName::Identifier {
+            name: format!("I{bits}"),
+            generic_parameters: None,
+        },
+        fields: vec![
+            sway::ConstructorField {
+                name: "underlying".into(),
+                value: create_uint_literal_expression(translated_definition, bits, underlying),
+            },
+        ],
+    })
+}
+
+/// Checks if `expression` of `expression_type_name` is implicitly convertible to the signed integer type `type_name`,
+/// i.e. it is an integer literal or a signed integer which is not wider than `type_name`.
+pub fn can_coerce_signed_expression(type_name: &sway::TypeName, expression: &sway::Expression, expression_type_name: &sway::TypeName) -> bool {
+    let Some(bits) = type_name.int_bits() else { return false };
+
+    if get_integer_literal_value(expression).is_some() {
+        return true;
+    }
+
+    expression_type_name.int_bits().is_some_and(|from_bits| from_bits <= bits)
+}
+
+/// Implicitly converts an expression to the signed integer type `type_name`, if it is one.
+/// Integer literals are folded into signed integer literals and smaller signed integers are sign-extended.
+pub fn coerce_signed_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    type_name: &sway::TypeName,
+    expression: sway::Expression,
+) -> sway::Expression {
+    let Some(bits) = translated_definition.get_underlying_type(type_name).int_bits() else { return expression };
+
+    if let Some((value, negative)) = get_integer_literal_value(&expression) {
+        return create_signed_literal_expression(translated_definition, bits, &value, negative);
+    }
+
+    let Ok(expression_type_name) = translated_definition.get_expression_type(scope.clone(), &expression) else { return expression };
+
+    match translated_definition.get_underlying_type(&expression_type_name).int_bits() {
+        Some(from_bits) if from_bits < bits => create_signed_resize_expression(translated_definition, from_bits, bits, expression),
+        _ => expression,
+    }
+}
+
+/// Implicitly converts expressions which need to have a common type, i.e. the elements of an array literal or the branches of a conditional,
+/// to the widest signed integer type among them. Untyped literals, which are `I256` literals when negative, don't determine the common type.
+pub fn coerce_signed_expressions(translated_definition: &mut TranslatedDefinition, scope: Arc<RwLock<TranslationScope>>, expressions: &mut [sway::Expression]) {
+    let type_name = expressions.iter()
+        .filter_map(|x| {
+            let type_name = translated_definition.get_expression_type(scope.clone(), x).ok()?;
+            let type_name = translated_definition.get_underlying_type(&type_name);
+            let bits = type_name.int_bits()?;
+
+            if bits == 256 && get_integer_literal_value(x).is_some() {
+                return None;
+            }
+
+            Some((bits, type_name))
+        })
+        .max_by_key(|(bits, _)| *bits)
+        .map(|(_, type_name)| type_name);
+
+    let Some(type_name) = type_name else { return };
+
+    for expression in expressions.iter_mut() {
+        *expression = coerce_signed_expression(translated_definition, scope.clone(), &type_name, expression.clone());
+    }
+}
+
+/// Converts an unsigned integer expression from one bit width to another, zero-extending or truncating its bits.
+pub fn create_uint_resize_expression(translated_definition: &mut TranslatedDefinition, from_bits: usize, to_bits: usize, value: sway::Expression) -> sway::Expression {
+    let type_name = |bits: usize| if bits == 128 { "U128".to_string() } else { format!("u{bits}") };
+
+    if from_bits == to_bits {
+        return value;
+    }
+
+    if from_bits < to_bits {
+        // u{to_bits}::from(value)
+        return sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::Identifier(format!("{}::from", type_name(to_bits))),
+            generic_parameters: None,
+            parameters: vec![value],
+        });
+    }
+
+    // u{to_bits}::try_from(value & mask).unwrap()
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::from(sway::MemberAccess {
+            expression: sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier(format!("{}::try_from", type_name(to_bits))),
+                generic_parameters: None,
+                parameters: vec![
+                    sway::Expression::from(sway::BinaryExpression {
+                        operator: "&".into(),
+                        lhs: value,
+                        rhs: create_uint_literal_expression(translated_definition, from_bits, (BigUint::one() << to_bits) - BigUint::one()),
+                    }),
+                ],
+            }),
+            member: "unwrap".into(),
+        }),
+        generic_parameters: None,
+        parameters: vec![],
+    })
+}
+
+/// Converts an unsigned integer expression of the `from_bits` Sway type to the Solidity `uint{bits}` type, truncating its value to the width.
+pub fn create_uint_cast_expression(translated_definition: &mut TranslatedDefinition, from_bits: usize, bits: usize, value: sway::Expression) -> sway::Expression {
+    let to_bits = match bits {
+        0..=8 => 8,
+        9..=16 => 16,
+        17..=32 => 32,
+        33..=64 => 64,
+        _ => 256,
+    };
+
+    if bits >= from_bits || bits == to_bits {
+        return create_uint_resize_expression(translated_definition, from_bits, to_bits, value);
+    }
+
+    // value & mask
+    let value = sway::Expression::from(sway::BinaryExpression {
+        operator: "&".into(),
+        lhs: value,
+        rhs: create_uint_literal_expression(translated_definition, from_bits, (BigUint::one() << bits) - BigUint::one()),
+    });
+
+    if from_bits == to_bits {
+        return sway::Expression::Tuple(vec![value]);
+    }
+
+    // u{to_bits}::try_from(value & mask).unwrap()
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::from(sway::MemberAccess {
+            expression: sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier(format!("u{to_bits}::try_from")),
+                generic_parameters: None,
+                parameters: vec![value],
+            }),
+            member: "unwrap".into(),
+        }),
+        generic_parameters: None,
+        parameters: vec![],
+    })
+}
+
+/// Creates a block which binds `value` to a unique variable name, so that it is only evaluated once by the expression created with `f`.
+fn create_bound_value_expression(
+    scope: Arc<RwLock<TranslationScope>>,
+    name: &str,
+    value: sway::Expression,
+    f: impl FnOnce(sway::Expression) -> sway::Expression,
+) -> sway::Expression {
+    if let sway::Expression::Identifier(_) | sway::Expression::Literal(_) = &value {
+        return f(value);
+    }
 
-                while value.len() < *length {
-                    value.push(' ');
-                }
+    let variable_name = scope.read().unwrap().generate_unique_variable_name(name);
 
-                sway::Expression::FunctionCall(Box::new(sway::FunctionCall {
-                    function: sway::Expression::Identifier("__to_str_array".into()),
-                    generic_parameters: None,
-                    parameters: vec![
-                        sway::Expression::Literal(sway::Literal::String(value)),
-                    ],
-                }))
-            }
+    sway::Expression::from(sway::Block {
+        statements: vec![
+            sway::Statement::from(sway::Let {
+                pattern: sway::LetPattern::from(sway::LetIdentifier {
+                    is_mutable: false,
+                    name: variable_name.clone(),
+                }),
+                type_name: None,
+                value,
+            }),
+        ],
+        final_expr: Some(f(sway::Expression::Identifier(variable_name))),
+    })
+}
 
-            Some(sway::Expression::FunctionCall(f)) => {
-                let sway::Expression::Identifier(id) = &f.function else {
-                    panic!("Invalid string value expression, expected `__to_str_array` function call, found: {value:#?}");
-                };
+/// Creates an expression which checks if the sign bit of the two's complement bits of a signed integer is clear.
+fn create_sign_bit_clear_expression(bits: usize, underlying: sway::Expression) -> sway::Expression {
+    // (underlying >> (bits - 1)) == 0
+    sway::Expression::from(sway::BinaryExpression {
+        operator: "==".into(),
+        lhs: sway::Expression::from(sway::BinaryExpression {
+            operator: ">>".into(),
+            lhs: underlying,
+            rhs: sway::Expression::from(sway::Literal::DecInt(BigUint::from(bits - 1))),
+        }),
+        rhs: sway::Expression::from(sway::Literal::DecInt(BigUint::zero())),
+    })
+}
 
-                if id != "__to_str_array" {
-                    panic!("Invalid string value expression, expected `__to_str_array` function call, found: {value:#?}");
-                }
+/// Converts a signed integer expression from one bit width to another with two's complement semantics, sign-extending or truncating its bits.
+pub fn create_signed_resize_expression(
+    translated_definition: &mut TranslatedDefinition,
+    from_bits: usize,
+    to_bits: usize,
+    value: sway::Expression,
+) -> sway::Expression {
+    if from_bits == to_bits {
+        return value;
+    }
 
-                if f.parameters.len() != 1 {
-                    panic!("Invalid string value expression, invalid parameters supplied to `__to_str_array` function call, found: {value:#?}");
-                }
+    if from_bits > to_bits {
+        let value = create_uint_resize_expression(translated_definition, from_bits, to_bits, create_signed_bits_expression(from_bits, value));
+        return create_signed_from_bits_expression(to_bits, value);
+    }
 
-                let sway::Expression::Literal(sway::Literal::String(value)) = &f.parameters[0] else {
-                    panic!("Invalid string value expression, expected string literal to be supplied to `__to_str_array` function call, found: {value:#?}");
-                };
+    // Sign-extending keeps the value, so only the offset by `indent()` grows with the width
+    let offset = create_uint_literal_expression(
+        translated_definition,
+        to_bits,
+        (BigUint::one() << (to_bits - 1)) - (BigUint::one() << (from_bits - 1)),
+    );
 
-                if value.len() > *length {
-                    panic!("Invalid string value expression, string is {} characters long, expected {}: {value}", value.len(), *length);
-                }
+    // I{to_bits} { underlying: u{to_bits}::from(value.underlying) + offset }
+    let underlying = sway::Expression::from(sway::MemberAccess {
+        expression: match value {
+            sway::Expression::BinaryExpression(_) | sway::Expression::UnaryExpression(_) => sway::Expression::Tuple(vec![value]),
+            _ => value,
+        },
+        member: "underlying".into(),
+    });
 
-                let mut value = value.clone();
+    sway::Expression::from(sway::Constructor {
+        type_name: sway::TypeName::Identifier {
+            name: format!("I{to_bits}"),
+            generic_parameters: None,
+        },
+        fields: vec![
+            sway::ConstructorField {
+                name: "underlying".into(),
+                value: sway::Expression::from(sway::BinaryExpression {
+                    operator: "+".into(),
+                    lhs: create_uint_resize_expression(translated_definition, from_bits, to_bits, underlying),
+                    rhs: offset,
+                }),
+            },
+        ],
+    })
+}
 
-                while value.len() < *length {
-                    value.push(' ');
-                }
+/// Converts the two's complement bits of a value to a signed integer width which Sway doesn't have, i.e. `int24`, truncating the bits to the width.
+/// The value is held by the next larger signed integer type, so the truncated bits are sign-extended by offsetting them like `create_signed_resize_expression`:
+///
+/// ```sway
+/// I32 { underlying: ((value & 16777215) ^ 8388608) + 2139095040 }
+/// ```
+pub fn create_signed_truncate_expression(translated_definition: &mut TranslatedDefinition, bits: usize, value: sway::Expression) -> sway::Expression {
+    let storage_bits = get_int_storage_bits(bits);
+    let half = BigUint::one() << (bits - 1);
+
+    let value = sway::Expression::from(sway::BinaryExpression {
+        operator: "&".into(),
+        lhs: match value {
+            sway::Expression::BinaryExpression(_) => sway::Expression::Tuple(vec![value]),
+            _ => value,
+        },
+        rhs: create_uint_literal_expression(translated_definition, storage_bits, (BigUint::one() << bits) - BigUint::one()),
+    });
+
+    let value = sway::Expression::from(sway::BinaryExpression {
+        operator: "^".into(),
+        lhs: sway::Expression::Tuple(vec![value]),
+        rhs: create_uint_literal_expression(translated_definition, storage_bits, half.clone()),
+    });
+
+    sway::Expression::from(sway::Constructor {
+        type_name: sway::TypeName::Identifier {
+            name: format!("I{storage_bits}"),
+            generic_parameters: None,
+        },
+        fields: vec![
+            sway::ConstructorField {
+                name: "underlying".into(),
+                value: sway::Expression::from(sway::BinaryExpression {
+                    operator: "+".into(),
+                    lhs: sway::Expression::Tuple(vec![value]),
+                    rhs: create_uint_literal_expression(translated_definition, storage_bits, (BigUint::one() << (storage_bits - 1)) - half),
+                }),
+            },
+        ],
+    })
+}
 
-                sway::Expression::FunctionCall(Box::new(sway::FunctionCall {
-                    function: sway::Expression::Identifier("__to_str_array".into()),
-                    generic_parameters: None,
-                    parameters: vec![
-                        sway::Expression::Literal(sway::Literal::String(value)),
-                    ],
+/// Creates a binary expression with signed integer semantics for operators which aren't implemented by the signed integer types of `sway-libs`.
+/// Returns `None` if the operator can be used on the signed integer types directly.
+pub fn create_signed_binary_expression(
+    scope: Arc<RwLock<TranslationScope>>,
+    bits: usize,
+    operator: &str,
+    lhs: sway::Expression,
+    rhs: sway::Expression,
+) -> Option<sway::Expression> {
+    match operator {
+        // The bitwise operators act on the two's complement bits of the operands
+        "&" | "|" | "^" => Some(create_signed_from_bits_expression(bits, sway::Expression::from(sway::BinaryExpression {
+            operator: operator.into(),
+            lhs: create_signed_bits_expression(bits, lhs),
+            rhs: create_signed_bits_expression(bits, rhs),
+        }))),
+
+        // Left shifts act on the two's complement bits of the value
+        "<<" => Some(create_signed_from_bits_expression(bits, sway::Expression::from(sway::BinaryExpression {
+            operator: operator.into(),
+            lhs: create_signed_bits_expression(bits, lhs),
+            rhs,
+        }))),
+
+        // Arithmetic shift right, rounding towards negative infinity:
+        // if value >> (bits - 1) == 0 { value >> rhs } else { !(!value >> rhs) }
+        ">>" => Some(create_bound_value_expression(scope.clone(), "value", create_signed_bits_expression(bits, lhs), |value| {
+            create_bound_value_expression(scope, "shift", rhs, |rhs| {
+                create_signed_from_bits_expression(bits, sway::Expression::from(sway::If {
+                    condition: Some(create_sign_bit_clear_expression(bits, value.clone())),
+                    then_body: sway::Block {
+                        statements: vec![],
+                        final_expr: Some(sway::Expression::from(sway::BinaryExpression {
+                            operator: ">>".into(),
+                            lhs: value.clone(),
+                            rhs: rhs.clone(),
+                        })),
+                    },
+                    else_if: Some(Box::new(sway::If {
+                        condition: None,
+                        then_body: sway::Block {
+                            statements: vec![],
+                            final_expr: Some(sway::Expression::from(sway::UnaryExpression {
+                                operator: "!".into(),
+                                expression: sway::Expression::Tuple(vec![
+                                    sway::Expression::from(sway::BinaryExpression {
+                                        operator: ">>".into(),
+                                        lhs: sway::Expression::from(sway::UnaryExpression {
+                                            operator: "!".into(),
+                                            expression: value,
+                                        }),
+                                        rhs,
+                                    }),
+                                ]),
+                            })),
+                        },
+                        else_if: None,
+                    })),
                 }))
-            }
+            })
+        })),
 
-            Some(value) => panic!("Invalid string array value expression: {value:#?}"),
-        }
+        // The remainder takes the sign of the dividend: lhs - (lhs / rhs) * rhs
+        "%" => Some(create_bound_value_expression(scope.clone(), "lhs", lhs, |lhs| {
+            create_bound_value_expression(scope, "rhs", rhs, |rhs| {
+                sway::Expression::from(sway::BinaryExpression {
+                    operator: "-".into(),
+                    lhs: lhs.clone(),
+                    rhs: sway::Expression::from(sway::BinaryExpression {
+                        operator: "*".into(),
+                        lhs: sway::Expression::from(sway::BinaryExpression {
+                            operator: "/".into(),
+                            lhs,
+                            rhs: rhs.clone(),
+                        }),
+                        rhs,
+                    }),
+                })
+            })
+        })),
+
+        _ => None,
     }
 }
 
 pub fn translate_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
+    // Translate the expression without its import aliases (if any)
+    if let Some(expression) = resolve_expression_import_aliases(project, translated_definition, expression) {
+        return translate_expression(project, translated_definition, scope, &expression);
+    }
+
+    // Fold constant expressions which need rational arithmetic into exact integers, i.e. `1.5 * 3` or `5 / 2 * 2`
+    if is_rational_expression(expression) {
+        if let Some((numerator, denominator)) = evaluate_rational_expression(expression) {
+            return create_rational_literal_expression(translated_definition, expression, numerator, denominator);
+        }
+    }
+
     match expression {
         solidity::Expression::BoolLiteral(_, _)
         | solidity::Expression::NumberLiteral(_, _, _, _)
@@ -406,7 +1351,7 @@ pub fn translate_expression(
         | solidity::Expression::HexNumberLiteral(_, _, _)
         | solidity::Expression::AddressLiteral(_, _)
         | solidity::Expression::HexLiteral(_)
-        | solidity::Expression::StringLiteral(_) => translate_literal_expression(project, expression),
+        | solidity::Expression::StringLiteral(_) => translate_literal_expression(project, translated_definition, expression),
         
         solidity::Expression::Type(_, _) => translate_type_expression(project, translated_definition, scope.clone(), expression),
         solidity::Expression::Variable(_) => translate_variable_expression(project, translated_definition, scope.clone(), expression),
@@ -475,6 +1420,7 @@ pub fn translate_expression(
 #[inline]
 pub fn translate_literal_expression(
     _project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     match expression {
@@ -482,12 +1428,12 @@ pub fn translate_literal_expression(
             Ok(sway::Expression::from(sway::Literal::Bool(*value)))
         }
         
-        solidity::Expression::NumberLiteral(_, value, _, _) => {
-            Ok(sway::Expression::from(sway::Literal::DecInt(value.parse().unwrap())))
-        }
+        solidity::Expression::NumberLiteral(_, _, _, _) | solidity::Expression::RationalNumberLiteral(_, _, _, _, _) => {
+            let Some((numerator, denominator)) = evaluate_rational_expression(expression) else {
+                return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid number literal: {expression}")));
+            };
 
-        solidity::Expression::RationalNumberLiteral(_, _, _, _, _) => {
-            Ok(sway::Expression::create_todo(Some(format!("rational number: {}", expression))))
+            create_rational_literal_expression(translated_definition, expression, numerator, denominator)
         }
 
         solidity::Expression::HexNumberLiteral(_, value, _) | solidity::Expression::AddressLiteral(_, value) => {
@@ -517,7 +1463,7 @@ pub fn translate_literal_expression(
             )))
         }
 
-        _ => panic!("Expected literal expression, found {} - {expression:#?}", expression),
+        _ => Err(Error::InvalidConstruct(expression.loc(), format!("Expected literal expression, found {} - {expression}", expression))),
     }
 }
 
@@ -525,7 +1471,7 @@ pub fn translate_literal_expression(
 pub fn translate_type_expression(
     _project: &mut Project,
     _translated_definition: &mut TranslatedDefinition,
-    _scope: Rc<RefCell<TranslationScope>>,
+    _scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     //
@@ -534,14 +1480,160 @@ pub fn translate_type_expression(
     // They should be handled in a higher level expression.
     //
 
-    unimplemented!("type expression: {expression} - {expression:#?}")
+    Err(Error::UnsupportedConstruct(expression.loc(), format!("type expression: {expression} - {expression}")))
 }
 
 #[inline]
+/// Gets the name of the internal function which an expression refers to, if it is a reference to one instead of a variable.
+fn get_function_reference_name<'a>(scope: &Arc<RwLock<TranslationScope>>, expression: &'a solidity::Expression) -> Option<&'a str> {
+    let solidity::Expression::Variable(solidity::Identifier { name, .. }) = expression else { return None };
+
+    let scope = scope.read().unwrap();
+
+    if scope.get_variable_from_old_name(name).is_some() || scope.find_function(|f| f.read().unwrap().old_name == *name).is_none() {
+        return None;
+    }
+
+    Some(name.as_str())
+}
+
+/// Translates a reference to an internal function into a value of the enum of its function type.
+/// Overloaded functions are resolved by `type_name`, which is the function type the value is expected to have.
+/// Returns `None` if the expression doesn't refer to an internal function.
+pub fn translate_function_value_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    expression: &solidity::Expression,
+    type_name: Option<&sway::TypeName>,
+) -> Result<Option<sway::Expression>, Error> {
+    let Some(name) = get_function_reference_name(&scope, expression) else { return Ok(None) };
+
+    let mut functions: Vec<TranslatedFunction> = vec![];
+
+    for function in scope.read().unwrap().find_functions(|f| f.read().unwrap().old_name == name) {
+        let function = function.read().unwrap();
+
+        if !functions.iter().any(|f| f.new_name == function.new_name) {
+            functions.push(function.clone());
+        }
+    }
+
+    let function = if functions.len() == 1 {
+        functions.remove(0)
+    } else {
+        let Some(type_name) = type_name.filter(|t| translated_definition.get_function_type_dispatch_name(t).is_some()) else {
+            return Err(Error::UnresolvedReference(expression.loc(), format!("Ambiguous reference to overloaded function `{name}`, its function type is unknown")));
+        };
+
+        let Some(function) = functions.into_iter().find(|f| TranslatedDefinition::get_function_type_name(f) == *type_name) else {
+            return Err(Error::UnresolvedReference(expression.loc(), format!("Failed to find an overload of function `{name}` of type `{type_name}`")));
+        };
+
+        function
+    };
+
+    translated_definition.ensure_function_type_variant_declared(&function).map(Some)
+}
+
+/// Translates the arguments of a function call. Arguments which refer to internal functions are translated into values of `parameter_types`,
+/// which resolves overloaded functions by the parameter types of the function they're passed to.
+fn translate_argument_expressions(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    arguments: &[solidity::Expression],
+    parameter_types: &[Option<sway::TypeName>],
+) -> Result<Vec<sway::Expression>, Error> {
+    let mut parameters = vec![];
+
+    for (i, argument) in arguments.iter().enumerate() {
+        let type_name = parameter_types.get(i).and_then(|t| t.as_ref());
+
+        parameters.push(match translate_function_value_expression(translated_definition, scope.clone(), argument, type_name)? {
+            Some(parameter) => parameter,
+            None => translate_expression(project, translated_definition, scope.clone(), argument)?,
+        });
+    }
+
+    Ok(parameters)
+}
+
+/// Gets the function types of the parameters of the internal function `name` that internal functions passed as `arguments` are expected to have.
+/// The parameter types come from the first overload of `name` which each of the passed functions has an overload for.
+fn get_function_value_parameter_types(
+    scope: Arc<RwLock<TranslationScope>>,
+    name: &str,
+    arguments: &[solidity::Expression],
+) -> Vec<Option<sway::TypeName>> {
+    let mut parameter_types = vec![None; arguments.len()];
+
+    let function_types = arguments.iter()
+        .enumerate()
+        .filter_map(|(i, argument)| {
+            let argument_name = get_function_reference_name(&scope, argument)?;
+
+            let type_names = scope.read().unwrap()
+                .find_functions(|f| f.read().unwrap().old_name == argument_name)
+                .iter()
+                .map(|f| TranslatedDefinition::get_function_type_name(&f.read().unwrap()))
+                .collect::<Vec<_>>();
+
+            Some((i, type_names))
+        })
+        .collect::<Vec<_>>();
+
+    if function_types.is_empty() {
+        return parameter_types;
+    }
+
+    let function = scope.read().unwrap().find_function(|f| {
+        let f = f.read().unwrap();
+
+        f.old_name == name
+            && f.parameters.entries.len() == arguments.len()
+            && function_types.iter().all(|(i, type_names)| f.parameters.entries[*i].type_name.as_ref().is_some_and(|t| type_names.contains(t)))
+    });
+
+    if let Some(function) = function {
+        let function = function.read().unwrap();
+
+        for (i, _) in function_types.iter() {
+            parameter_types[*i] = function.parameters.entries[*i].type_name.clone();
+        }
+    }
+
+    parameter_types
+}
+
+/// Creates a call to the dispatch function of a value of an internal function type, i.e. `f(x)` => `dispatch_function_u256_returns_u256(f, x)`.
+fn create_function_value_call_expression(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    dispatch_function_name: String,
+    function_value: sway::Expression,
+    arguments: &[solidity::Expression],
+) -> Result<sway::Expression, Error> {
+    // The first parameter of the dispatch function is the function value
+    let parameter_types = scope.read().unwrap()
+        .find_function(|f| f.read().unwrap().new_name == dispatch_function_name)
+        .map(|f| f.read().unwrap().parameters.entries.iter().skip(1).map(|p| p.type_name.clone()).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let mut parameters = translate_argument_expressions(project, translated_definition, scope.clone(), arguments, &parameter_types)?;
+    parameters.insert(0, function_value);
+
+    Ok(sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier(dispatch_function_name),
+        generic_parameters: None,
+        parameters,
+    }))
+}
+
 pub fn translate_variable_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     //
@@ -550,8 +1642,13 @@ pub fn translate_variable_expression(
     // Writes are handled when translating assignment expressions.
     //
 
+    // Check if the expression refers to an internal function, which makes it a value of a function type
+    if let Some(value) = translate_function_value_expression(translated_definition, scope.clone(), expression, None)? {
+        return Ok(value);
+    }
+
     let (variable, expression) = translate_variable_access_expression(project, translated_definition, scope.clone(), expression)?;
-    let mut variable = variable.borrow_mut();
+    let mut variable = variable.write().unwrap();
 
     variable.read_count += 1;
     
@@ -593,21 +1690,23 @@ pub fn translate_variable_expression(
 pub fn translate_array_literal_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expressions: &[solidity::Expression],
 ) -> Result<sway::Expression, Error> {
-    Ok(sway::Expression::Array(sway::Array {
-        elements: expressions.iter()
-            .map(|x| translate_expression(project, translated_definition, scope.clone(), x))
-            .collect::<Result<Vec<_>, _>>()?,
-    }))
+    let mut elements = expressions.iter()
+        .map(|x| translate_expression(project, translated_definition, scope.clone(), x))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    coerce_signed_expressions(translated_definition, scope.clone(), &mut elements);
+
+    Ok(sway::Expression::Array(sway::Array { elements }))
 }
 
 #[inline]
 pub fn translate_array_subscript_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     //
@@ -617,7 +1716,7 @@ pub fn translate_array_subscript_expression(
     //
 
     let (variable, expression) = translate_variable_access_expression(project, translated_definition, scope.clone(), expression)?;
-    let mut variable = variable.borrow_mut();
+    let mut variable = variable.write().unwrap();
 
     variable.read_count += 1;
 
@@ -634,7 +1733,7 @@ pub fn translate_array_subscript_expression(
         match &variable.type_name {
             sway::TypeName::Identifier { name, .. } if name == "Vec" => {
                 let sway::Expression::ArrayAccess(array_access) = expression else {
-                    panic!("Expected array access expression, found {expression:#?}");
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Expected array access expression, found {}", sway::TabbedDisplayer(&expression))));
                 };
 
                 Ok(sway::Expression::from(sway::FunctionCall {
@@ -665,17 +1764,17 @@ pub fn translate_array_subscript_expression(
 pub fn translate_array_slice_expression(
     _project: &mut Project,
     _translated_definition: &mut TranslatedDefinition,
-    _scope: Rc<RefCell<TranslationScope>>,
+    _scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
-    todo!("translate array slice expression: {expression} - {expression:#?}")
+    Err(Error::UnsupportedConstruct(expression.loc(), format!("translate array slice expression: {expression} - {expression}")))
 }
 
 #[inline]
 pub fn translate_list_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     parameters: &[(solidity::Loc, Option<solidity::Parameter>)],
 ) -> Result<sway::Expression, Error> {
     //
@@ -685,7 +1784,7 @@ pub fn translate_list_expression(
 
     // Ensure all elements of the list have no name (value-only tuple)
     if !parameters.iter().all(|(_, p)| p.as_ref().unwrap().name.is_none()) {
-        unimplemented!("non-value list expression")
+        return Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, "non-value list expression".into()))
     }
 
     // Create a tuple expression
@@ -700,7 +1799,7 @@ pub fn translate_list_expression(
 pub fn translate_parenthesis_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     // (x)
@@ -713,7 +1812,7 @@ pub fn translate_parenthesis_expression(
 pub fn translate_member_access_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
     container: &solidity::Expression,
     member: &solidity::Identifier,
@@ -723,10 +1822,30 @@ pub fn translate_member_access_expression(
             solidity::Expression::Variable(solidity::Identifier { name, .. }) => match name.as_str() {
                 "type" => {
                     if args.len() != 1 {
-                        panic!("Invalid type name expression, expected 1 parameter, found {}: {}", args.len(), expression);
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid type name expression, expected 1 parameter, found {}: {}", args.len(), expression)));
+                    }
+
+                    let type_name = translate_type_name(project, translated_definition, &args[0], false, false)?;
+
+                    // The bounds of widths which Sway doesn't have are literals
+                    if type_name.is_non_standard_uint() {
+                        match member.name.as_str() {
+                            "min" => return Ok(sway::Expression::from(sway::Literal::DecInt(BigUint::zero()))),
+                            "max" => return Ok(create_uint_literal_expression(translated_definition, 256, (BigUint::one() << type_name.uint_bits().unwrap()) - BigUint::one())),
+                            _ => {}
+                        }
                     }
 
-                    let type_name = translate_type_name(project, translated_definition, &args[0], false, false);
+                    if let Some(width) = type_name.non_standard_int_bits() {
+                        let bits = get_int_storage_bits(width);
+                        let half = BigUint::one() << (width - 1);
+
+                        match member.name.as_str() {
+                            "min" => return Ok(create_signed_literal_expression(translated_definition, bits, &half, true)),
+                            "max" => return Ok(create_signed_literal_expression(translated_definition, bits, &(half - BigUint::one()), false)),
+                            _ => {}
+                        }
+                    }
 
                     match &type_name {
                         sway::TypeName::Identifier { name, .. } => match (name.as_str(), member.name.as_str()) {
@@ -758,6 +1877,8 @@ pub fn translate_member_access_expression(
         solidity::Expression::Variable(solidity::Identifier { name, .. }) => match (name.as_str(), member.name.as_str()) {
             ("block", "basefee") => {
                 // block.basefee => /*unsupported: block.basefee; using:*/ 0
+                project.report_unsupported(translated_definition, &expression.loc(), ReportEntryKind::Unsupported, "block.basefee");
+
                 return Ok(sway::Expression::Commented(
                     "unsupported: block.basefee; using:".into(),
                     Box::new(sway::Expression::from(sway::Literal::DecInt(BigUint::zero()))),
@@ -766,6 +1887,8 @@ pub fn translate_member_access_expression(
 
             ("block", "blobbasefee") => {
                 // block.blobbasefee => /*unsupported: block.blobbasefee; using:*/ 0
+                project.report_unsupported(translated_definition, &expression.loc(), ReportEntryKind::Unsupported, "block.blobbasefee");
+
                 return Ok(sway::Expression::Commented(
                     "unsupported: block.blobbasefee; using:".into(),
                     Box::new(sway::Expression::from(sway::Literal::DecInt(BigUint::zero()))),
@@ -903,6 +2026,8 @@ pub fn translate_member_access_expression(
 
             ("block", "difficulty") => {
                 // block.difficulty => /*unsupported: block.difficulty; using:*/ 0
+                project.report_unsupported(translated_definition, &expression.loc(), ReportEntryKind::Unsupported, "block.difficulty");
+
                 return Ok(sway::Expression::Commented(
                     "unsupported: block.difficulty; using:".into(),
                     Box::new(sway::Expression::from(sway::Literal::DecInt(BigUint::zero()))),
@@ -912,7 +2037,7 @@ pub fn translate_member_access_expression(
             // TODO: find out the appropriate sway version of `block.gaslimit`
             ("block", "gaslimit") => {
                 // block.gaslimit => ???
-                return Ok(sway::Expression::create_todo(Some("block.gaslimit".into())))
+                return Ok(create_todo_expression(project, translated_definition, &expression.loc(), Some("block.gaslimit".into())))
             }
 
             ("block", "number") => {
@@ -926,6 +2051,8 @@ pub fn translate_member_access_expression(
 
             ("block", "prevrandao") => {
                 // block.prevrandao => /*unsupported: block.prevrandao; using:*/ 0
+                project.report_unsupported(translated_definition, &expression.loc(), ReportEntryKind::Unsupported, "block.prevrandao");
+
                 return Ok(sway::Expression::Commented(
                     "unsupported: block.prevrandao; using:".into(),
                     Box::new(sway::Expression::from(sway::Literal::DecInt(BigUint::zero()))),
@@ -978,6 +2105,8 @@ pub fn translate_member_access_expression(
 
             ("msg", "sig") => {
                 // msg.sig => /*unsupported: msg.sig; using:*/ [0, 0, 0, 0]
+                project.report_unsupported(translated_definition, &expression.loc(), ReportEntryKind::Unsupported, "msg.sig");
+
                 return Ok(sway::Expression::Commented(
                     "unsupported: msg.sig; using:".into(),
                     Box::new(sway::Expression::from(sway::Array {
@@ -1021,6 +2150,8 @@ pub fn translate_member_access_expression(
             ("tx", "origin") => {
                 // tx.origin => Identity::from(Address::from(/*unsupported: tx.origin; using:*/ ZERO_B256))
 
+                project.report_unsupported(translated_definition, &expression.loc(), ReportEntryKind::Unsupported, "tx.origin");
+
                 // Ensure `std::constants::ZERO_B256` is imported
                 translated_definition.ensure_use_declared("std::constants::ZERO_B256");
 
@@ -1061,17 +2192,15 @@ pub fn translate_member_access_expression(
 
                 // Check to see if the variable is an external definition
                 if let Some(external_definition) = resolve_import(project, &name.to_string(), &translated_definition.path)? {
-                    let Some(variable) = external_definition.toplevel_scope.borrow().get_variable_from_old_name(member) else {
-                        panic!(
-                            "{}error: Variable not found in scope: \"{member}\"",
+                    let Some(variable) = external_definition.toplevel_scope.read().unwrap().get_variable_from_old_name(member) else {
+                        return Err(Error::UnresolvedReference(expression.loc(), format!("{}error: Variable not found in scope: \"{member}\"",
                             match project.loc_to_line_and_column(&translated_definition.path, &container.loc()) {
                                 Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
                                 None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                            }
-                        );
+                            })));
                     };
 
-                    let variable = variable.borrow();
+                    let variable = variable.read().unwrap();
                 
                     // If the variable is a constant, ensure it is added to the current definition
                     if variable.is_constant {
@@ -1081,8 +2210,8 @@ pub fn translate_member_access_expression(
                             translated_definition.constants.push(constant.clone());
                         }
 
-                        if !translated_definition.toplevel_scope.borrow().variables.iter().any(|v| v.borrow().new_name == variable.new_name) {
-                            translated_definition.toplevel_scope.borrow_mut().variables.push(Rc::new(RefCell::new(variable.clone())));
+                        if !translated_definition.toplevel_scope.read().unwrap().variables.iter().any(|v| v.read().unwrap().new_name == variable.new_name) {
+                            translated_definition.toplevel_scope.write().unwrap().variables.push(Arc::new(RwLock::new(variable.clone())));
                         }
 
                         return Ok(sway::Expression::Identifier(variable.new_name.clone()));
@@ -1093,18 +2222,17 @@ pub fn translate_member_access_expression(
 
         solidity::Expression::MemberAccess(_, container1, member1) => match container1.as_ref() {
             solidity::Expression::Variable(solidity::Identifier { name, .. }) => {
-                // Check to see if container is an external definition
-                if let Some(external_definition) = project.translated_definitions.iter().find(|d| d.name == *name) {
-                    // Check to see if member is an enum
+                // Check to see if container is an enum of an external definition
+                if let Some(external_definition) = project.find_symbol(&translated_definition.path, SymbolKind::Enum, &member1.name, |d| d.name == *name) {
                     if let Some(external_enum) = external_definition.enums.iter().find(|e| {
                         let sway::TypeName::Identifier { name, generic_parameters: None } = &e.type_definition.name else {
-                            panic!("Expected Identifier type name, found {:#?}", e.type_definition.name);
+                            return false;
                         };
     
                         *name == member1.name
                     }) {
                         let sway::TypeName::Identifier { name: enum_name, generic_parameters: None } = &external_enum.type_definition.name else {
-                            panic!("Expected Identifier type name, found {:#?}", external_enum.type_definition.name);
+                            return Err(Error::InvalidConstruct(expression.loc(), format!("Expected Identifier type name, found {}", external_enum.type_definition.name)));
                         };
     
                         let variant_name = crate::translate_naming_convention(member.name.as_str(), Case::ScreamingSnake);
@@ -1226,21 +2354,54 @@ pub fn translate_member_access_expression(
         _ => {}
     }
 
-    todo!("translate {container_type_name_string} member access expression: {expression} - {expression:#?}")
+    Err(Error::UnsupportedConstruct(expression.loc(), format!("translate {container_type_name_string} member access expression: {expression} - {expression}")))
+}
+
+/// Imports the function named `old_name` which matches the supplied parameters from the `external_definition` into the `translated_definition`,
+/// unless it already has a matching function. Returns the declaration of the function, or `None` if the external definition doesn't have it.
+fn import_external_function(
+    translated_definition: &mut TranslatedDefinition,
+    external_definition: &TranslatedDefinition,
+    old_name: &str,
+    parameters: &[sway::Expression],
+    parameter_types: &[sway::TypeName],
+) -> Option<TranslatedFunction> {
+    // Check if the member is a function defined in the toplevel scope
+    let external_function_declaration = external_definition.toplevel_scope.read().unwrap()
+        .find_function_matching_types(old_name, parameters, parameter_types)?
+        .read().unwrap()
+        .clone();
+
+    // Get the external function definition
+    let external_function_definition = external_definition.functions.iter().find(|f| {
+        f.name == external_function_declaration.new_name
+        && f.parameters.entries.len() == external_function_declaration.parameters.entries.len()
+    })?;
+
+    // Import the function if we haven't already
+    if translated_definition.toplevel_scope.read().unwrap().find_function_matching_types(old_name, parameters, parameter_types).is_none() {
+        // Add the local function definition to the beginning of the list
+        translated_definition.functions.insert(0, external_function_definition.clone());
+
+        // Add the local function declaration to the beginning of the toplevel scope
+        translated_definition.toplevel_scope.write().unwrap().functions.insert(0, Arc::new(RwLock::new(external_function_declaration.clone())));
+    }
+
+    Some(external_function_declaration)
 }
 
 #[inline]
 pub fn translate_function_call_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
     function: &solidity::Expression,
     named_arguments: Option<&[solidity::NamedArgument]>,
     arguments: &[solidity::Expression],
 ) -> Result<sway::Expression, Error> {
     if named_arguments.is_some() && !arguments.is_empty() {
-        panic!("Invalid call to translate_function_call_expression: named_arguments is Some(_) and arguments is not empty");
+        return Err(Error::InvalidConstruct(expression.loc(), "Invalid call to translate_function_call_expression: named_arguments is Some(_) and arguments is not empty".into()));
     }
     
     match function {
@@ -1248,7 +2409,7 @@ pub fn translate_function_call_expression(
             // Type casting
 
             if arguments.len() != 1 {
-                panic!("Invalid type cast: {expression:#?}");
+                return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid type cast: {expression}")));
             }
 
             match ty {
@@ -1298,7 +2459,7 @@ pub fn translate_function_call_expression(
                             sway::TypeName::Identifier { name, generic_parameters: None } if name == "Identity" => {
                                 Ok(value)
                             }
-                            _ => todo!("translate address cast: {expression:#?}"),
+                            _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate address cast: {expression}"))),
                         }
                     }
                 }
@@ -1311,204 +2472,122 @@ pub fn translate_function_call_expression(
                         .collect::<Result<Vec<_>, _>>()?;
                     
                     if parameters.len() != 1 {
-                        panic!("Malformed payable cast: {} - {expression:#?}", expression);
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Malformed payable cast: {} - {expression}", expression)));
                     }
 
                     Ok(parameters[0].clone())
                 }
 
-                solidity::Type::Int(bits) => {
+                solidity::Type::Rational => {
+                    let type_name = translate_type_name(project, translated_definition, function, false, false)?;
+                    translate_fixed_point_cast_expression(project, translated_definition, scope.clone(), expression, &type_name, arguments)
+                }
+
+                solidity::Type::Int(width) => {
+                    let width = *width as usize;
+                    let type_name = translate_type_name(project, translated_definition, function, false, false)?;
+                    let bits = translated_definition.get_underlying_type(&type_name).int_bits().unwrap();
+
                     let value_expression = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
                     let value_type_name = translated_definition.get_expression_type(scope.clone(), &value_expression)?;
-                    let value_type_name = translated_definition.get_underlying_type(&value_type_name);
 
-                    let create_int_try_from_unwrap_expression = |from_bits: usize, to_bits: usize, value: sway::Expression| -> Result<sway::Expression, Error> {
-                        if from_bits == to_bits {
-                            return Ok(value);
-                        }
+                    // Fixed point values are truncated to their integer part
+                    if value_type_name.fixed_point_decimals().is_some() {
+                        return create_fixed_point_conversion_expression(translated_definition, &expression.loc(), &value_type_name, &type_name, value_expression);
+                    }
 
-                        Ok(sway::Expression::from(sway::FunctionCall {
-                            function: sway::Expression::Identifier(format!("I{to_bits}::from")),
-                            generic_parameters: None,
-                            parameters: vec![
-                                sway::Expression::from(sway::FunctionCall {
-                                    function: sway::Expression::from(sway::MemberAccess {
-                                        expression: sway::Expression::from(sway::FunctionCall {
-                                            function: sway::Expression::Identifier(format!("{}{to_bits}::try_from", if to_bits > 64 { "U" } else { "u" })),
-                                            generic_parameters: None,
-                                            parameters: vec![value],
-                                        }),
-                                        member: "unwrap".into(),
-                                    }),
-                                    generic_parameters: None,
-                                    parameters: vec![],
-                                }),
-                            ],
-                        }))
-                    };
+                    // Fold integer literals, wrapping them to the width
+                    if let Some((value, negative)) = get_integer_literal_value(&value_expression) {
+                        let modulus = BigUint::one() << width;
+                        let mut value = value % &modulus;
 
-                    let bits = match bits {
-                        0..=8 => {
-                            if *bits != 8 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i8`...");
-                            }
-                            8
-                        }
-                        9..=16 => {
-                            if *bits != 16 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i16`...");
-                            }
-                            16
-                        }
-                        17..=32 => {
-                            if *bits != 32 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i32`...");
-                            }
-                            32
-                        }
-                        33..=64 => {
-                            if *bits != 64 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i64`...");
-                            }
-                            64
+                        if negative && !value.is_zero() {
+                            value = &modulus - value;
                         }
-                        65..=128 => {
-                            if *bits != 128 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i128`...");
-                            }
-                            128
-                        }
-                        129..=256 => {
-                            if *bits != 256 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `i256`...");
-                            }
-                            256
+
+                        // The wrapped two's complement bits are negative when their sign bit is set
+                        if value >= BigUint::one() << (width - 1) {
+                            return Ok(create_signed_literal_expression(translated_definition, bits, &(modulus - value), true));
                         }
-                        _ => panic!("Invalid int type: {expression:#?}"),
-                    };
 
-                    match &value_type_name {
-                        sway::TypeName::Identifier { name, generic_parameters: None } => match (name.as_str(), bits) {
-                            ("I8", 8 | 16 | 32 | 64 | 128 | 256) => create_int_try_from_unwrap_expression(8, bits as usize, value_expression),
-                            ("I16", 8 | 16 | 32 | 64 | 128 | 256) => create_int_try_from_unwrap_expression(16, bits as usize, value_expression),
-                            ("I32", 8 | 16 | 32 | 64 | 128 | 256) => create_int_try_from_unwrap_expression(32, bits as usize, value_expression),
-                            ("I64", 8 | 16 | 32 | 64 | 128 | 256) => create_int_try_from_unwrap_expression(64, bits as usize, value_expression),
-                            ("I128", 8 | 16 | 32 | 64 | 128 | 256) => create_int_try_from_unwrap_expression(128, bits as usize, value_expression),
-                            ("I256", 8 | 16 | 32 | 64 | 128 | 256) => create_int_try_from_unwrap_expression(256, bits as usize, value_expression),
-                            
-                            ("u256", 256) => {
-                                Ok(sway::Expression::from(sway::FunctionCall {
-                                    function: sway::Expression::Identifier("I256::from".into()),
-                                    generic_parameters: None,
-                                    parameters: vec![
-                                        value_expression,
-                                    ],
-                                }))
-                            }
+                        return Ok(create_signed_literal_expression(translated_definition, bits, &value, false));
+                    }
 
-                            _ => todo!("translate type cast from {value_type_name} to I{bits}: {expression} - {expression:#?}"),
-                        }
-                        
-                        _ => todo!("translate type cast from {value_type_name} to I{bits}: {expression} - {expression:#?}"),
+                    let value_width = translated_definition.get_int_width(&value_type_name)
+                        .or_else(|| translated_definition.get_underlying_type(&value_type_name).uint_bits());
+
+                    let value_type_name = translated_definition.get_underlying_type(&value_type_name);
+
+                    // Widths which Sway doesn't have keep the bits of wider values within the width
+                    if width < bits && value_width.is_some_and(|value_width| value_width > width || value_type_name.is_uint()) {
+                        let value_expression = if let Some(from_bits) = value_type_name.int_bits() {
+                            create_uint_resize_expression(translated_definition, from_bits, bits, create_signed_bits_expression(from_bits, value_expression))
+                        } else {
+                            create_uint_resize_expression(translated_definition, value_type_name.uint_bits().unwrap(), bits, value_expression)
+                        };
+
+                        return Ok(create_signed_truncate_expression(translated_definition, width, value_expression));
+                    }
+
+                    if let Some(from_bits) = value_type_name.int_bits() {
+                        return Ok(create_signed_resize_expression(translated_definition, from_bits, bits, value_expression));
                     }
+
+                    // Reinterpret the bits of unsigned integers as two's complement
+                    if let Some(from_bits) = value_type_name.uint_bits() {
+                        let value_expression = create_uint_resize_expression(translated_definition, from_bits, bits, value_expression);
+                        return Ok(create_signed_from_bits_expression(bits, value_expression));
+                    }
+
+                    Err(Error::UnsupportedConstruct(expression.loc(), format!("translate type cast from {value_type_name} to I{bits}: {expression}")))
                 }
 
                 solidity::Type::Uint(bits) => {
-                    let value_expression = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
-                    let value_type_name = translated_definition.get_expression_type(scope.clone(), &value_expression)?;
-                    let value_type_name = translated_definition.get_underlying_type(&value_type_name);
+                    let bits = *bits as usize;
+                    let type_name = translate_type_name(project, translated_definition, function, false, false)?;
+                    let type_name = translated_definition.get_underlying_type(&type_name);
 
-                    if value_type_name.is_int() {
-                        match &arguments[0] {
-                            solidity::Expression::Negate(_, expr) => {
-                                match expr.as_ref() {
-                                    solidity::Expression::NumberLiteral(_, value, _, _) => {
-                                        let value = value.parse::<BigUint>().map_err(|e| Error::Wrapped(Box::new(e)))?;
-                                        let max = if *bits == 256 {
-                                            BigUint::from_str_radix("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", 16).map_err(|e| Error::Wrapped(Box::new(e)))?
-                                        } else {
-                                            (BigUint::one() << *bits) - BigUint::one()
-                                        };
-
-                                        return Ok(sway::Expression::from(sway::Literal::HexInt((max - value) + BigUint::one())));
-                                    }
-                                    _ => {}
-                                }
-                            }
-                            _ => {}
-                        }
+                    if type_name.uint_bits() == Some(256) {
+                        translated_definition.ensure_use_declared("std::u256::*");
                     }
 
-                    let create_uint_try_from_unwrap_expression = |from_bits: usize, to_bits: usize, value: sway::Expression| -> Result<sway::Expression, Error> {
-                        if from_bits == to_bits {
-                            return Ok(value);
-                        }
-
-                        Ok(sway::Expression::from(sway::FunctionCall {
-                            function: sway::Expression::from(sway::MemberAccess {
-                                expression: sway::Expression::from(sway::FunctionCall {
-                                    function: sway::Expression::Identifier(format!("{}{to_bits}::try_from", if to_bits > 64 { "U" } else { "u" })),
-                                    generic_parameters: None,
-                                    parameters: vec![value],
-                                }),
-                                member: "unwrap".into(),
-                            }),
-                            generic_parameters: None,
-                            parameters: vec![],
-                        }))
-                    };
+                    let value_expression = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
 
-                    let bits = match bits {
-                        0..=8 => {
-                            if *bits != 8 {
-                                eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u8`...");
-                            }
-                            8
-                        }
-                        9..=16 => {
-                            if *bits != 16 {
-                                eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u16`...");
-                            }
-                            16
-                        }
-                        17..=32 => {
-                            if *bits != 32 {
-                                eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u32`...");
-                            }
-                            32
-                        }
-                        33..=64 => {
-                            if *bits != 64 {
-                                eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u64`...");
-                            }
-                            64
-                        }
-                        65..=256 => {
-                            if *bits != 256 {
-                                eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u256`...");
-                            }
-                            translated_definition.ensure_use_declared("std::u256::*");
-                            256
+                    // Fold integer literals, wrapping them to the width
+                    if let Some((value, negative)) = get_integer_literal_value(&value_expression) {
+                        let modulus = BigUint::one() << bits;
+                        let value = value % &modulus;
+
+                        if negative && !value.is_zero() {
+                            return Ok(sway::Expression::from(sway::Literal::HexInt(modulus - value)));
                         }
-                        _ => panic!("Invalid uint type: {expression:#?}"),
+
+                        return Ok(create_uint_literal_expression(translated_definition, type_name.uint_bits().unwrap(), value));
+                    }
+
+                    let value_type_name = translated_definition.get_expression_type(scope.clone(), &value_expression)?;
+                    let underlying_type_name = translated_definition.get_underlying_type(&value_type_name);
+
+                    // Fixed point values are truncated to their integer part
+                    let value_expression = if value_type_name.fixed_point_decimals().is_some() {
+                        create_fixed_point_conversion_expression(translated_definition, &expression.loc(), &value_type_name, &underlying_type_name, value_expression)?
+                    } else {
+                        value_expression
                     };
 
+                    let value_type_name = underlying_type_name;
+
+                    // Reinterpret the two's complement bits of signed integers
+                    if let Some(from_bits) = value_type_name.int_bits() {
+                        return Ok(create_uint_cast_expression(translated_definition, from_bits, bits, create_signed_bits_expression(from_bits, value_expression)));
+                    }
+
+                    if let Some(from_bits) = value_type_name.uint_bits() {
+                        return Ok(create_uint_cast_expression(translated_definition, from_bits, bits, value_expression));
+                    }
+
                     match &value_type_name {
                         sway::TypeName::Identifier { name, .. } => match (name.as_str(), bits) {
-                            ("u8", 8 | 16 | 32 | 64 | 256) => create_uint_try_from_unwrap_expression(8, bits as usize, value_expression),
-                            ("u16", 8 | 16 | 32 | 64 | 256) => create_uint_try_from_unwrap_expression(16, bits as usize, value_expression),
-                            ("u32", 8 | 16 | 32 | 64 | 256) => create_uint_try_from_unwrap_expression(32, bits as usize, value_expression),
-                            ("u64", 8 | 16 | 32 | 64 | 256) => create_uint_try_from_unwrap_expression(64, bits as usize, value_expression),
-                            ("u256", 8 | 16 | 32 | 64 | 256) => create_uint_try_from_unwrap_expression(256, bits as usize, value_expression),
-                            
-                            // Direct signed-to-unsigned conversion
-                            ("I8", 8) | ("I16", 16) | ("I32", 32) | ("I64", 64) | ("I128", 128) | ("I256", 256) => {
-                                Ok(sway::Expression::from(sway::MemberAccess {
-                                    expression: value_expression,
-                                    member: "underlying".into(),
-                                }))
-                            }
-
                             ("b256", 256) => {
                                 Ok(sway::Expression::from(sway::FunctionCall {
                                     function: sway::Expression::from(sway::MemberAccess {
@@ -1607,10 +2686,10 @@ pub fn translate_function_call_expression(
 
                             ("todo!", _) => Ok(value_expression),
 
-                            _ => panic!("translate from {value_type_name} to u{bits}: {value_expression:#?}"),
+                            _ => Err(Error::InvalidConstruct(expression.loc(), format!("translate from {value_type_name} to u{bits}: {}", sway::TabbedDisplayer(&value_expression)))),
                         }
 
-                        _ => todo!("translate {value_type_name} type cast: {} - {expression:#?}", expression),
+                        _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate {value_type_name} type cast: {} - {expression}", expression))),
                     }
                 }
 
@@ -1621,7 +2700,7 @@ pub fn translate_function_call_expression(
                     let value_type_name = translated_definition.get_expression_type(scope.clone(), &value_expression)?;
 
                     match &value_type_name {
-                        sway::TypeName::Undefined => panic!("Undefined type name"),
+                        sway::TypeName::Undefined => Err(Error::InvalidConstruct(expression.loc(), "Undefined type name".into())),
 
                         sway::TypeName::Identifier { name, generic_parameters } => match name.as_str() {
                             "b256" if generic_parameters.is_none() => {
@@ -1629,7 +2708,7 @@ pub fn translate_function_call_expression(
                                 translated_definition.ensure_use_declared("std::bytes::Bytes");
         
                                 // Generate a unique name for our variable
-                                let variable_name = scope.borrow_mut().generate_unique_variable_name("bytes");
+                                let variable_name = scope.write().unwrap().generate_unique_variable_name("bytes");
         
                                 Ok(sway::Expression::from(sway::Block {
                                     statements: vec![
@@ -1675,11 +2754,11 @@ pub fn translate_function_call_expression(
                                 }))
                             }
 
-                            _ => Ok(sway::Expression::create_todo(Some(format!("translate from {value_type_name} to bytes{byte_count}: {expression}")))),
+                            _ => Ok(create_todo_expression(project, translated_definition, &expression.loc(), Some(format!("translate from {value_type_name} to bytes{byte_count}: {expression}")))),
                         }
 
-                        sway::TypeName::Array { .. } => todo!("translate from {value_type_name} to bytes{byte_count}"),
-                        sway::TypeName::Tuple { .. } => todo!("translate from {value_type_name} to bytes{byte_count}"),
+                        sway::TypeName::Array { .. } => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate from {value_type_name} to bytes{byte_count}"))),
+                        sway::TypeName::Tuple { .. } => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate from {value_type_name} to bytes{byte_count}"))),
 
                         sway::TypeName::StringSlice => {
                             match &value_expression {
@@ -1690,11 +2769,11 @@ pub fn translate_function_call_expression(
                                     ))
                                 ),
 
-                                _ => todo!("translate from {value_type_name} to bytes{byte_count}"),
+                                _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate from {value_type_name} to bytes{byte_count}"))),
                             }
                         }
 
-                        sway::TypeName::StringArray { .. } => todo!("translate from {value_type_name} to bytes{byte_count}"),
+                        sway::TypeName::StringArray { .. } => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate from {value_type_name} to bytes{byte_count}"))),
                     }
                 }
 
@@ -1705,17 +2784,17 @@ pub fn translate_function_call_expression(
                     let value_type_name = translated_definition.get_expression_type(scope.clone(), &value_expression)?;
 
                     match &value_type_name {
-                        sway::TypeName::Undefined => panic!("Undefined type name"),
-                        sway::TypeName::Identifier { .. } => todo!("translate from {value_type_name} to bytes"),
-                        sway::TypeName::Array { .. } => todo!("translate from {value_type_name} to bytes"),
-                        sway::TypeName::Tuple { .. } => todo!("translate from {value_type_name} to bytes"),
+                        sway::TypeName::Undefined => Err(Error::InvalidConstruct(expression.loc(), "Undefined type name".into())),
+                        sway::TypeName::Identifier { .. } => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate from {value_type_name} to bytes"))),
+                        sway::TypeName::Array { .. } => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate from {value_type_name} to bytes"))),
+                        sway::TypeName::Tuple { .. } => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate from {value_type_name} to bytes"))),
     
                         sway::TypeName::StringSlice => {
                             // Ensure `std::bytes::Bytes` is imported
                             translated_definition.ensure_use_declared("std::bytes::Bytes");
     
                             // Generate a unique name for our variable
-                            let variable_name = scope.borrow_mut().generate_unique_variable_name("s");
+                            let variable_name = scope.write().unwrap().generate_unique_variable_name("s");
 
                             if let sway::Expression::Identifier(variable_name) = &value_expression {
                                 return Ok(sway::Expression::from(sway::FunctionCall {
@@ -1810,7 +2889,7 @@ pub fn translate_function_call_expression(
                             }))
                         }
 
-                        sway::TypeName::StringArray { .. } => todo!("translate from {value_type_name} to bytes"),
+                        sway::TypeName::StringArray { .. } => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate from {value_type_name} to bytes"))),
                     }
                 }
 
@@ -1835,28 +2914,53 @@ pub fn translate_function_call_expression(
                                 }))
                             }
 
-                            _ => todo!("translate {value_type_name} type cast: {} - {expression:#?}", expression),
+                            _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate {value_type_name} type cast: {} - {expression}", expression))),
                         }
 
-                        _ => todo!("translate {value_type_name} type cast: {} - {expression:#?}", expression),
+                        _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate {value_type_name} type cast: {} - {expression}", expression))),
                     }
                 }
 
-                _ => todo!("translate type cast: {} - {expression:#?}", expression),
+                _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate type cast: {} - {expression}", expression))),
             }
         }
 
         solidity::Expression::Variable(solidity::Identifier { name, .. }) => {
-            let mut parameters = arguments.iter()
-                .map(|a| translate_expression(project, translated_definition, scope.clone(), a))
-                .collect::<Result<Vec<_>, _>>()?;
+            // Check if the function is a fixed point type, which the parser doesn't recognize
+            if parse_fixed_point_type_name(name).is_some() {
+                let type_name = translate_type_name(project, translated_definition, function, false, false)?;
+                return translate_fixed_point_cast_expression(project, translated_definition, scope.clone(), expression, &type_name, arguments);
+            }
+
+            // Check if the function is a variable of an internal function type, which is called through its dispatch function
+            let variable = scope.read().unwrap().get_variable_from_old_name(name);
+
+            if let Some(variable) = variable {
+                let type_name = variable.read().unwrap().type_name.clone();
+
+                if let Some(dispatch_function_name) = translated_definition.get_function_type_dispatch_name(&type_name) {
+                    let function_value = translate_variable_expression(project, translated_definition, scope.clone(), function)?;
+                    return create_function_value_call_expression(project, translated_definition, scope.clone(), dispatch_function_name, function_value, arguments);
+                }
+            }
+
+            // Overloaded internal functions which are passed as arguments are resolved by the parameter types of the function they're passed to,
+            // or by the field types of the struct they're passed to the constructor of
+            let parameter_types = match translated_definition.structs.iter().find(|s| s.name == *name) {
+                Some(struct_definition) if struct_definition.fields.len() == arguments.len() => {
+                    struct_definition.fields.iter().map(|f| Some(f.type_name.clone())).collect()
+                }
+
+                _ => get_function_value_parameter_types(scope.clone(), name, arguments),
+            };
+            let mut parameters = translate_argument_expressions(project, translated_definition, scope.clone(), arguments, &parameter_types)?;
 
             match name.as_str() {
                 "blockhash" => {
                     // blockhash(block_number) => std::block::block_header_hash(block_height).unwrap_or(0)
 
                     if parameters.len() != 1 {
-                        panic!("Invalid blockhash call: {expression:#?}");
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid blockhash call: {expression}")));
                     }
 
                     Ok(sway::Expression::from(sway::FunctionCall {
@@ -1879,7 +2983,7 @@ pub fn translate_function_call_expression(
                     // gasleft() => std::registers::global_gas()
 
                     if !parameters.is_empty() {
-                        panic!("Invalid gasleft call: {expression:#?}");
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid gasleft call: {expression}")));
                     }
 
                     Ok(sway::Expression::from(sway::FunctionCall {
@@ -1893,7 +2997,7 @@ pub fn translate_function_call_expression(
                     // addmod(x, y, k) => (x + y) % k
 
                     if parameters.len() != 3 {
-                        panic!("Invalid addmod call: {expression:#?}");
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid addmod call: {expression}")));
                     }
 
                     Ok(sway::Expression::from(sway::BinaryExpression {
@@ -1913,7 +3017,7 @@ pub fn translate_function_call_expression(
                     // mulmod(x, y, k) => (x * y) % k
 
                     if parameters.len() != 3 {
-                        panic!("Invalid mulmod call: {expression:#?}");
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid mulmod call: {expression}")));
                     }
 
                     Ok(sway::Expression::from(sway::BinaryExpression {
@@ -1933,7 +3037,7 @@ pub fn translate_function_call_expression(
                     // keccak256(value) => std::hash::keccak256(value)
 
                     if parameters.len() != 1 {
-                        panic!("Invalid keccak256 call: {expression:#?}");
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid keccak256 call: {expression}")));
                     }
 
                     Ok(sway::Expression::from(sway::FunctionCall {
@@ -1947,7 +3051,7 @@ pub fn translate_function_call_expression(
                     // sha256(value) => std::hash::sha256(value)
 
                     if parameters.len() != 1 {
-                        panic!("Invalid sha256 call: {expression:#?}");
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid sha256 call: {expression}")));
                     }
 
                     Ok(sway::Expression::from(sway::FunctionCall {
@@ -1958,8 +3062,10 @@ pub fn translate_function_call_expression(
                 }
 
                 "ripemd160" => {
-                    // ripemd160() => /*unsupported: block.basefee; using:*/ 0
-                    
+                    // ripemd160() => /*unsupported: ripemd160(); using:*/ 0
+
+                    project.report_unsupported(translated_definition, &expression.loc(), ReportEntryKind::Unsupported, "ripemd160()");
+
                     Ok(sway::Expression::Commented(
                         "unsupported: ripemd160(); using:".into(),
                         Box::new(sway::Expression::from(sway::Literal::DecInt(BigUint::zero()))),
@@ -1974,14 +3080,14 @@ pub fn translate_function_call_expression(
                     //
 
                     if parameters.len() != 4 {
-                        panic!("Invalid ecrecover call: {expression:#?}");
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid ecrecover call: {expression}")));
                     }
 
                     Ok(sway::Expression::from(sway::FunctionCall {
                         function: sway::Expression::Identifier("std::ecr::ec_recover".into()),
                         generic_parameters: None,
                         parameters: vec![
-                            sway::Expression::create_todo(Some("ecrecover: how should we generate the sig value from v,r,s?".into())),
+                            create_todo_expression(project, translated_definition, &expression.loc(), Some("ecrecover: how should we generate the sig value from v,r,s?".into())),
                             parameters[0].clone(),
                         ],
                     }))
@@ -1992,14 +3098,14 @@ pub fn translate_function_call_expression(
                     // TODO: how should we handle this?
                     //
 
-                    Ok(sway::Expression::create_unimplemented(Some("selfdestruct is not supported in sway".into())))
+                    Ok(create_unimplemented_expression(project, translated_definition, &expression.loc(), Some("selfdestruct is not supported in sway".into())))
                 }
 
                 "assert" => {
                     // assert(x) => assert(x)
 
                     if parameters.len() != 1 {
-                        panic!("Invalid assert call: {expression:#?}");
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid assert call: {expression}")));
                     }
 
                     Ok(sway::Expression::from(sway::FunctionCall {
@@ -2022,7 +3128,7 @@ pub fn translate_function_call_expression(
                     }
 
                     if parameters.len() != 2 {
-                        panic!("Invalid require call: {expression:#?}");
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid require call: {expression}")));
                     }
 
                     Ok(sway::Expression::from(sway::FunctionCall {
@@ -2050,7 +3156,7 @@ pub fn translate_function_call_expression(
                     }
 
                     if parameters.len() != 1 {
-                        panic!("Invalid revert call: {expression:#?}");
+                        return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid revert call: {expression}")));
                     }
 
                     Ok(sway::Expression::from(sway::Block {
@@ -2109,30 +3215,34 @@ pub fn translate_function_call_expression(
                             }
                         }
 
-                        if valid && !struct_definition.fields.iter().zip(parameter_types.iter()).all(|(f, t)| f.type_name.is_compatible_with(t)) {
+                        if valid && !struct_definition.fields.iter().zip(parameters.iter().zip(parameter_types.iter())).all(|(f, (p, t))| {
+                            f.type_name.is_compatible_with(t) || can_coerce_signed_expression(&f.type_name, p, t)
+                        }) {
                             valid = false;
                         }
 
                         if valid {
+                            let mut fields = vec![];
+
+                            for (field, value) in struct_definition.fields.iter().zip(parameters.iter()) {
+                                fields.push(sway::ConstructorField {
+                                    name: field.name.clone(),
+                                    value: coerce_signed_expression(translated_definition, scope.clone(), &field.type_name, value.clone()),
+                                });
+                            }
+
                             return Ok(sway::Expression::from(sway::Constructor {
                                 type_name: sway::TypeName::Identifier {
                                     name: struct_definition.name.clone(),
                                     generic_parameters: None,
                                 },
-
-                                fields: struct_definition.fields.iter()
-                                    .zip(parameters.iter())
-                                    .map(|(field, value)| sway::ConstructorField {
-                                        name: field.name.clone(),
-                                        value: value.clone(),
-                                    })
-                                    .collect(),
+                                fields,
                             }));
                         }
                     }
 
                     // Check to see if the expression is an ABI type
-                    if let Some(external_definition) = project.find_definition_with_abi(old_name) {
+                    if let Some(external_definition) = project.find_definition_with_abi(&translated_definition.path, old_name) {
                         if parameters.len() == 1 {
                             match translated_definition.get_expression_type(scope.clone(), &parameters[0])? {
                                 sway::TypeName::Identifier { name, generic_parameters: None } if name == "Identity" => {
@@ -2189,8 +3299,8 @@ pub fn translate_function_call_expression(
                             ));
                         }
 
-                        if let Some(function) = scope.borrow().find_function(|f| {
-                            let f = f.borrow();
+                        if let Some(function) = scope.read().unwrap().find_function(|f| {
+                            let f = f.read().unwrap();
 
                             if f.old_name != old_name {
                                 return false;
@@ -2202,7 +3312,7 @@ pub fn translate_function_call_expression(
 
                             f.parameters.entries.iter().all(|p| named_parameters.iter().any(|(name, _)| p.name == *name))
                         }) {
-                            let function = function.borrow();
+                            let function = function.read().unwrap();
 
                             parameters = vec![];
                             parameter_types = vec![];
@@ -2222,23 +3332,33 @@ pub fn translate_function_call_expression(
                         }
                     }
 
+                    // Import free functions declared in other files
+                    if scope.read().unwrap().find_function_matching_types(old_name, parameters.as_slice(), parameter_types.as_slice()).is_none() {
+                        if let Some(external_definition) = project.find_free_function(&translated_definition.path, old_name).cloned() {
+                            import_external_function(translated_definition, &external_definition, old_name, parameters.as_slice(), parameter_types.as_slice());
+                        }
+                    }
+
                     // Ensure the function exists in scope
-                    let Some(function) = scope.borrow().find_function_matching_types(old_name, parameters.as_slice(), parameter_types.as_slice()) else {
-                        panic!(
-                            "{}error: Failed to find function `{old_name}({})` in scope",
-                            match project.loc_to_line_and_column(&translated_definition.path, &function.loc()) {
-                                Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                                None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                            },
+                    let Some(function) = scope.read().unwrap().find_function_matching_types(old_name, parameters.as_slice(), parameter_types.as_slice()) else {
+                        return Err(Error::UnresolvedReference(function.loc(), format!(
+                            "Failed to find function `{old_name}({})` in scope",
                             parameter_types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", "),
-                        );
+                        )));
                     };
 
-                    let function = function.borrow();
+                    let function = function.read().unwrap();
 
                     // Increase the call count of the function
                     *translated_definition.function_call_counts.entry(function.new_name.clone()).or_insert(0) += 1;
 
+                    // Convert literals and smaller signed integers to the signed parameter types of the function
+                    for (parameter, entry) in parameters.iter_mut().zip(function.parameters.entries.iter()) {
+                        if let Some(type_name) = entry.type_name.as_ref() {
+                            *parameter = coerce_signed_expression(translated_definition, scope.clone(), type_name, parameter.clone());
+                        }
+                    }
+
                     // Translate the function call
                     Ok(sway::Expression::from(sway::FunctionCall {
                         function: sway::Expression::Identifier(function.new_name.clone()),
@@ -2260,10 +3380,10 @@ pub fn translate_function_call_expression(
                             // TODO: how should this be handled?
                             //
 
-                            return Ok(sway::Expression::create_todo(Some(expression.to_string())));
+                            return Ok(create_todo_expression(project, translated_definition, &expression.loc(), Some(expression.to_string())));
                         }
                         
-                        member => todo!("translate `string.{member}``")
+                        member => return Err(Error::UnsupportedConstruct(expression.loc(), format!("translate `string.{member}``")))
                     }
 
                     solidity::Type::DynamicBytes => match member.name.as_str() {
@@ -2274,13 +3394,13 @@ pub fn translate_function_call_expression(
                             // TODO: how should this be handled?
                             //
 
-                            return Ok(sway::Expression::create_todo(Some(expression.to_string())));
+                            return Ok(create_todo_expression(project, translated_definition, &expression.loc(), Some(expression.to_string())));
                         }
                         
-                        member => todo!("translate `bytes.{member}`")
+                        member => return Err(Error::UnsupportedConstruct(expression.loc(), format!("translate `bytes.{member}`")))
                     }
 
-                    _ => todo!("translate member access function call: {expression} - {expression:#?}"),
+                    _ => return Err(Error::UnsupportedConstruct(expression.loc(), format!("translate member access function call: {expression} - {expression}"))),
                 }
 
                 solidity::Expression::Variable(solidity::Identifier { name, .. }) => match name.as_str() {
@@ -2298,7 +3418,7 @@ pub fn translate_function_call_expression(
                             // };
 
                             if arguments.len() != 2 {
-                                panic!("Invalid `abi.decode` call: expected 2 arguments, found {}: {} - {expression:#?}", arguments.len(), expression);
+                                return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid `abi.decode` call: expected 2 arguments, found {}: {} - {expression}", arguments.len(), expression)));
                             }
 
                             let encoded_data = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
@@ -2307,17 +3427,17 @@ pub fn translate_function_call_expression(
                                 solidity::Expression::List(_, parameter_types) => {
                                     parameter_types.iter()
                                         .map(|(_, p)| translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, false))
-                                        .collect::<Vec<_>>()
+                                        .collect::<Result<Vec<_>, _>>()?
                                 }
 
                                 solidity::Expression::Parenthesis(_, expression) if matches!(expression.as_ref(), solidity::Expression::Type(_, _)) => {
                                     vec![
-                                        translate_type_name(project, translated_definition, expression, false, false),
+                                        translate_type_name(project, translated_definition, expression, false, false)?,
                                     ]
                                 }
 
                                 _ => {
-                                    panic!("Invalid `abi.decode` call: expected type list, found {} - {:#?}", arguments[1], arguments[1]);
+                                    return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid `abi.decode` call: expected type list, found {}", arguments[1])));
                                 }
                             };
 
@@ -2327,7 +3447,7 @@ pub fn translate_function_call_expression(
                                 .collect::<Vec<_>>();
 
                             if parameter_types.len() != parameter_names.len() {
-                                panic!("Failed to generate parameter names for `{}`", expression);
+                                return Err(Error::InvalidConstruct(expression.loc(), format!("Failed to generate parameter names for `{}`", expression)));
                             }
 
                             // If we only have 1 parameter to decode, just decode it directly
@@ -2468,7 +3588,7 @@ pub fn translate_function_call_expression(
                             translated_definition.ensure_use_declared("std::bytes::Bytes");
 
                             // Generate a unique variable name
-                            let variable_name = scope.borrow_mut().generate_unique_variable_name("bytes");
+                            let variable_name = scope.write().unwrap().generate_unique_variable_name("bytes");
 
                             let parameters = arguments.iter()
                                 .map(|a| translate_expression(project, translated_definition, scope.clone(), a))
@@ -2529,7 +3649,7 @@ pub fn translate_function_call_expression(
                             // TODO: how should this be handled?
                             //
 
-                            return Ok(sway::Expression::create_todo(Some(expression.to_string())))
+                            return Ok(create_todo_expression(project, translated_definition, &expression.loc(), Some(expression.to_string())))
                         }
                         
                         "encodeWithSignature" => {
@@ -2539,7 +3659,7 @@ pub fn translate_function_call_expression(
                             // TODO: how should this be handled?
                             //
 
-                            return Ok(sway::Expression::create_todo(Some(expression.to_string())))
+                            return Ok(create_todo_expression(project, translated_definition, &expression.loc(), Some(expression.to_string())))
                         }
                         
                         "encodeCall" => {
@@ -2549,10 +3669,10 @@ pub fn translate_function_call_expression(
                             // TODO: how should this be handled?
                             //
 
-                            return Ok(sway::Expression::create_todo(Some(expression.to_string())))
+                            return Ok(create_todo_expression(project, translated_definition, &expression.loc(), Some(expression.to_string())))
                         }
                         
-                        member => todo!("handle `abi.{member}` translation"),
+                        member => return Err(Error::UnsupportedConstruct(expression.loc(), format!("handle `abi.{member}` translation"))),
                     }
 
                     "super" => {
@@ -2565,7 +3685,7 @@ pub fn translate_function_call_expression(
                             .collect::<Result<Vec<_>, _>>()?;
 
                         for inherit in translated_definition.inherits.clone() {
-                            let Some(inherited_definition) = project.find_definition_with_abi(&inherit).cloned() else { continue };
+                            let Some(inherited_definition) = project.find_definition_with_abi(&translated_definition.path, &inherit).cloned() else { continue };
 
                             if let Some(named_arguments) = named_arguments {
                                 let mut named_parameters = vec![];
@@ -2577,8 +3697,8 @@ pub fn translate_function_call_expression(
                                     ));
                                 }
         
-                                if let Some(function) = inherited_definition.toplevel_scope.borrow().find_function(|f| {
-                                    let f = f.borrow();
+                                if let Some(function) = inherited_definition.toplevel_scope.read().unwrap().find_function(|f| {
+                                    let f = f.read().unwrap();
         
                                     if f.old_name != member.name.as_str() {
                                         return false;
@@ -2590,7 +3710,7 @@ pub fn translate_function_call_expression(
         
                                     f.parameters.entries.iter().all(|p| named_parameters.iter().any(|(name, _)| p.name == *name))
                                 }) {
-                                    let function = function.borrow();
+                                    let function = function.read().unwrap();
         
                                     parameters = vec![];
                                     parameter_types = vec![];
@@ -2610,13 +3730,13 @@ pub fn translate_function_call_expression(
                                 }
                             }
             
-                            let Some(inherited_function) = inherited_definition.toplevel_scope.borrow().find_function_matching_types(
+                            let Some(inherited_function) = inherited_definition.toplevel_scope.read().unwrap().find_function_matching_types(
                                 member.name.as_str(),
                                 parameters.as_slice(),
                                 parameter_types.as_slice(),
                             ) else { continue };
 
-                            let inherited_function = inherited_function.borrow();
+                            let inherited_function = inherited_function.read().unwrap();
 
                             return Ok(sway::Expression::from(sway::FunctionCall {
                                 function: sway::Expression::Identifier(inherited_function.new_name.clone()),
@@ -2625,7 +3745,7 @@ pub fn translate_function_call_expression(
                             }));
                         }
 
-                        todo!("handle super member access function `{member:#?}`")
+                        return Err(Error::UnsupportedConstruct(expression.loc(), format!("handle super member access function `{member}`")))
                     }
 
                     "this" => {
@@ -2647,8 +3767,8 @@ pub fn translate_function_call_expression(
                                 ));
                             }
 
-                            if let Some(function) = scope.borrow().find_function(|f| {
-                                let f = f.borrow();
+                            if let Some(function) = scope.read().unwrap().find_function(|f| {
+                                let f = f.read().unwrap();
 
                                 if f.old_name != member.name {
                                     return false;
@@ -2660,7 +3780,7 @@ pub fn translate_function_call_expression(
 
                                 f.parameters.entries.iter().all(|p| named_parameters.iter().any(|(name, _)| p.name == *name))
                             }) {
-                                let function = function.borrow();
+                                let function = function.read().unwrap();
 
                                 parameters = vec![];
                                 parameter_types = vec![];
@@ -2680,9 +3800,9 @@ pub fn translate_function_call_expression(
                             }
                         }
 
-                        if let Some(function) = scope.borrow().find_function_matching_types(&member.name, &parameters, &parameter_types) {
+                        if let Some(function) = scope.read().unwrap().find_function_matching_types(&member.name, &parameters, &parameter_types) {
                             return Ok(sway::Expression::from(sway::FunctionCall {
-                                function: sway::Expression::Identifier(function.borrow().new_name.clone()),
+                                function: sway::Expression::Identifier(function.read().unwrap().new_name.clone()),
                                 generic_parameters: None,
                                 parameters,
                             }));
@@ -2701,7 +3821,7 @@ pub fn translate_function_call_expression(
                         // TODO: check full inheritance heirarchy
                         // Check for explicit super function calls
                         if translated_definition.inherits.iter().any(|i| i == name) {
-                            if let Some(inherited_definition) = project.find_definition_with_abi(name).cloned() {
+                            if let Some(inherited_definition) = project.find_definition_with_abi(&translated_definition.path, name).cloned() {
                                 if let Some(named_arguments) = named_arguments {
                                     let mut named_parameters = vec![];
             
@@ -2712,8 +3832,8 @@ pub fn translate_function_call_expression(
                                         ));
                                     }
             
-                                    if let Some(function) = inherited_definition.toplevel_scope.borrow().find_function(|f| {
-                                        let f = f.borrow();
+                                    if let Some(function) = inherited_definition.toplevel_scope.read().unwrap().find_function(|f| {
+                                        let f = f.read().unwrap();
             
                                         if f.old_name != member.name.as_str() {
                                             return false;
@@ -2725,7 +3845,7 @@ pub fn translate_function_call_expression(
             
                                         f.parameters.entries.iter().all(|p| named_parameters.iter().any(|(name, _)| p.name == *name))
                                     }) {
-                                        let function = function.borrow();
+                                        let function = function.read().unwrap();
             
                                         parameters = vec![];
                                         parameter_types = vec![];
@@ -2745,12 +3865,12 @@ pub fn translate_function_call_expression(
                                     }
                                 }
                 
-                                if let Some(inherited_function) = inherited_definition.toplevel_scope.borrow().find_function_matching_types(
+                                if let Some(inherited_function) = inherited_definition.toplevel_scope.read().unwrap().find_function_matching_types(
                                     member.name.as_str(),
                                     parameters.as_slice(),
                                     parameter_types.as_slice(),
                                 ) {
-                                    let inherited_function = inherited_function.borrow();
+                                    let inherited_function = inherited_function.read().unwrap();
         
                                     return Ok(sway::Expression::from(sway::FunctionCall {
                                         function: sway::Expression::Identifier(inherited_function.new_name.clone()),
@@ -2772,7 +3892,7 @@ pub fn translate_function_call_expression(
                         }
 
                         // Check if function is contained in an external definition
-                        if let Some(external_definition) = project.translated_definitions.iter().find(|x| x.name == name).cloned() {
+                        if let Some(external_definition) = project.find_symbol(&translated_definition.path, SymbolKind::Function, &member.name, |d| d.name == *name).cloned() {
                             let old_name = member.name.clone();
     
                             if let Some(named_arguments) = named_arguments {
@@ -2785,8 +3905,8 @@ pub fn translate_function_call_expression(
                                     ));
                                 }
         
-                                if let Some(function) = scope.borrow().find_function(|f| {
-                                    let f = f.borrow();
+                                if let Some(function) = scope.read().unwrap().find_function(|f| {
+                                    let f = f.read().unwrap();
         
                                     if f.old_name != old_name {
                                         return false;
@@ -2798,7 +3918,7 @@ pub fn translate_function_call_expression(
         
                                     f.parameters.entries.iter().all(|p| named_parameters.iter().any(|(name, _)| p.name == *name))
                                 }) {
-                                    let function = function.borrow();
+                                    let function = function.read().unwrap();
         
                                     parameters = vec![];
                                     parameter_types = vec![];
@@ -2818,56 +3938,11 @@ pub fn translate_function_call_expression(
                                 }
                             }
         
-                            // Check if the member is a function defined in the toplevel scope
-                            let Some(external_function_declaration) = external_definition.toplevel_scope.borrow().find_function_matching_types(
-                                old_name.as_str(),
-                                parameters.as_slice(),
-                                parameter_types.as_slice(),
-                            ) else {
-                                panic!(
-                                    "error: Failed to find function in scope: {name}.{old_name}({})",
-                                    parameter_types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", "),
-                                );
-                            };
-    
-                            let external_function_declaration = external_function_declaration.borrow();
-    
-                            // Get the external function definition
-                            let Some(external_function_definition) = external_definition.functions.iter().find(|f| {
-                                f.name == external_function_declaration.new_name
-                                && f.parameters.entries.len() == external_function_declaration.parameters.entries.len()
-                            }) else {
-                                panic!(
-                                    "{}error: Failed to find function in scope: {name}.{old_name}({})",
-                                    match project.loc_to_line_and_column(&translated_definition.path, &container.loc()) {
-                                        Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                                        None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                                    },
-                                    parameter_types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", "),
-                                );
-                            };
-    
                             // Import the function if we haven't already
-                            if translated_definition.toplevel_scope.borrow().find_function_matching_types(
-                                old_name.as_str(),
-                                parameters.as_slice(),
-                                parameter_types.as_slice(),
-                            ).is_none() {
-                                // Create the local function definition
-                                let mut local_function_definition = external_function_definition.clone();
-                                local_function_definition.name = external_function_declaration.new_name.clone();
-                                
-                                // Add the local function definition to the beginning of the list
-                                translated_definition.functions.insert(0, local_function_definition);
-    
-                                // Create the local function declaration for the toplevel scope
-                                let mut local_function_declaration = external_function_declaration.clone();
-                                local_function_declaration.old_name = external_function_declaration.old_name.clone();
-                                local_function_declaration.new_name = external_function_declaration.new_name.clone();
-    
-                                // Add the local function to the beginning of the toplevel scope
-                                translated_definition.toplevel_scope.borrow_mut().functions.insert(0, Rc::new(RefCell::new(local_function_declaration.clone())));
-                            }
+                            let Some(external_function_declaration) = import_external_function(translated_definition, &external_definition, old_name.as_str(), parameters.as_slice(), parameter_types.as_slice()) else {
+                                return Err(Error::UnresolvedReference(expression.loc(), format!("Failed to find function in scope: {name}.{old_name}({})",
+                                    parameter_types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", "),)));
+                            };
     
                             // Create the function call
                             let function_call = sway::Expression::from(sway::FunctionCall {
@@ -2896,8 +3971,27 @@ pub fn translate_function_call_expression(
             let mut container = translate_expression(project, translated_definition, scope.clone(), container)?;
             let type_name = translated_definition.get_expression_type(scope.clone(), &container)?;
 
+            // Check if the member is a struct field of an internal function type, which is called through its dispatch function
+            let type_name_string = type_name.to_string();
+            let field_name = crate::translate_naming_convention(member.name.as_str(), Case::Snake);
+
+            if let Some(field_type_name) = translated_definition.structs.iter()
+                .find(|s| s.name == type_name_string)
+                .and_then(|s| s.fields.iter().find(|f| f.name == field_name))
+                .map(|f| f.type_name.clone())
+            {
+                if let Some(dispatch_function_name) = translated_definition.get_function_type_dispatch_name(&field_type_name) {
+                    let function_value = sway::Expression::from(sway::MemberAccess {
+                        expression: container,
+                        member: field_name,
+                    });
+                    
+                    return create_function_value_call_expression(project, translated_definition, scope.clone(), dispatch_function_name, function_value, arguments);
+                }
+            }
+
             match &type_name {
-                sway::TypeName::Undefined => panic!("Undefined type name"),
+                sway::TypeName::Undefined => Err(Error::InvalidConstruct(expression.loc(), "Undefined type name".into())),
                 
                 sway::TypeName::Identifier { name, generic_parameters } => match (name.as_str(), generic_parameters.as_ref()) {
                     ("Identity", None) => {
@@ -2972,7 +4066,7 @@ pub fn translate_function_call_expression(
                                 // TODO: is delegatecall possible?
                                 //
 
-                                return Ok(sway::Expression::create_todo(Some(expression.to_string())));
+                                return Ok(create_todo_expression(project, translated_definition, &expression.loc(), Some(expression.to_string())));
                             }
 
                             "staticcall" => {
@@ -2980,7 +4074,7 @@ pub fn translate_function_call_expression(
                                 // TODO: is staticcall possible?
                                 //
 
-                                return Ok(sway::Expression::create_todo(Some(expression.to_string())));
+                                return Ok(create_todo_expression(project, translated_definition, &expression.loc(), Some(expression.to_string())));
                             }
 
                             _ => {}
@@ -2992,8 +4086,8 @@ pub fn translate_function_call_expression(
 
                         // Check using directives for Identity-specific function
                         for using_directive in translated_definition.using_directives.iter() {
-                            let Some(external_definition) = project.translated_definitions.iter().find(|d| {
-                                d.name == using_directive.library_name && matches!(d.kind.as_ref().unwrap(), solidity::ContractTy::Library(_))
+                            let Some(external_definition) = project.find_symbol(&translated_definition.path, SymbolKind::Definition, &using_directive.library_name, |d| {
+                                matches!(d.kind.as_ref().unwrap(), solidity::ContractTy::Library(_))
                             }).cloned() else { continue };
 
                             if let Some(for_type_name) = &using_directive.for_type {
@@ -3002,8 +4096,8 @@ pub fn translate_function_call_expression(
                                 }
                             }
                             
-                            for f in external_definition.toplevel_scope.borrow().functions.iter() {
-                                let f = f.borrow();
+                            for f in external_definition.toplevel_scope.read().unwrap().functions.iter() {
+                                let f = f.read().unwrap();
 
                                 if f.old_name != member.name {
                                     continue;
@@ -3034,13 +4128,13 @@ pub fn translate_function_call_expression(
 
                         // Check if expression is a variable that had an ABI type
                         if let Some(variable) = variable.as_ref() {
-                            let variable = variable.borrow();
+                            let variable = variable.read().unwrap();
 
                             if let Some(abi_type_name) = variable.abi_type_name.as_ref() {
                                 let abi_type_name = abi_type_name.to_string();
 
                                 // Ensure the ABI is added to the current definition
-                                if let Some(external_definition) = project.find_definition_with_abi(abi_type_name.as_str()) {
+                                if let Some(external_definition) = project.find_definition_with_abi(&translated_definition.path, abi_type_name.as_str()) {
                                     if let Some(abi) = external_definition.abi.as_ref() {
                                         if abi.name == abi_type_name && !translated_definition.abis.iter().any(|a| a.name == abi.name) {
                                             translated_definition.abis.push(abi.clone());
@@ -3086,7 +4180,7 @@ pub fn translate_function_call_expression(
                         }
 
                         // Check to see if the type is located in an external ABI
-                        if let Some(external_definition) = project.find_definition_with_abi(name.as_str()) {
+                        if let Some(external_definition) = project.find_definition_with_abi(&translated_definition.path, name.as_str()) {
                             let external_abi = external_definition.abi.as_ref().unwrap();
 
                             // Check lower case names for regular functions
@@ -3099,7 +4193,7 @@ pub fn translate_function_call_expression(
                                 return Ok(sway::Expression::from(sway::FunctionCall {
                                     function: sway::Expression::from(sway::MemberAccess {
                                         expression: container.clone(),
-                                        member: new_name_lower.into(),
+                                        member: new_name_lower,
                                     }),
                                     generic_parameters: None,
                                     parameters: arguments.iter()
@@ -3118,7 +4212,7 @@ pub fn translate_function_call_expression(
                                 return Ok(sway::Expression::from(sway::FunctionCall {
                                     function: sway::Expression::from(sway::MemberAccess {
                                         expression: container.clone(),
-                                        member: new_name_upper.into(),
+                                        member: new_name_upper,
                                     }),
                                     generic_parameters: None,
                                     parameters: arguments.iter()
@@ -3128,17 +4222,17 @@ pub fn translate_function_call_expression(
                             }
                         }
 
-                        todo!("translate Identity member function call `{member}`: {} - {container:#?} - {:#?}", sway::TabbedDisplayer(&container), variable.unwrap().borrow())
+                        Err(Error::UnsupportedConstruct(expression.loc(), format!("translate Identity member function call `{member}`: {}", sway::TabbedDisplayer(&container))))
                     }
                     
                     ("StorageVec", Some(_)) => match member.name.as_str() {
                         "push" => {
                             let (Some(variable), Some(container_access)) = (variable, container_access) else {
-                                panic!("StorageVec is not a variable");
+                                return Err(Error::InvalidConstruct(expression.loc(), "StorageVec is not a variable".into()));
                             };
 
-                            if !variable.borrow().is_storage {
-                                panic!("StorageVec is not in storage");
+                            if !variable.read().unwrap().is_storage {
+                                return Err(Error::InvalidConstruct(expression.loc(), "StorageVec is not in storage".into()));
                             }
 
                             Ok(sway::Expression::from(sway::FunctionCall {
@@ -3157,11 +4251,11 @@ pub fn translate_function_call_expression(
 
                         "pop" => {
                             let (Some(variable), Some(container_access)) = (variable, container_access) else {
-                                panic!("StorageVec is not a variable");
+                                return Err(Error::InvalidConstruct(expression.loc(), "StorageVec is not a variable".into()));
                             };
 
-                            if !variable.borrow().is_storage {
-                                panic!("StorageVec is not in storage");
+                            if !variable.read().unwrap().is_storage {
+                                return Err(Error::InvalidConstruct(expression.loc(), "StorageVec is not in storage".into()));
                             }
 
                             Ok(sway::Expression::from(sway::FunctionCall {
@@ -3176,11 +4270,11 @@ pub fn translate_function_call_expression(
 
                         "remove" => {
                             let (Some(variable), Some(container_access)) = (variable, container_access) else {
-                                panic!("StorageVec is not a variable");
+                                return Err(Error::InvalidConstruct(expression.loc(), "StorageVec is not a variable".into()));
                             };
 
-                            if !variable.borrow().is_storage {
-                                panic!("StorageVec is not in storage");
+                            if !variable.read().unwrap().is_storage {
+                                return Err(Error::InvalidConstruct(expression.loc(), "StorageVec is not in storage".into()));
                             }
 
                             Ok(sway::Expression::from(sway::FunctionCall {
@@ -3197,13 +4291,13 @@ pub fn translate_function_call_expression(
                             }))
                         }
 
-                        _ => todo!("translate StorageVec member function call `{member}`: {} - {container:#?} - {:#?}", sway::TabbedDisplayer(&container), variable.unwrap().borrow())
+                        _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate StorageVec member function call `{member}`: {}", sway::TabbedDisplayer(&container))))
                     }
 
                     ("Vec", Some(_)) => match member.name.as_str() {
                         "push" => {
                             let (Some(_), Some(container_access)) = (variable, container_access) else {
-                                panic!("Vec is not a variable");
+                                return Err(Error::InvalidConstruct(expression.loc(), "Vec is not a variable".into()));
                             };
 
                             Ok(sway::Expression::from(sway::FunctionCall {
@@ -3222,7 +4316,7 @@ pub fn translate_function_call_expression(
 
                         "pop" => {
                             let (Some(_), Some(container_access)) = (variable, container_access) else {
-                                panic!("Vec is not a variable");
+                                return Err(Error::InvalidConstruct(expression.loc(), "Vec is not a variable".into()));
                             };
 
                             Ok(sway::Expression::from(sway::FunctionCall {
@@ -3237,7 +4331,7 @@ pub fn translate_function_call_expression(
 
                         "remove" => {
                             let (Some(_), Some(container_access)) = (variable, container_access) else {
-                                panic!("Vec is not a variable");
+                                return Err(Error::InvalidConstruct(expression.loc(), "Vec is not a variable".into()));
                             };
 
                             Ok(sway::Expression::from(sway::FunctionCall {
@@ -3254,7 +4348,7 @@ pub fn translate_function_call_expression(
                             }))
                         }
 
-                        _ => todo!("translate Vec member function call `{member}`: {} - {container:#?} - {:#?}", sway::TabbedDisplayer(&container), variable.unwrap().borrow())
+                        _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate Vec member function call `{member}`: {}", sway::TabbedDisplayer(&container))))
                     }
 
                     _ => {
@@ -3338,7 +4432,7 @@ pub fn translate_function_call_expression(
                                 for (i, value_type_name) in using_parameter_types.iter().enumerate() {
                                     let Some(parameter_type_name) = f.parameters.entries[i].type_name.as_ref() else { continue };
                                     
-                                    if !value_type_name.is_compatible_with(parameter_type_name) {
+                                    if !value_type_name.is_compatible_with(parameter_type_name) && !can_coerce_signed_expression(parameter_type_name, &using_parameters[i], value_type_name) {
                                         return false;
                                     }
                                 }
@@ -3347,6 +4441,13 @@ pub fn translate_function_call_expression(
                             }) {
                                 *translated_definition.function_call_counts.entry(function.new_name.clone()).or_insert(0) += 1;
 
+                                // Convert literals and smaller signed integers to the signed parameter types of the function
+                                for (parameter, entry) in using_parameters.iter_mut().zip(function.parameters.entries.iter()) {
+                                    if let Some(type_name) = entry.type_name.as_ref() {
+                                        *parameter = coerce_signed_expression(translated_definition, scope.clone(), type_name, parameter.clone());
+                                    }
+                                }
+
                                 return Ok(sway::Expression::from(sway::FunctionCall {
                                     function: sway::Expression::Identifier(function.new_name.clone()),
                                     generic_parameters: None,
@@ -3356,7 +4457,7 @@ pub fn translate_function_call_expression(
                         }
 
                         // Check if this is a function from an ABI
-                        if let Some(definition) = project.find_definition_with_abi(name).cloned() {
+                        if let Some(definition) = project.find_definition_with_abi(&translated_definition.path, name).cloned() {
                             if let Some(named_arguments) = named_arguments {
                                 let mut named_parameters = vec![];
         
@@ -3367,8 +4468,8 @@ pub fn translate_function_call_expression(
                                     ));
                                 }
         
-                                if let Some(function) = definition.toplevel_scope.borrow().find_function(|f| {
-                                    let f = f.borrow();
+                                if let Some(function) = definition.toplevel_scope.read().unwrap().find_function(|f| {
+                                    let f = f.read().unwrap();
         
                                     if f.old_name != member.name {
                                         return false;
@@ -3380,7 +4481,7 @@ pub fn translate_function_call_expression(
         
                                     f.parameters.entries.iter().all(|p| named_parameters.iter().any(|(name, _)| p.name == *name))
                                 }) {
-                                    let function = function.borrow();
+                                    let function = function.read().unwrap();
         
                                     parameters = vec![];
                                     parameter_types = vec![];
@@ -3400,8 +4501,8 @@ pub fn translate_function_call_expression(
                                 }
                             }
                             
-                            if let Some(function) = definition.toplevel_scope.borrow().find_function_matching_types(&member.name, &parameters, &parameter_types) {
-                                let function = function.borrow();
+                            if let Some(function) = definition.toplevel_scope.read().unwrap().find_function_matching_types(&member.name, &parameters, &parameter_types) {
+                                let function = function.read().unwrap();
                                 
                                 *translated_definition.function_call_counts.entry(function.new_name.clone()).or_insert(0) += 1;
 
@@ -3416,14 +4517,14 @@ pub fn translate_function_call_expression(
                             }
                         }
 
-                        todo!("translate {name} member function call: {}.{member}({}) - {container:#?}", sway::TabbedDisplayer(&container), parameter_types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", "))
+                        Err(Error::UnsupportedConstruct(expression.loc(), format!("translate {name} member function call: {}.{member}({})", sway::TabbedDisplayer(&container), parameter_types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", "))))
                     }
                 }
 
-                sway::TypeName::Array { .. } => todo!("translate array member function call: {} - {container:#?}", sway::TabbedDisplayer(&container)),
-                sway::TypeName::Tuple { .. } => todo!("translate tuple member function call: {} - {container:#?}", sway::TabbedDisplayer(&container)),
-                sway::TypeName::StringSlice => todo!("translate string slice member function call: {} - {container:#?}", sway::TabbedDisplayer(&container)),
-                sway::TypeName::StringArray { .. } => todo!("translate string array member function call: {} - {container:#?}", sway::TabbedDisplayer(&container)),
+                sway::TypeName::Array { .. } => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate array member function call: {}", sway::TabbedDisplayer(&container)))),
+                sway::TypeName::Tuple { .. } => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate tuple member function call: {}", sway::TabbedDisplayer(&container)))),
+                sway::TypeName::StringSlice => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate string slice member function call: {}", sway::TabbedDisplayer(&container)))),
+                sway::TypeName::StringArray { .. } => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate string array member function call: {}", sway::TabbedDisplayer(&container)))),
             }
         }
 
@@ -3434,7 +4535,7 @@ pub fn translate_function_call_expression(
                 let type_name = translated_definition.get_expression_type(scope.clone(), &container)?;
 
                 let solidity::Statement::Args(_, block_args) = block.as_ref() else {
-                    panic!("Malformed `address.call` call, expected args block, found: {block:#?}");
+                    return Err(Error::InvalidConstruct(expression.loc(), format!("Malformed `address.call` call, expected args block, found: {block}")));
                 };
 
                 let mut coins = None;
@@ -3444,18 +4545,18 @@ pub fn translate_function_call_expression(
                     match block_arg.name.name.as_str() {
                         "value" => coins = Some(translate_expression(project, translated_definition, scope.clone(), &block_arg.expr)?),
                         "gas" => gas = Some(translate_expression(project, translated_definition, scope.clone(), &block_arg.expr)?),
-                        arg => todo!("address.transfer block arg: {arg}"),
+                        arg => return Err(Error::UnsupportedConstruct(expression.loc(), format!("address.transfer block arg: {arg}"))),
                     }
                 }
 
                 match type_name {
-                    sway::TypeName::Undefined => panic!("Undefined type name"),
+                    sway::TypeName::Undefined => Err(Error::InvalidConstruct(expression.loc(), "Undefined type name".into())),
                     
                     sway::TypeName::Identifier { name, .. } => match name.as_str() {
                         "Identity" => match member.name.as_str() {
                             "call" => {
                                 if arguments.len() != 1 {
-                                    panic!("Malformed `address.call` call, expected 1 argument, found {}", arguments.len());
+                                    return Err(Error::InvalidConstruct(expression.loc(), format!("Malformed `address.call` call, expected 1 argument, found {}", arguments.len())));
                                 }
 
                                 let payload = translate_expression(project, translated_definition, scope.clone(), &arguments[0])?;
@@ -3468,13 +4569,13 @@ pub fn translate_function_call_expression(
     
                                 // Check if expression is a variable that had an ABI type
                                 if let Some(variable) = variable.as_ref() {
-                                    let variable = variable.borrow();
+                                    let variable = variable.read().unwrap();
     
                                     if let Some(abi_type_name) = variable.abi_type_name.as_ref() {
                                         let abi_type_name = abi_type_name.to_string();
     
                                         // Ensure the ABI is added to the current definition
-                                        if let Some(external_definition) = project.find_definition_with_abi(abi_type_name.as_str()) {
+                                        if let Some(external_definition) = project.find_definition_with_abi(&translated_definition.path, abi_type_name.as_str()) {
                                             if let Some(abi) = external_definition.abi.as_ref() {
                                                 if abi.name == abi_type_name && !translated_definition.abis.iter().any(|a| a.name == abi.name) {
                                                     translated_definition.abis.push(abi.clone());
@@ -3520,7 +4621,7 @@ pub fn translate_function_call_expression(
                                 }
         
                                 // Check to see if the type is located in an external ABI
-                                if let Some(external_definition) = project.find_definition_with_abi(name.as_str()) {
+                                if let Some(external_definition) = project.find_definition_with_abi(&translated_definition.path, name.as_str()) {
                                     let external_abi = external_definition.abi.as_ref().unwrap();
 
                                     if external_abi.functions.iter().any(|f| f.name == external_function_new_name) {
@@ -3559,24 +4660,36 @@ pub fn translate_function_call_expression(
                                     }
                                 }
 
-                                todo!("translate Identity member function call block `{member}{}`: {} - {container:#?}", block.to_string(), sway::TabbedDisplayer(&container))
+                                Err(Error::UnsupportedConstruct(expression.loc(), format!("translate Identity member function call block `{member}{}`: {}", block, sway::TabbedDisplayer(&container))))
                             }
                         }
 
-                        _ => todo!("translate {name} member function call block: {} - {container:#?}", sway::TabbedDisplayer(&container))
+                        _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate {name} member function call block: {}", sway::TabbedDisplayer(&container))))
                     }
 
-                    sway::TypeName::Array { .. } => todo!(),
-                    sway::TypeName::Tuple { .. } => todo!(),
-                    sway::TypeName::StringSlice => todo!(),
-                    sway::TypeName::StringArray { .. } => todo!(),
+                    sway::TypeName::Array { .. } => Err(Error::UnsupportedConstruct(expression.loc(), "TODO".into())),
+                    sway::TypeName::Tuple { .. } => Err(Error::UnsupportedConstruct(expression.loc(), "TODO".into())),
+                    sway::TypeName::StringSlice => Err(Error::UnsupportedConstruct(expression.loc(), "TODO".into())),
+                    sway::TypeName::StringArray { .. } => Err(Error::UnsupportedConstruct(expression.loc(), "TODO".into())),
                 }
             }
 
-            _ => todo!("translate function call block expression: {expression} - {expression:#?}")
+            _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate function call block expression: {expression}")))
+        }
+
+        solidity::Expression::ArraySubscript(_, _, _) => {
+            // Elements of arrays and mappings of internal function types are called through their dispatch function
+            let function_value = translate_expression(project, translated_definition, scope.clone(), function)?;
+            let type_name = translated_definition.get_expression_type(scope.clone(), &function_value)?;
+
+            let Some(dispatch_function_name) = translated_definition.get_function_type_dispatch_name(&type_name) else {
+                return Err(Error::UnsupportedConstruct(expression.loc(), format!("translate call of {type_name} value: {expression}")));
+            };
+
+            create_function_value_call_expression(project, translated_definition, scope.clone(), dispatch_function_name, function_value, arguments)
         }
 
-        _ => todo!("translate function call expression: {expression} - {expression:#?}"),
+        _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate function call expression: {expression} - {expression}"))),
     }
 }
 
@@ -3584,7 +4697,7 @@ pub fn translate_function_call_expression(
 pub fn translate_address_call_expression(
     _project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     payload: sway::Expression,
     coins: Option<sway::Expression>,
     asset_id: Option<sway::Expression>,
@@ -3612,9 +4725,9 @@ pub fn translate_address_call_expression(
     translated_definition.ensure_use_declared("std::bytes::Bytes");
 
     // Create unique variable names
-    let return_ptr_name = scope.borrow_mut().generate_unique_variable_name("return_ptr");
-    let return_length_name = scope.borrow_mut().generate_unique_variable_name("return_length");
-    let result_ptr_name = scope.borrow_mut().generate_unique_variable_name("result_ptr");
+    let return_ptr_name = scope.write().unwrap().generate_unique_variable_name("return_ptr");
+    let return_length_name = scope.write().unwrap().generate_unique_variable_name("return_length");
+    let result_ptr_name = scope.write().unwrap().generate_unique_variable_name("result_ptr");
 
     Ok(sway::Expression::from(sway::Block {
         statements: vec![
@@ -3801,26 +4914,76 @@ pub fn translate_address_call_expression(
 pub fn translate_function_call_block_expression(
     _project: &mut Project,
     _translated_definition: &mut TranslatedDefinition,
-    _scope: Rc<RefCell<TranslationScope>>,
+    _scope: Arc<RwLock<TranslationScope>>,
     _function: &solidity::Expression,
     _block: &solidity::Statement,
 ) -> Result<sway::Expression, Error> {
-    todo!("translate function call block expression")
+    Err(Error::UnsupportedConstruct(_function.loc(), "translate function call block expression".into()))
 }
 
 #[inline]
 pub fn translate_unary_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     operator: &str,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     let expression = translate_expression(project, translated_definition, scope.clone(), expression)?;
 
+    // Signed integers don't implement bitwise not, so invert their two's complement bits instead
+    if operator == "!" {
+        let type_name = translated_definition.get_expression_type(scope.clone(), &expression)?;
+        let type_name = translated_definition.get_underlying_type(&type_name);
+
+        if let Some(bits) = type_name.int_bits() {
+            return Ok(create_signed_from_bits_expression(bits, sway::Expression::from(sway::UnaryExpression {
+                operator: operator.into(),
+                expression: create_signed_bits_expression(bits, expression),
+            })));
+        }
+    }
+
     // NOTE: Sway does not have a negate operator, so we need to make sure to use the correct translation
     if operator == "-" {
-        let type_name = translated_definition.get_expression_type(scope, &expression)?;
+        let type_name = translated_definition.get_expression_type(scope.clone(), &expression)?;
+        let int_width = get_signed_operation_width(translated_definition, scope.clone(), operator, &expression, None);
+        let type_name = translated_definition.get_underlying_type(&type_name);
+
+        // Negating the minimum value of widths which Sway doesn't have reverts, or wraps in unchecked scopes
+        if int_width.is_some_and(|width| width < get_int_storage_bits(width)) {
+            let expression = sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::from(sway::MemberAccess {
+                    expression,
+                    member: "neg".into(),
+                }),
+                generic_parameters: None,
+                parameters: vec![],
+            });
+
+            return Ok(create_checked_int_expression(translated_definition, scope.clone(), int_width, operator, expression));
+        }
+
+        // Negating the minimum value wraps in unchecked scopes: I{bits} { underlying: wrapping_sub_u{bits}(0, x) }
+        if let Some(bits) = type_name.int_bits() {
+            if scope.read().unwrap().is_unchecked() {
+                let underlying_type_name = sway::TypeName::Identifier {
+                    name: if bits == 128 { "U128".into() } else { format!("u{bits}") },
+                    generic_parameters: None,
+                };
+
+                let function_name = translated_definition.ensure_wrapping_function_declared("-", &underlying_type_name)?;
+
+                return Ok(create_signed_from_bits_expression(bits, sway::Expression::from(sway::FunctionCall {
+                    function: sway::Expression::Identifier(function_name),
+                    generic_parameters: None,
+                    parameters: vec![
+                        create_uint_literal_expression(translated_definition, bits, BigUint::zero()),
+                        create_signed_bits_expression(bits, expression),
+                    ],
+                })));
+            }
+        }
 
         match &type_name {
             sway::TypeName::Identifier { name, generic_parameters } => match (name.as_str(), generic_parameters.as_ref()) {
@@ -3834,23 +4997,17 @@ pub fn translate_unary_expression(
                 })),
 
                 _ => {
-                    // HACK: allow literals to be negated
-                    if let sway::Expression::Literal(sway::Literal::DecInt(_) | sway::Literal::HexInt(_)) = &expression {
-                        return Ok(sway::Expression::from(sway::FunctionCall {
-                            function: sway::Expression::from(sway::MemberAccess {
-                                expression,
-                                member: "neg".into(),
-                            }),
-                            generic_parameters: None,
-                            parameters: vec![],
-                        }));
+                    // Negated literals are `int256` literals, which are converted to the signed type expected where they're used
+                    if let sway::Expression::Literal(sway::Literal::DecInt(value) | sway::Literal::HexInt(value)) = &expression {
+                        translated_definition.ensure_use_declared("signed_integers::i256::*");
+                        return Ok(create_signed_literal_expression(translated_definition, 256, value, true));
                     }
 
-                    panic!("Unhandled {type_name} negate operator translation")
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Unhandled {type_name} negate operator translation")))
                 }
             }
 
-            _ => panic!("Unhandled {type_name} negate operator translation"),
+            _ => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Unhandled {type_name} negate operator translation"))),
         }
     }
 
@@ -3864,7 +5021,7 @@ pub fn translate_unary_expression(
 pub fn translate_power_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     lhs: &solidity::Expression,
     rhs: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
@@ -3875,8 +5032,13 @@ pub fn translate_power_expression(
 
     let lhs = translate_expression(project, translated_definition, scope.clone(), lhs)?;
     let rhs = translate_expression(project, translated_definition, scope.clone(), rhs)?;
+    let int_width = get_signed_operation_width(translated_definition, scope.clone(), "**", &lhs, None);
 
-    Ok(sway::Expression::from(sway::FunctionCall {
+    if let Some(expression) = create_wrapping_expression(translated_definition, scope.clone(), "**", &lhs, &rhs)? {
+        return Ok(create_checked_int_expression(translated_definition, scope.clone(), int_width, "**", expression));
+    }
+
+    let expression = sway::Expression::from(sway::FunctionCall {
         function: sway::Expression::from(sway::MemberAccess {
             expression: lhs,
             member: "pow".into(),
@@ -3885,14 +5047,17 @@ pub fn translate_power_expression(
         parameters: vec![
             rhs,
         ],
-    }))
+    });
+
+    let expression = create_checked_uint_expression(translated_definition, scope.clone(), "**", expression);
+    Ok(create_checked_int_expression(translated_definition, scope, int_width, "**", expression))
 }
 
 #[inline]
 pub fn translate_binary_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     operator: &str,
     lhs: &solidity::Expression,
     rhs: &solidity::Expression,
@@ -3933,22 +5098,312 @@ pub fn translate_binary_expression(
         }
     }
 
-    Ok(sway::Expression::from(sway::BinaryExpression {
+    let (mut lhs, mut rhs, fixed_point_type_name) = translate_binary_operands(project, translated_definition, scope.clone(), lhs, rhs)?;
+
+    if let Some(type_name) = fixed_point_type_name.as_ref() {
+        if let Some(expression) = create_fixed_point_binary_expression(translated_definition, type_name, operator, lhs.clone(), rhs.clone())? {
+            return Ok(expression);
+        }
+    }
+
+    // Get the width of signed operations before the operands are converted to a common type
+    let int_width = get_signed_operation_width(translated_definition, scope.clone(), operator, &lhs, Some(&rhs));
+
+    if let Some(expression) = create_signed_operands_binary_expression(translated_definition, scope.clone(), operator, &mut lhs, &mut rhs) {
+        return Ok(create_checked_int_expression(translated_definition, scope.clone(), int_width, operator, expression));
+    }
+
+    if let Some(expression) = create_wrapping_expression(translated_definition, scope.clone(), operator, &lhs, &rhs)? {
+        return Ok(create_checked_int_expression(translated_definition, scope.clone(), int_width, operator, expression));
+    }
+
+    let expression = sway::Expression::from(sway::BinaryExpression {
         operator: operator.into(),
-        lhs: translate_expression(project, translated_definition, scope.clone(), lhs)?,
-        rhs: translate_expression(project, translated_definition, scope.clone(), rhs)?,
-    }))
+        lhs,
+        rhs,
+    });
+
+    let expression = create_checked_uint_expression(translated_definition, scope.clone(), operator, expression);
+    Ok(create_checked_int_expression(translated_definition, scope.clone(), int_width, operator, expression))
+}
+
+/// Translates an arithmetic operation in an unchecked scope to a call to a function which wraps on overflow instead of reverting.
+/// Signed integers wrap their two's complement bits, i.e. `I256 { underlying: wrapping_add_u256(a.underlying ^ I256::indent(), b.underlying ^ I256::indent()) ^ I256::indent() }`.
+fn create_wrapping_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    operator: &str,
+    lhs: &sway::Expression,
+    rhs: &sway::Expression,
+) -> Result<Option<sway::Expression>, Error> {
+    if !matches!(operator, "+" | "-" | "*" | "**") || !scope.read().unwrap().is_unchecked() {
+        return Ok(None);
+    }
+
+    // The type of the operation comes from whichever operand isn't a literal, but the exponent doesn't count
+    let operand = if operator == "**" || get_integer_literal_value(lhs).is_none() { lhs } else { rhs };
+    let Ok(type_name) = translated_definition.get_expression_type(scope.clone(), operand) else { return Ok(None) };
+
+    // The wrapping power functions take a `u32` exponent, like `Power::pow`
+    let exponent = if operator == "**" {
+        Some(create_power_exponent_expression(translated_definition, scope.clone(), rhs.clone())?)
+    } else {
+        None
+    };
+
+    if let Some(bits) = translated_definition.get_underlying_type(&type_name).int_bits() {
+        let underlying_type_name = sway::TypeName::Identifier {
+            name: if bits == 128 { "U128".into() } else { format!("u{bits}") },
+            generic_parameters: None,
+        };
+
+        let function_name = translated_definition.ensure_wrapping_function_declared(operator, &underlying_type_name)?;
+
+        return Ok(Some(create_signed_from_bits_expression(bits, sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::Identifier(function_name),
+            generic_parameters: None,
+            parameters: vec![
+                create_signed_bits_expression(bits, lhs.clone()),
+                exponent.unwrap_or_else(|| create_signed_bits_expression(bits, rhs.clone())),
+            ],
+        }))));
+    }
+
+    if !type_name.is_uint() {
+        return Ok(None);
+    }
+
+    let function_name = translated_definition.ensure_wrapping_function_declared(operator, &type_name)?;
+
+    Ok(Some(sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier(function_name),
+        generic_parameters: None,
+        parameters: vec![lhs.clone(), exponent.unwrap_or_else(|| rhs.clone())],
+    })))
+}
+
+/// Converts the exponent of a power expression to `u32`, reverting if it doesn't fit.
+fn create_power_exponent_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    exponent: sway::Expression,
+) -> Result<sway::Expression, Error> {
+    if let Some((value, false)) = get_integer_literal_value(&exponent) {
+        if value <= BigUint::from(u32::MAX) {
+            return Ok(sway::Expression::from(sway::Literal::DecInt(value)));
+        }
+    }
+
+    let type_name = translated_definition.get_expression_type(scope, &exponent)?;
+
+    let call = |function: &str, parameter: sway::Expression| sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier(function.into()),
+        generic_parameters: None,
+        parameters: vec![parameter],
+    });
+
+    let unwrap = |expression: sway::Expression| sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::from(sway::MemberAccess {
+            expression,
+            member: "unwrap".into(),
+        }),
+        generic_parameters: None,
+        parameters: vec![],
+    });
+
+    match translated_definition.get_underlying_type(&type_name).uint_bits() {
+        Some(32) => Ok(exponent),
+
+        // u32::from(exponent)
+        Some(8 | 16) => Ok(call("u32::from", exponent)),
+
+        // u32::try_from(exponent).unwrap()
+        Some(64 | 256) => Ok(unwrap(call("u32::try_from", exponent))),
+
+        // u32::try_from(exponent.as_u64().unwrap()).unwrap()
+        Some(128) => Ok(unwrap(call("u32::try_from", unwrap(sway::Expression::from(sway::FunctionCall {
+            function: sway::Expression::from(sway::MemberAccess {
+                expression: exponent,
+                member: "as_u64".into(),
+            }),
+            generic_parameters: None,
+            parameters: vec![],
+        }))))),
+
+        _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("{type_name} exponent: {}", sway::TabbedDisplayer(&exponent)))),
+    }
+}
+
+/// Gets the Solidity bit width of an operation on signed integers, which is the width of the widest operand that isn't a literal.
+/// The shift amount and exponent don't count.
+fn get_signed_operation_width(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    operator: &str,
+    lhs: &sway::Expression,
+    rhs: Option<&sway::Expression>,
+) -> Option<usize> {
+    let mut operands = vec![lhs];
+
+    if !matches!(operator, "<<" | ">>" | "**") {
+        operands.extend(rhs);
+    }
+
+    operands.into_iter()
+        .filter(|x| get_integer_literal_value(x).is_none())
+        .filter_map(|x| {
+            let type_name = translated_definition.get_expression_type(scope.clone(), x).ok()?;
+            translated_definition.get_int_width(&type_name)
+        })
+        .max()
+}
+
+/// Keeps the result of an arithmetic expression on a signed integer width which Sway doesn't have (i.e. `int24`) within its width.
+/// Additions, subtractions, multiplications, powers and negations revert when they overflow, unless they wrap in an unchecked scope,
+/// while left shifts are truncated.
+fn create_checked_int_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    width: Option<usize>,
+    operator: &str,
+    expression: sway::Expression,
+) -> sway::Expression {
+    let Some(width) = width.filter(|width| *width < get_int_storage_bits(*width)) else { return expression };
+    let bits = get_int_storage_bits(width);
+
+    if operator == "<<" || (matches!(operator, "+" | "-" | "*" | "**") && scope.read().unwrap().is_unchecked()) {
+        let value = create_signed_bits_expression(bits, expression);
+        return create_signed_truncate_expression(translated_definition, width, value);
+    }
+
+    if !matches!(operator, "+" | "-" | "*" | "**") {
+        return expression;
+    }
+
+    // i{width}_checked(expression)
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier(translated_definition.ensure_int_check_function_declared(width)),
+        generic_parameters: None,
+        parameters: vec![expression],
+    })
+}
+
+/// Keeps the result of an arithmetic expression on an unsigned integer width which Sway doesn't have (i.e. `u24`) within its width.
+/// Additions, multiplications and powers revert when they overflow, while left shifts are truncated.
+fn create_checked_uint_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    operator: &str,
+    expression: sway::Expression,
+) -> sway::Expression {
+    if !matches!(operator, "+" | "*" | "**" | "<<") {
+        return expression;
+    }
+
+    // Get the operands of `lhs op rhs` or `lhs.pow(rhs)`
+    let (lhs, rhs) = match &expression {
+        sway::Expression::BinaryExpression(binary_expression) => (&binary_expression.lhs, Some(&binary_expression.rhs)),
+
+        sway::Expression::FunctionCall(function_call) => match &function_call.function {
+            sway::Expression::MemberAccess(member_access) => (&member_access.expression, None),
+            _ => return expression,
+        }
+
+        _ => return expression,
+    };
+
+    // The width of the operation comes from whichever operand isn't a literal, but the shift amount and exponent don't count
+    let mut operands = vec![lhs];
+    
+    if operator != "<<" {
+        operands.extend(rhs);
+    }
+
+    let Some(bits) = operands.into_iter()
+        .filter(|x| get_integer_literal_value(x).is_none())
+        .filter_map(|x| translated_definition.get_expression_type(scope.clone(), x).ok())
+        .find(|x| x.is_non_standard_uint())
+        .and_then(|x| x.uint_bits())
+    else {
+        return expression;
+    };
+
+    if operator == "<<" {
+        // (lhs << rhs) & mask
+        return sway::Expression::from(sway::BinaryExpression {
+            operator: "&".into(),
+            lhs: sway::Expression::Tuple(vec![expression]),
+            rhs: create_uint_literal_expression(translated_definition, 256, (BigUint::one() << bits) - BigUint::one()),
+        });
+    }
+
+    // u{bits}_checked(lhs op rhs)
+    sway::Expression::from(sway::FunctionCall {
+        function: sway::Expression::Identifier(translated_definition.ensure_uint_check_function_declared(bits)),
+        generic_parameters: None,
+        parameters: vec![expression],
+    })
+}
+
+/// Converts the operands of a binary expression to a common signed integer type when either of them is signed.
+/// Returns the translated expression if the operator needs to be implemented with signed integer semantics.
+fn create_signed_operands_binary_expression(
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    operator: &str,
+    lhs: &mut sway::Expression,
+    rhs: &mut sway::Expression,
+) -> Option<sway::Expression> {
+    let lhs_type_name = translated_definition.get_expression_type(scope.clone(), lhs).ok()?;
+    let lhs_type_name = translated_definition.get_underlying_type(&lhs_type_name);
+    
+    // The shift amount is always unsigned
+    if matches!(operator, "<<" | ">>") {
+        let bits = lhs_type_name.int_bits()?;
+        return create_signed_binary_expression(scope, bits, operator, lhs.clone(), rhs.clone());
+    }
+
+    let rhs_type_name = translated_definition.get_expression_type(scope.clone(), rhs).ok()?;
+    let rhs_type_name = translated_definition.get_underlying_type(&rhs_type_name);
+
+    // Integer literals take the type of the other operand
+    let lhs_bits = if get_integer_literal_value(lhs).is_some() { None } else { lhs_type_name.int_bits() };
+    let rhs_bits = if get_integer_literal_value(rhs).is_some() { None } else { rhs_type_name.int_bits() };
+
+    let bits = match (lhs_bits, rhs_bits) {
+        (Some(lhs_bits), Some(rhs_bits)) => {
+            // Implicitly convert the smaller operand to the larger type
+            let bits = lhs_bits.max(rhs_bits);
+            *lhs = create_signed_resize_expression(translated_definition, lhs_bits, bits, lhs.clone());
+            *rhs = create_signed_resize_expression(translated_definition, rhs_bits, bits, rhs.clone());
+            bits
+        }
+
+        (Some(bits), None) => {
+            *rhs = coerce_signed_expression(translated_definition, scope.clone(), &lhs_type_name, rhs.clone());
+            bits
+        }
+
+        (None, Some(bits)) => {
+            *lhs = coerce_signed_expression(translated_definition, scope.clone(), &rhs_type_name, lhs.clone());
+            bits
+        }
+
+        (None, None) => return None,
+    };
+
+    create_signed_binary_expression(scope, bits, operator, lhs.clone(), rhs.clone())
 }
 
 pub fn translate_variable_access_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
-) -> Result<(Rc<RefCell<TranslatedVariable>>, sway::Expression), Error> {
+) -> Result<(Arc<RwLock<TranslatedVariable>>, sway::Expression), Error> {
     match expression {
         solidity::Expression::Variable(solidity::Identifier { name, .. }) => {
-            let Some(variable) = scope.borrow().get_variable_from_old_name(name) else {
+            let Some(variable) = scope.read().unwrap().get_variable_from_old_name(name) else {
                 return Err(Error::Wrapped(Box::new(std::io::Error::new(
                     std::io::ErrorKind::NotFound,
                     format!(
@@ -3961,8 +5416,8 @@ pub fn translate_variable_access_expression(
                 ))));
             };
 
-            let variable_name = variable.borrow().new_name.clone();
-            let is_storage = variable.borrow().is_storage;
+            let variable_name = variable.read().unwrap().new_name.clone();
+            let is_storage = variable.read().unwrap().is_storage;
 
             Ok((
                 variable,
@@ -3980,7 +5435,7 @@ pub fn translate_variable_access_expression(
         solidity::Expression::ArraySubscript(_, expression, Some(index)) => {
             let index = translate_expression(project, translated_definition, scope.clone(), index.as_ref())?;
             let (variable, expression) = translate_variable_access_expression(project, translated_definition, scope.clone(), expression)?;
-            let is_storage = variable.borrow().is_storage;
+            let is_storage = variable.read().unwrap().is_storage;
 
             Ok((
                 variable,
@@ -4024,7 +5479,7 @@ pub fn translate_variable_access_expression(
                 }
             }
         
-            todo!("translate variable {container_type_name_string} member access expression: {expression} - {expression:#?}")
+            Err(Error::UnsupportedConstruct(expression.loc(), format!("translate variable {container_type_name_string} member access expression: {expression} - {expression}")))
         }
 
         solidity::Expression::FunctionCall(_, function, arguments) => {
@@ -4051,7 +5506,7 @@ pub fn translate_variable_access_expression(
             )
         ))),
 
-        _ => todo!("translate variable access expression: {expression} - {expression:#?}"),
+        _ => Err(Error::UnsupportedConstruct(expression.loc(), format!("translate variable access expression: {expression} - {expression}"))),
     }
 }
 
@@ -4059,27 +5514,34 @@ pub fn translate_variable_access_expression(
 pub fn translate_conditional_operator_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     condition: &solidity::Expression,
     then_value: &solidity::Expression,
     else_value: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     // if condition { then_value } else { else_value }
+    let condition = translate_expression(project, translated_definition, scope.clone(), condition)?;
+
+    let mut values = [
+        translate_expression(project, translated_definition, scope.clone(), then_value)?,
+        translate_expression(project, translated_definition, scope.clone(), else_value)?,
+    ];
+
+    coerce_signed_expressions(translated_definition, scope.clone(), &mut values);
+
+    let [then_value, else_value] = values;
+
     Ok(sway::Expression::from(sway::If {
-        condition: Some(translate_expression(project, translated_definition, scope.clone(), condition)?),
+        condition: Some(condition),
         then_body: sway::Block {
             statements: vec![],
-            final_expr: Some(
-                translate_expression(project, translated_definition, scope.clone(), then_value)?
-            ),
+            final_expr: Some(then_value),
         },
         else_if: Some(Box::new(sway::If {
             condition: None,
             then_body: sway::Block {
                 statements: vec![],
-                final_expr: Some(
-                    translate_expression(project, translated_definition, scope.clone(), else_value)?
-                ),
+                final_expr: Some(else_value),
             },
             else_if: None,
         })),
@@ -4092,11 +5554,11 @@ pub fn create_assignment_expression(
     translated_definition: &mut TranslatedDefinition,
     operator: &str,
     expression: &sway::Expression,
-    variable: Rc<RefCell<TranslatedVariable>>,
+    variable: Arc<RwLock<TranslatedVariable>>,
     rhs: &sway::Expression,
     rhs_type_name: &sway::TypeName,
 ) -> Result<sway::Expression, Error> {
-    let mut variable = variable.borrow_mut();
+    let mut variable = variable.write().unwrap();
 
     variable.mutation_count += 1;
 
@@ -4161,7 +5623,7 @@ pub fn create_assignment_expression(
         match &variable.type_name {
             sway::TypeName::Identifier { name, .. } if name == "Vec" => {
                 let sway::Expression::ArrayAccess(array_access) = expression else {
-                    panic!("Expected array access expression, found {expression:#?}");
+                    return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Expected array access expression, found {}", sway::TabbedDisplayer(expression))));
                 };
 
                 Ok(sway::Expression::from(sway::FunctionCall {
@@ -4241,19 +5703,54 @@ pub fn create_assignment_expression(
 pub fn translate_assignment_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     operator: &str,
     lhs: &solidity::Expression,
     rhs: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
+    let (variable, expression) = translate_variable_access_expression(project, translated_definition, scope.clone(), lhs)?;
+    
+    // Get the type of the assigned field or element, falling back to the type of the variable itself
+    let variable_type_name = match translated_definition.get_expression_type(scope.clone(), &expression) {
+        // Unwrap the type of storage fields and elements, i.e. `StorageKey<T>` => `T`
+        Ok(sway::TypeName::Identifier { name, generic_parameters: Some(generic_parameters) })
+            if name == "StorageKey" && generic_parameters.entries.len() == 1 => generic_parameters.entries[0].type_name.clone(),
+        
+        Ok(type_name) if type_name.is_int() || type_name.is_uint() || type_name.non_standard_int_bits().is_some() || type_name.fixed_point_decimals().is_some()
+            || translated_definition.get_function_type_dispatch_name(&type_name).is_some() => type_name,
+        _ => variable.read().unwrap().type_name.clone(),
+    };
+
+    // Signed integers don't implement these operators, the results of widths which Sway doesn't have need to be checked,
+    // arithmetic in unchecked scopes needs to wrap, and fixed point operands need to be scaled, so translate `x op= y` as `x = x op y`
+    if (variable_type_name.is_int() && matches!(operator, "<<=" | ">>=" | "%=" | "&=" | "|=" | "^="))
+        || (variable_type_name.fixed_point_decimals().is_some() && operator != "=")
+        || (variable_type_name.is_non_standard_uint() && matches!(operator, "+=" | "*=" | "<<="))
+        || (variable_type_name.non_standard_int_bits().is_some() && operator != "=")
+        || ((variable_type_name.is_int() || variable_type_name.is_uint()) && matches!(operator, "+=" | "-=" | "*=") && scope.read().unwrap().is_unchecked()) {
+        let rhs = translate_binary_expression(project, translated_definition, scope.clone(), operator.trim_end_matches('='), lhs, rhs)?;
+        let rhs_type_name = translated_definition.get_expression_type(scope.clone(), &rhs)?;
+        return create_assignment_expression(project, translated_definition, "=", &expression, variable, &rhs, &rhs_type_name);
+    }
+
     let rhs = match operator {
-        "=" => translate_pre_or_post_operator_value_expression(project, translated_definition, scope.clone(), rhs)?,
+        "=" => {
+            // Overloaded internal functions are resolved by the function type of the variable
+            if let Some(rhs) = translate_function_value_expression(translated_definition, scope.clone(), rhs, Some(&variable_type_name))? {
+                rhs
+            } else if let Some(rhs) = translate_fixed_point_literal_expression(translated_definition, &variable_type_name, rhs)? {
+                rhs
+            } else {
+                translate_pre_or_post_operator_value_expression(project, translated_definition, scope.clone(), rhs)?
+            }
+        }
+
         _ => translate_expression(project, translated_definition, scope.clone(), rhs)?,
     };
 
+    let variable_type_name = translated_definition.get_underlying_type(&variable_type_name);
+    let rhs = coerce_signed_expression(translated_definition, scope.clone(), &variable_type_name, rhs);
     let rhs_type_name = translated_definition.get_expression_type(scope.clone(), &rhs)?;
-    
-    let (variable, expression) = translate_variable_access_expression(project, translated_definition, scope.clone(), lhs)?;
 
     create_assignment_expression(project, translated_definition, operator, &expression, variable, &rhs, &rhs_type_name)
 }
@@ -4262,7 +5759,7 @@ pub fn translate_assignment_expression(
 pub fn translate_pre_or_post_operator_value_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     match expression {
@@ -4278,7 +5775,7 @@ pub fn translate_pre_or_post_operator_value_expression(
 pub fn translate_pre_operator_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     loc: &solidity::Loc,
     x: &solidity::Expression,
     operator: &str,
@@ -4294,7 +5791,7 @@ pub fn translate_pre_operator_expression(
     );
 
     let (variable, expression) = translate_variable_access_expression(project, translated_definition, scope.clone(), x)?;
-    let mut variable = variable.borrow_mut();
+    let mut variable = variable.write().unwrap();
 
     variable.read_count += 1;
 
@@ -4321,7 +5818,7 @@ pub fn translate_pre_operator_expression(
 pub fn translate_post_operator_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     loc: &solidity::Loc,
     x: &solidity::Expression,
     operator: &str,
@@ -4337,7 +5834,7 @@ pub fn translate_post_operator_expression(
     );
 
     let (variable, expression) = translate_variable_access_expression(project, translated_definition, scope.clone(), x)?;
-    let mut variable = variable.borrow_mut();
+    let mut variable = variable.write().unwrap();
 
     variable.read_count += 1;
 
@@ -4378,11 +5875,11 @@ pub fn translate_post_operator_expression(
 pub fn translate_new_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     let solidity::Expression::FunctionCall(_, mut expr, args) = expression.clone() else {
-        todo!("translate new expression: {expression:#?}")
+        return Err(Error::UnsupportedConstruct(expression.loc(), format!("translate new expression: {expression}")))
     };
 
     let block_fields = match expr.clone().as_ref() {
@@ -4390,7 +5887,7 @@ pub fn translate_new_expression(
             expr = function.clone();
 
             let solidity::Statement::Args(_, block_args) = block.as_ref() else {
-                panic!("Malformed function call block, expected args block, found: {block:#?}");
+                return Err(Error::InvalidConstruct(expression.loc(), format!("Malformed function call block, expected args block, found: {block}")));
             };
 
             let mut fields = vec![];
@@ -4409,13 +5906,7 @@ pub fn translate_new_expression(
                         value,
                     }),
 
-                    arg => println!(
-                        "{}WARNING: unsupported function call block arg: {arg}",
-                        match project.loc_to_line_and_column(&translated_definition.path, &block_arg.loc()) {
-                            Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                            None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                        }
-                    ),
+                    arg => project.report_warning(&translated_definition.path, &block_arg.loc(), format!("unsupported function call block arg: {arg}")),
                 }
             }
 
@@ -4425,8 +5916,7 @@ pub fn translate_new_expression(
         _ => None,
     };
 
-    loop {
-        let solidity::Expression::Parenthesis(_, expression) = expr.as_ref() else { break };
+    while let solidity::Expression::Parenthesis(_, expression) = expr.as_ref() {
         expr = expression.clone();
     }
 
@@ -4440,9 +5930,11 @@ pub fn translate_new_expression(
 
     match expr.as_ref() {
         solidity::Expression::Variable(solidity::Identifier {name, ..}) => {
-            if project.find_definition_with_abi(name).is_some() {
+            if project.find_definition_with_abi(&translated_definition.path, name).is_some() {
                 // new Contract(...) => /*unsupported: new Contract(...); using:*/ abi(Contract, Identity::ContractId(ContractId::from(ZERO_B256)))
 
+                project.report_unsupported(translated_definition, &expression.loc(), ReportEntryKind::Unsupported, format!("new {expression}"));
+
                 translated_definition.ensure_use_declared("std::constants::ZERO_B256");
 
                 return Ok(sway::Expression::Commented(
@@ -4484,11 +5976,11 @@ pub fn translate_new_expression(
                 // }
 
                 if block_fields.is_some() {
-                    panic!("Invalid new array expression: expected no block args, found {block_fields:#?}");
+                    return Err(Error::InvalidConstruct(expression.loc(), "Invalid new array expression: expected no block args".to_string()));
                 }
 
                 if args.len() != 1 {
-                    panic!("Invalid new array expression: expected 1 argument, found {}", args.len());
+                    return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid new array expression: expected 1 argument, found {}", args.len())));
                 }
 
                 let length = &args[0];
@@ -4576,11 +6068,11 @@ pub fn translate_new_expression(
                 // }
 
                 if block_fields.is_some() {
-                    panic!("Invalid new string expression: expected no block args, found {block_fields:#?}");
+                    return Err(Error::InvalidConstruct(expression.loc(), "Invalid new string expression: expected no block args".to_string()));
                 }
 
                 if args.len() != 1 {
-                    panic!("Invalid new string expression: expected 1 argument, found {}", args.len());
+                    return Err(Error::InvalidConstruct(expression.loc(), format!("Invalid new string expression: expected 1 argument, found {}", args.len())));
                 }
 
                 let length = &args[0];
@@ -4662,19 +6154,19 @@ pub fn translate_new_expression(
                 }));
             }
 
-            _ => todo!("translate new {} expression: {expression} {expression:#?}", type_name.to_string())
+            _ => return Err(Error::UnsupportedConstruct(expression.loc(), format!("translate new {} expression: {expression} {expression}", type_name)))
         }
 
-        _ => todo!("translate new expression: {expr:#?} - {expression:#?}")
+        _ => return Err(Error::UnsupportedConstruct(expression.loc(), format!("translate new expression: {expr} - {expression}")))
     }
 
     let name = match expr.as_ref() {
         solidity::Expression::ArraySubscript(_, ex, _) => {
-            let solidity::Expression::Variable(solidity::Identifier { name, .. }) = ex.as_ref() else { todo!("translate new expression: {expression:#?}") };
+            let solidity::Expression::Variable(solidity::Identifier { name, .. }) = ex.as_ref() else { return Err(Error::UnsupportedConstruct(expression.loc(), format!("translate new expression: {expression}"))) };
             name
         },
         solidity::Expression::Variable(solidity::Identifier { name, .. }) => name,
-        _ => todo!("translate new expression: {expression:#?}")
+        _ => return Err(Error::UnsupportedConstruct(expression.loc(), format!("translate new expression: {expression}")))
     };
     
     match block_fields {
@@ -4697,12 +6189,12 @@ pub fn translate_new_expression(
 pub fn translate_delete_expression(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Expression, Error> {
     let (variable, expr) = translate_variable_access_expression(project, translated_definition, scope.clone(), expression)?;
-    let type_name = variable.borrow().type_name.clone();
+    let type_name = variable.read().unwrap().type_name.clone();
     
-    let value = create_value_expression(translated_definition, scope.clone(), &type_name, None);
+    let value = create_value_expression(translated_definition, scope.clone(), &type_name, None)?;
     create_assignment_expression(project, translated_definition, "=", &expr, variable, &value, &type_name)
 }
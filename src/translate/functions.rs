@@ -1,13 +1,13 @@
 use super::{
-    create_value_expression, finalize_block_translation, translate_block, translate_expression,
-    translate_return_type_name, translate_statement, translate_storage_name, translate_type_name,
+    create_located_statement, create_value_expression, finalize_block_translation, translate_block, translate_expression,
+    translate_return_type_name, translate_statement, translate_statement_comments, translate_storage_name, translate_type_name,
     TranslatedDefinition, TranslatedFunction, TranslatedModifier, TranslatedVariable,
     TranslationScope,
 };
-use crate::{project::Project, sway, Error};
+use crate::{project::Project, report::ReportEntryKind, sway, Error};
 use convert_case::Case;
-use solang_parser::pt as solidity;
-use std::{cell::RefCell, rc::Rc};
+use solang_parser::{helpers::CodeLocation, pt as solidity};
+use std::sync::{Arc, RwLock};
 
 #[inline]
 pub fn translate_function_name(
@@ -71,7 +71,7 @@ pub fn translate_function_declaration(
     }
 
     // Create a scope for modifier invocation translations
-    let scope = Rc::new(RefCell::new(TranslationScope {
+    let scope = Arc::new(RwLock::new(TranslationScope {
         parent: Some(translated_definition.toplevel_scope.clone()),
         ..Default::default()
     }));
@@ -83,9 +83,9 @@ pub fn translate_function_declaration(
         
         let old_name = parameter_identifier.name.clone();
         let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let type_name = translate_type_name(project, translated_definition, &p.ty, false, true);
+        let type_name = translate_type_name(project, translated_definition, &p.ty, false, true)?;
 
-        scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+        scope.write().unwrap().variables.push(Arc::new(RwLock::new(TranslatedVariable {
             old_name,
             new_name,
             type_name,
@@ -108,7 +108,7 @@ pub fn translate_function_declaration(
             .unwrap_or_else(|| Ok(vec![]))?;
 
         // Check to see if base is a constructor call
-        if project.find_definition_with_abi(old_name.as_str()).is_some() {
+        if project.find_definition_with_abi(&translated_definition.path, old_name.as_str()).is_some() {
             let prefix = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
             let name = format!("{prefix}_constructor");
             
@@ -135,11 +135,11 @@ pub fn translate_function_declaration(
     for (_, parameter) in function_definition.params.iter() {
         let old_name = parameter.as_ref().unwrap().name.as_ref().map(|n| n.name.clone()).unwrap_or("_".into());
         let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let mut type_name = translate_type_name(project, translated_definition, &parameter.as_ref().unwrap().ty, false, true);
+        let mut type_name = translate_type_name(project, translated_definition, &parameter.as_ref().unwrap().ty, false, true)?;
 
         // Check if the parameter's type is an ABI
         if let sway::TypeName::Identifier { name, generic_parameters: None } = &type_name {
-            if project.find_definition_with_abi(name.as_str()).is_some() {
+            if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_some() {
                 type_name = sway::TypeName::Identifier {
                     name: "Identity".into(),
                     generic_parameters: None,
@@ -166,14 +166,14 @@ pub fn translate_function_declaration(
             None
         } else {
             Some(if function_definition.returns.len() == 1 {
-                let type_name = translate_type_name(project, translated_definition, &function_definition.returns[0].1.as_ref().unwrap().ty, false, true);
+                let type_name = translate_type_name(project, translated_definition, &function_definition.returns[0].1.as_ref().unwrap().ty, false, true)?;
                 translate_return_type_name(project, translated_definition, type_name)
             } else {
                 sway::TypeName::Tuple {
                     type_names: function_definition.returns.iter().map(|(_, p)| {
-                        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true);
-                        translate_return_type_name(project, translated_definition, type_name)
-                    }).collect(),
+                        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true)?;
+                        Ok(translate_return_type_name(project, translated_definition, type_name))
+                    }).collect::<Result<Vec<_>, Error>>()?,
                 }
             })
         },
@@ -200,7 +200,7 @@ pub fn translate_modifier_definition(
         post_body: None,
     };
 
-    let scope = Rc::new(RefCell::new(TranslationScope {
+    let scope = Arc::new(RwLock::new(TranslationScope {
         parent: Some(translated_definition.toplevel_scope.clone()),
         ..Default::default()
     }));
@@ -208,7 +208,7 @@ pub fn translate_modifier_definition(
     for (_, p) in function_definition.params.iter() {
         let old_name = p.as_ref().unwrap().name.as_ref().unwrap().name.clone();
         let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true);
+        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true)?;
 
         modifier.parameters.entries.push(sway::Parameter {
             name: new_name.clone(),
@@ -216,7 +216,7 @@ pub fn translate_modifier_definition(
             ..Default::default()
         });
 
-        scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+        scope.write().unwrap().variables.push(Arc::new(RwLock::new(TranslatedVariable {
             old_name,
             new_name,
             type_name,
@@ -225,11 +225,11 @@ pub fn translate_modifier_definition(
     }
 
     let solidity::Statement::Block { statements, .. } = function_definition.body.as_ref().unwrap() else {
-        panic!("Invalid modifier body, expected block, found: {:#?}", function_definition.body);
+        return Err(Error::InvalidConstruct(function_definition.loc, "Invalid modifier body, expected block".into()));
     };
 
     let mut current_body: &mut Option<sway::Block> = &mut modifier.pre_body;
-    let mut current_scope = Rc::new(RefCell::new(scope.borrow().clone()));
+    let mut current_scope = Arc::new(RwLock::new(scope.read().unwrap().clone()));
 
     let mut has_pre_storage_read = false;
     let mut has_pre_storage_write = false;
@@ -250,17 +250,17 @@ pub fn translate_modifier_definition(
                     let mut scope = Some(current_scope.clone());
 
                     while let Some(current_scope) = scope {
-                        for variable in current_scope.borrow_mut().variables.iter() {
+                        for variable in current_scope.write().unwrap().variables.iter() {
                             if *has_storage_read && *has_storage_write {
                                 break;
                             }
 
-                            if variable.borrow().is_storage {
-                                if variable.borrow().read_count != 0 {
+                            if variable.read().unwrap().is_storage {
+                                if variable.read().unwrap().read_count != 0 {
                                     *has_storage_read = true;
                                 }
     
-                                if variable.borrow().mutation_count != 0 {
+                                if variable.read().unwrap().mutation_count != 0 {
                                     *has_storage_write = true;
                                 }
                             }
@@ -270,14 +270,14 @@ pub fn translate_modifier_definition(
                             break;
                         }
 
-                        scope = current_scope.borrow().parent.clone();
+                        scope = current_scope.read().unwrap().parent.clone();
                     }
 
                     finalize_block_translation(project, current_scope.clone(), block)?;
                 }
 
                 current_body = &mut modifier.post_body;
-                current_scope = Rc::new(RefCell::new(scope.borrow().clone()));
+                current_scope = Arc::new(RwLock::new(scope.read().unwrap().clone()));
 
                 has_storage_read = &mut has_post_storage_read;
                 has_storage_write = &mut has_post_storage_write;
@@ -296,18 +296,21 @@ pub fn translate_modifier_definition(
         // Translate the statement
         let sway_statement = translate_statement(project, translated_definition, current_scope.clone(), statement)?;
 
+        // Keep any comments preceding the statement
+        block.statements.extend(translate_statement_comments(project, translated_definition, statement));
+
         // Store the index of the sway statement
         let statement_index = block.statements.len();
 
         // Add the sway statement to the sway block
-        block.statements.push(sway_statement);
+        block.statements.push(create_located_statement(project, translated_definition, statement, sway_statement));
 
         // If the sway statement is a variable declaration, keep track of its statement index
-        if let Some(sway::Statement::Let(sway_variable)) = block.statements.last() {
+        if let Some(sway::Statement::Let(sway_variable)) = block.statements.last().map(sway::Statement::unlocated) {
             let store_variable_statement_index = |id: &sway::LetIdentifier| {
-                let scope = scope.borrow_mut();
-                let scope_entry = scope.variables.iter().rev().find(|v| v.borrow().new_name == id.name).unwrap();
-                scope_entry.borrow_mut().statement_index = Some(statement_index);
+                let scope = scope.write().unwrap();
+                let scope_entry = scope.variables.iter().rev().find(|v| v.read().unwrap().new_name == id.name).unwrap();
+                scope_entry.write().unwrap().statement_index = Some(statement_index);
             };
 
             match &sway_variable.pattern {
@@ -317,13 +320,13 @@ pub fn translate_modifier_definition(
         }
     }
 
-    for variable in current_scope.borrow().variables.iter() {
-        if variable.borrow().is_storage {
-            if variable.borrow().read_count != 0 {
+    for variable in current_scope.read().unwrap().variables.iter() {
+        if variable.read().unwrap().is_storage {
+            if variable.read().unwrap().read_count != 0 {
                 *has_storage_read = true;
             }
 
-            if variable.borrow().mutation_count != 0 {
+            if variable.read().unwrap().mutation_count != 0 {
                 *has_storage_write = true;
             }
         }
@@ -360,15 +363,19 @@ pub fn translate_modifier_definition(
 
     // Ensure that an underscore statement was encountered while translating the modifier
     if !modifier.has_underscore {
-        panic!("Malformed modifier missing underscore statement: {}", modifier.old_name);
+        return Err(Error::InvalidConstruct(function_definition.loc, format!("Malformed modifier missing underscore statement: {}", modifier.old_name)));
     }
 
+    let doc_comment = project.get_doc_comment(&translated_definition.path, &function_definition.loc);
+    let location = project.loc_to_source_location(&translated_definition.path, &get_function_definition_loc(function_definition));
+
     // Generate toplevel modifier functions
     match (modifier.pre_body.as_ref(), modifier.post_body.as_ref()) {
         (Some(pre_body), Some(post_body)) => {
             let modifier_pre_function_name = format!("{}_pre", modifier.new_name);
 
             translated_definition.functions.push(sway::Function {
+                doc_comment: doc_comment.clone(),
                 attributes: create_attributes(has_pre_storage_read, has_pre_storage_write),
                 is_public: false,
                 name: modifier_pre_function_name.clone(),
@@ -376,6 +383,7 @@ pub fn translate_modifier_definition(
                 parameters: modifier.parameters.clone(),
                 return_type: None,
                 body: Some(pre_body.clone()),
+                location: location.clone(),
             });
 
             *translated_definition.function_call_counts.entry(modifier_pre_function_name.clone()).or_insert(0) += 1;
@@ -383,6 +391,7 @@ pub fn translate_modifier_definition(
             let modifier_post_function_name = format!("{}_post", modifier.new_name);
 
             translated_definition.functions.push(sway::Function {
+                doc_comment: doc_comment.clone(),
                 attributes: create_attributes(has_post_storage_read, has_post_storage_write),
                 is_public: false,
                 name: modifier_post_function_name.clone(),
@@ -390,6 +399,7 @@ pub fn translate_modifier_definition(
                 parameters: modifier.parameters.clone(),
                 return_type: None,
                 body: Some(post_body.clone()),
+                location: location.clone(),
             });
 
             *translated_definition.function_call_counts.entry(modifier_post_function_name.clone()).or_insert(0) += 1;
@@ -397,6 +407,7 @@ pub fn translate_modifier_definition(
 
         (Some(pre_body), None) => {
             translated_definition.functions.push(sway::Function {
+                doc_comment: doc_comment.clone(),
                 attributes: create_attributes(has_pre_storage_read, has_pre_storage_write),
                 is_public: false,
                 name: modifier.new_name.clone(),
@@ -404,6 +415,7 @@ pub fn translate_modifier_definition(
                 parameters: modifier.parameters.clone(),
                 return_type: None,
                 body: Some(pre_body.clone()),
+                location: location.clone(),
             });
 
             *translated_definition.function_call_counts.entry(modifier.new_name.clone()).or_insert(0) += 1;
@@ -411,6 +423,7 @@ pub fn translate_modifier_definition(
 
         (None, Some(post_body)) => {
             translated_definition.functions.push(sway::Function {
+                doc_comment: doc_comment.clone(),
                 attributes: create_attributes(has_post_storage_read, has_post_storage_write),
                 is_public: false,
                 name: modifier.new_name.clone(),
@@ -418,13 +431,14 @@ pub fn translate_modifier_definition(
                 parameters: modifier.parameters.clone(),
                 return_type: None,
                 body: Some(post_body.clone()),
+                location: location.clone(),
             });
 
             *translated_definition.function_call_counts.entry(modifier.new_name.clone()).or_insert(0) += 1;
         }
 
         (None, None) => {
-            panic!("Malformed modifier missing pre and post bodies");
+            return Err(Error::InvalidConstruct(function_definition.loc, "Malformed modifier missing pre and post bodies".into()));
         }
     }
     
@@ -491,11 +505,11 @@ pub fn translate_function_definition(
     for (_, parameter) in function_definition.params.iter() {
         let old_name = parameter.as_ref().unwrap().name.as_ref().map(|n| n.name.clone()).unwrap_or("_".into());
         let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let mut type_name = translate_type_name(project, translated_definition, &parameter.as_ref().unwrap().ty, false, true);
+        let mut type_name = translate_type_name(project, translated_definition, &parameter.as_ref().unwrap().ty, false, true)?;
 
         // Check if the parameter's type is an ABI and make it an Identity
         if let sway::TypeName::Identifier { name, generic_parameters: None } = &type_name {
-            if project.find_definition_with_abi(name.as_str()).is_some() {
+            if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_some() {
                 type_name = sway::TypeName::Identifier {
                     name: "Identity".into(),
                     generic_parameters: None,
@@ -513,6 +527,7 @@ pub fn translate_function_definition(
 
     // Create the function declaration
     let mut sway_function = sway::Function {
+        doc_comment: project.get_doc_comment(&translated_definition.path, &function_definition.loc),
         attributes: if is_constant || is_pure {
             None
         } else {
@@ -549,27 +564,31 @@ pub fn translate_function_definition(
             None
         } else {
             Some(if function_definition.returns.len() == 1 {
-                let type_name = translate_type_name(project, translated_definition, &function_definition.returns[0].1.as_ref().unwrap().ty, false, true);
+                let type_name = translate_type_name(project, translated_definition, &function_definition.returns[0].1.as_ref().unwrap().ty, false, true)?;
                 translate_return_type_name(project, translated_definition, type_name)
             } else {
                 sway::TypeName::Tuple {
                     type_names: function_definition.returns.iter().map(|(_, p)| {
-                        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true);
-                        translate_return_type_name(project, translated_definition, type_name)
-                    }).collect(),
+                        let type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true)?;
+                        Ok(translate_return_type_name(project, translated_definition, type_name))
+                    }).collect::<Result<Vec<_>, Error>>()?,
                 }
             })
         },
 
         body: None,
+
+        location: project.loc_to_source_location(&translated_definition.path, &get_function_definition_loc(function_definition)),
     };
 
     if is_public {
         sway_function.name = new_name_2.clone();
 
         if let Some(abi) = translated_definition.abi.as_mut() {
-            // Only add the function to the abi if it doesn't already exist
-            if !abi.functions.contains(&sway_function) && !is_override {
+            // Only add the function to the abi if it doesn't already exist, otherwise keep the documentation of the override
+            if let Some(f) = abi.functions.iter_mut().find(|f| f.has_same_signature(&sway_function)) {
+                f.merge_doc_comment(&sway_function);
+            } else if !is_override {
                 if is_constructor {
                     abi.functions.insert(0, sway_function.clone());
                 } else {
@@ -591,9 +610,82 @@ pub fn translate_function_definition(
     // Convert the statements in the function's body (if any)
     let Some(solidity::Statement::Block { statements, .. }) = function_definition.body.as_ref() else { return Ok(()) };
 
+    // Translate the function's body, falling back to a placeholder body if it fails so the rest of the contract is still translated
+    let function_body = match translate_function_body(project, translated_definition, function_definition, statements.as_slice(), is_constructor, new_name.as_str()) {
+        Ok(function_body) => function_body,
+
+        Err(error) => {
+            let error = error.with_loc(&function_definition.loc);
+            project.report_error(&translated_definition.path, &error);
+            create_placeholder_function_body(project, translated_definition, function_definition, &error)
+        }
+    };
+
+    // Create the body for the toplevel function
+    sway_function.body = Some(function_body);
+
+    // Add the toplevel function
+    translated_definition.functions.push(sway_function.clone());
+
+    if is_public {
+        // Create the body for the contract impl's function wrapper
+        sway_function.body = Some(sway::Block {
+            statements: vec![],
+            final_expr: Some(sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier(format!("::{}", sway_function.name)),
+                generic_parameters: None,
+                parameters: sway_function.parameters.entries.iter().map(|p| sway::Expression::Identifier(p.name.clone())).collect(),
+            })),
+        });
+        
+        sway_function.name = new_name_2;
+        
+        // Create the function wrapper item for the contract impl block
+        let impl_item = sway::ImplItem::Function(sway_function.clone());
+        
+        if let Some(contract_impl) = translated_definition.find_contract_impl_mut() {
+            if let Some(sway::ImplItem::Function(f)) = contract_impl.items.iter_mut().find(|item| {
+                let sway::ImplItem::Function(f) = item else { return false };
+                f.has_same_signature(&sway_function)
+            }) {
+                f.body = sway_function.body;
+            }
+            // Only add the function wrapper to the contract impl if it doesn't already exist
+            else if !contract_impl.items.iter().any(|i| i.has_same_signature(&impl_item)) {
+                if is_constructor {
+                    contract_impl.items.insert(0, impl_item);
+                } else {
+                    contract_impl.items.push(impl_item);
+                }
+            }
+        } else {
+            // Add the function wrapper to the contract impl
+            if is_constructor {
+                translated_definition.get_contract_impl().items.insert(0, impl_item);
+            } else {
+                translated_definition.get_contract_impl().items.push(impl_item);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn translate_function_body(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    function_definition: &solidity::FunctionDefinition,
+    statements: &[solidity::Statement],
+    is_constructor: bool,
+    new_name: &str,
+) -> Result<sway::Block, Error> {
     // Create the scope for the body of the toplevel function
-    let scope = Rc::new(RefCell::new(TranslationScope {
+    let scope = Arc::new(RwLock::new(TranslationScope {
         parent: Some(translated_definition.toplevel_scope.clone()),
+        return_type: translated_definition.toplevel_scope.read().unwrap()
+            .find_function(|f| f.read().unwrap().new_name == new_name)
+            .and_then(|f| f.read().unwrap().return_type.clone()),
         ..Default::default()
     }));
 
@@ -603,12 +695,12 @@ pub fn translate_function_definition(
     for (_, p) in function_definition.params.iter() {
         let old_name = p.as_ref().unwrap().name.as_ref().map(|n| n.name.clone()).unwrap_or("_".into());
         let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let mut type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true);
+        let mut type_name = translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, true)?;
         let mut abi_type_name = None;
 
         // Check if the parameter's type is an ABI
         if let sway::TypeName::Identifier { name, generic_parameters: None } = &type_name {
-            if project.find_definition_with_abi(name.as_str()).is_some() {
+            if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_some() {
                 abi_type_name = Some(type_name.clone());
 
                 type_name = sway::TypeName::Identifier {
@@ -627,7 +719,7 @@ pub fn translate_function_definition(
         };
 
         parameters.push(translated_variable.clone());
-        scope.borrow_mut().variables.push(Rc::new(RefCell::new(translated_variable)));
+        scope.write().unwrap().variables.push(Arc::new(RwLock::new(translated_variable)));
     }
 
     // Add the function's named return parameters to the scope
@@ -637,12 +729,12 @@ pub fn translate_function_definition(
         let Some(return_parameter) = return_parameter else { continue };
         let Some(old_name) = return_parameter.name.as_ref().map(|n| n.name.clone()) else { continue };
         let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-        let mut type_name = translate_type_name(project, translated_definition, &return_parameter.ty, false, true);
+        let mut type_name = translate_type_name(project, translated_definition, &return_parameter.ty, false, true)?;
         let mut abi_type_name = None;
 
         // Check if the parameter's type is an ABI
         if let sway::TypeName::Identifier { name, generic_parameters: None } = &type_name {
-            if project.find_definition_with_abi(name.as_str()).is_some() {
+            if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_some() {
                 abi_type_name = Some(type_name.clone());
 
                 type_name = sway::TypeName::Identifier {
@@ -661,11 +753,11 @@ pub fn translate_function_definition(
         };
 
         return_parameters.push(translated_variable.clone());
-        scope.borrow_mut().variables.push(Rc::new(RefCell::new(translated_variable)));
+        scope.write().unwrap().variables.push(Arc::new(RwLock::new(translated_variable)));
     }
 
     // Translate the body for the toplevel function
-    let mut function_body = translate_block(project, translated_definition, scope.clone(), statements.as_slice())?;
+    let mut function_body = translate_block(project, translated_definition, scope.clone(), statements)?;
 
     if is_constructor {
         let prefix = crate::translate_naming_convention(translated_definition.name.as_str(), Case::Snake);
@@ -673,6 +765,7 @@ pub fn translate_function_definition(
         
         // Add the `constructor_called` field to the storage block
         translated_definition.get_storage().fields.push(sway::StorageField {
+            doc_comment: None,
             name: constructor_called_variable_name.clone(),
             type_name: sway::TypeName::Identifier {
                 name: "bool".into(),
@@ -724,11 +817,11 @@ pub fn translate_function_definition(
 
     // Check for parameters that were mutated and make them local variables
     for parameter in parameters.iter().rev() {
-        let Some(variable) = scope.borrow().get_variable_from_new_name(&parameter.new_name) else {
-            panic!("error: Variable not found in scope: \"{}\"", parameter.new_name);
+        let Some(variable) = scope.read().unwrap().get_variable_from_new_name(&parameter.new_name) else {
+            return Err(Error::UnresolvedReference(function_definition.loc, format!("Variable not found in scope: \"{}\"", parameter.new_name)));
         };
 
-        if variable.borrow().mutation_count > 0 {
+        if variable.read().unwrap().mutation_count > 0 {
             function_body.statements.insert(0, sway::Statement::Let(sway::Let {
                 pattern: sway::LetPattern::Identifier(sway::LetIdentifier {
                     is_mutable: true,
@@ -742,7 +835,7 @@ pub fn translate_function_definition(
 
     // Propagate the return variable declarations
     for return_parameter in return_parameters.iter().rev() {
-        let scope = Rc::new(RefCell::new(TranslationScope {
+        let scope = Arc::new(RwLock::new(TranslationScope {
             parent: Some(translated_definition.toplevel_scope.clone()),
             ..Default::default()
         }));
@@ -753,12 +846,12 @@ pub fn translate_function_definition(
                 name: return_parameter.new_name.clone(),
             }),
             type_name: Some(return_parameter.type_name.clone()),
-            value: create_value_expression(translated_definition, scope.clone(), &return_parameter.type_name, None),
+            value: create_value_expression(translated_definition, scope.clone(), &return_parameter.type_name, None)?,
         }));
     }
 
     // If the function returns values but doesn't end in a return statement, propagate the return variables
-    if !return_parameters.is_empty() && !matches!(function_body.statements.last(), Some(sway::Statement::Expression(sway::Expression::Return(_)))) {
+    if !return_parameters.is_empty() && !matches!(function_body.statements.last().map(sway::Statement::unlocated), Some(sway::Statement::Expression(sway::Expression::Return(_)))) {
         function_body.statements.push(sway::Statement::from(sway::Expression::Return(Some(Box::new(
             if return_parameters.len() == 1 {
                 sway::Expression::Identifier(
@@ -773,28 +866,25 @@ pub fn translate_function_definition(
     }
 
     // Check if the final statement returns a value and change it to be the final expression of the block
-    if let Some(sway::Statement::Expression(sway::Expression::Return(Some(value)))) = function_body.statements.last().cloned() {
-        function_body.statements.pop();
-        function_body.final_expr = Some(*value);
+    if let Some(sway::Statement::Expression(sway::Expression::Return(Some(value)))) = function_body.statements.last().map(sway::Statement::unlocated).cloned() {
+        // Keep the location of the return statement for the final expression
+        let final_expr = match function_body.statements.pop() {
+            Some(sway::Statement::Located(location, _)) => sway::Expression::Located(location, value),
+            _ => *value,
+        };
+
+        function_body.final_expr = Some(final_expr);
     }
 
     // Get the function from the scope
-    let function = match translated_definition.toplevel_scope.borrow().find_function(|f| f.borrow().new_name == new_name) {
+    let function = match translated_definition.toplevel_scope.read().unwrap().find_function(|f| f.read().unwrap().new_name == new_name) {
         Some(function) => function,
         None => {
-            panic!(
-                "{}error: Failed to find function `{}.{new_name}` in scope - {:#?}",
-                match project.loc_to_line_and_column(&translated_definition.path, &function_definition.loc) {
-                    Some((line, col)) => format!("{}:{}:{} - ", translated_definition.path.to_string_lossy(), line, col),
-                    None => format!("{} - ", translated_definition.path.to_string_lossy()),
-                },
-                translated_definition.name,
-                project.translated_definitions,
-            )
+            return Err(Error::UnresolvedReference(function_definition.loc, format!("Failed to find function `{}.{new_name}` in scope", translated_definition.name)));
         }
     };
 
-    let function = function.borrow();
+    let function = function.read().unwrap();
 
     // Propagate constructor calls into the function's body
     for constructor_call in function.constructor_calls.iter().rev() {
@@ -807,11 +897,11 @@ pub fn translate_function_definition(
 
     for modifier_invocation in function.modifiers.iter() {
         let sway::Expression::Identifier(new_name) = &modifier_invocation.function else {
-            panic!("Malformed modifier invocation: {modifier_invocation:#?}");
+            return Err(Error::InvalidConstruct(function_definition.loc, format!("Malformed modifier invocation: {}", sway::TabbedDisplayer(modifier_invocation))));
         };
         
         let Some(modifier) = translated_definition.modifiers.iter().find(|v| v.new_name == *new_name) else {
-            panic!("Failed to find modifier: {new_name}");
+            return Err(Error::UnresolvedReference(function_definition.loc, format!("Failed to find modifier: {new_name}")));
         };
 
         if modifier.pre_body.is_some() && modifier.post_body.is_some() {
@@ -849,58 +939,55 @@ pub fn translate_function_definition(
         function_body.statements.push(sway::Statement::from(sway::Expression::from(modifier_post_call.clone())));
     }
 
-    // Create the body for the toplevel function
-    sway_function.body = Some(function_body);
-
-    // Add the toplevel function
-    translated_definition.functions.push(sway_function.clone());
+    Ok(function_body)
+}
 
-    if is_public {
-        // Create the body for the contract impl's function wrapper
-        sway_function.body = Some(sway::Block {
-            statements: vec![],
-            final_expr: Some(sway::Expression::from(sway::FunctionCall {
-                function: sway::Expression::Identifier(format!("::{}", sway_function.name)),
-                generic_parameters: None,
-                parameters: sway_function.parameters.entries.iter().map(|p| sway::Expression::Identifier(p.name.clone())).collect(),
-            })),
-        });
-        
-        sway_function.name = new_name_2;
-        
-        // Create the function wrapper item for the contract impl block
-        let impl_item = sway::ImplItem::Function(sway_function.clone());
-        
-        if let Some(contract_impl) = translated_definition.find_contract_impl_mut() {
-            if let Some(sway::ImplItem::Function(f)) = contract_impl.items.iter_mut().find(|item| {
-                let sway::ImplItem::Function(f) = item else { return false };
-                if f.name != sway_function.name {
-                    return false;
-                }
-                if !sway_function.parameters.entries.iter().zip(f.parameters.entries.iter()).all(|(a, b)| a == b) {
-                    return false;
-                }
-                sway_function.return_type == f.return_type
-            }) {
-                f.body = sway_function.body;
-            }
-            // Only add the function wrapper to the contract impl if it doesn't already exist
-            else if !contract_impl.items.contains(&impl_item) {
-                if is_constructor {
-                    contract_impl.items.insert(0, impl_item);
+/// Creates a `todo!()` function body which keeps the original Solidity code and the reason it could not be translated, and records it in the project's report.
+#[inline]
+fn create_placeholder_function_body(
+    project: &mut Project,
+    translated_definition: &TranslatedDefinition,
+    function_definition: &solidity::FunctionDefinition,
+    error: &Error,
+) -> sway::Block {
+    let loc = get_function_definition_loc(function_definition);
+
+    let source_text = match project.loc_to_source_text(&translated_definition.path, &loc) {
+        Some(source_text) => {
+            // Remove the indentation of the function definition from each line after the first
+            let indent = project.loc_to_line_and_column(&translated_definition.path, &loc).map(|(_, column)| column - 1).unwrap_or(0);
+
+            source_text.lines().enumerate().map(|(i, line)| {
+                if i == 0 {
+                    line
                 } else {
-                    contract_impl.items.push(impl_item);
+                    let whitespace = line.len() - line.trim_start().len();
+                    &line[whitespace.min(indent)..]
                 }
-            }
-        } else {
-            // Add the function wrapper to the contract impl
-            if is_constructor {
-                translated_definition.get_contract_impl().items.insert(0, impl_item);
-            } else {
-                translated_definition.get_contract_impl().items.push(impl_item);
-            }
+            }).collect::<Vec<_>>().join("\n")
         }
+
+        None => function_definition.to_string(),
+    };
+
+    project.report_unsupported(translated_definition, error.loc().unwrap_or(&function_definition.loc), ReportEntryKind::Todo, error.message());
+
+    sway::Block {
+        statements: vec![
+            sway::Statement::Commented(
+                format!("Failed to translate function: {}\n{source_text}", error.message()).replace("*/", "* /"),
+                None,
+            ),
+        ],
+        final_expr: Some(sway::Expression::create_todo(None)),
     }
+}
 
-    Ok(())
+/// Gets the location of the entire function definition, since its location only covers its signature.
+#[inline]
+fn get_function_definition_loc(function_definition: &solidity::FunctionDefinition) -> solidity::Loc {
+    match (function_definition.loc, function_definition.body.as_ref().map(|body| body.loc())) {
+        (solidity::Loc::File(file_no, start, _), Some(solidity::Loc::File(_, _, end))) => solidity::Loc::File(file_no, start, end),
+        (loc, _) => loc,
+    }
 }
@@ -1,6 +1,6 @@
 use super::TranslatedDefinition;
 use crate::{errors::Error, project::Project};
-use solang_parser::pt as solidity;
+use solang_parser::{helpers::CodeLocation, pt as solidity};
 use std::path::{Path, PathBuf};
 
 pub fn resolve_import(
@@ -9,25 +9,26 @@ pub fn resolve_import(
     source_unit_path: &Path,
 ) -> Result<Option<TranslatedDefinition>, Error> {
     let mut source_unit_path = PathBuf::from(source_unit_path);
-    let source_unit_directory = source_unit_path.parent().map(PathBuf::from).unwrap();
-    
-    if !source_unit_path.to_string_lossy().starts_with('.') {
-        source_unit_path = project.get_project_type_path(&source_unit_directory, source_unit_path.to_string_lossy().to_string().as_str())?;
-    } else {
-        source_unit_path = source_unit_directory.join(source_unit_path);
-    }
-    
-    source_unit_path = crate::get_canonical_path(source_unit_path, false, false)
-        .map_err(|e| Error::Wrapped(Box::new(e))).unwrap();
-    
-    if !source_unit_path.exists() {
-        return Err(Error::Wrapped(Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, source_unit_path.to_string_lossy()))));
-    }
 
-    let source_unit_path = crate::get_canonical_path(source_unit_path, false, false)
-        .map_err(|e| Error::Wrapped(Box::new(e)))?;
+    // Source units loaded from memory may not exist on disk, so only resolve paths we haven't loaded yet
+    if !project.solidity_source_units.read().unwrap().contains_key(&source_unit_path) {
+        let source_unit_directory = source_unit_path.parent().map(PathBuf::from).unwrap();
+
+        if !source_unit_path.to_string_lossy().starts_with('.') {
+            source_unit_path = project.get_project_type_path(&source_unit_directory, source_unit_path.to_string_lossy().to_string().as_str())?;
+        } else {
+            source_unit_path = source_unit_directory.join(source_unit_path);
+        }
+
+        if !source_unit_path.exists() {
+            return Err(Error::Wrapped(Box::new(std::io::Error::new(std::io::ErrorKind::NotFound, source_unit_path.to_string_lossy()))));
+        }
 
-    if let Some(t) = project.translated_definitions.iter().find(|t| t.name == *definition_name && t.path == source_unit_path).cloned() {
+        source_unit_path = crate::get_canonical_path(source_unit_path, false, false)
+            .map_err(|e| Error::Wrapped(Box::new(e)))?;
+    }
+
+    if let Some(t) = project.get_translated_definition(&source_unit_path, definition_name).cloned() {
         return Ok(Some(t));
     }
     
@@ -51,7 +52,7 @@ pub fn resolve_import(
 
     project.translate(Some(definition_name), &source_unit_path)?;
 
-    if let Some(t) = project.translated_definitions.iter().find(|t| t.name == *definition_name && t.path == source_unit_path).cloned() {
+    if let Some(t) = project.get_translated_definition(&source_unit_path, definition_name).cloned() {
         return Ok(Some(t));
     }
     
@@ -67,55 +68,113 @@ pub fn translate_import_directives(
     let source_unit_directory = translated_definition.path.parent().map(PathBuf::from).unwrap();
 
     for import_directive in import_directives.iter() {
-        let mut translate_import_directive = |definition_name: Option<&String>, filename: &solidity::StringLiteral| -> Result<(), Error> {
-            let mut import_path = PathBuf::from(filename.string.clone());
+        let (import_path, definition_names) = match import_directive {
+            solidity::Import::Plain(import_path, _) | solidity::Import::GlobalSymbol(import_path, _, _) => (import_path, None),
+            solidity::Import::Rename(import_path, identifiers, _) => (import_path, Some(identifiers.iter().map(|(i, _)| &i.name).collect::<Vec<_>>())),
+        };
 
-            if !import_path.to_string_lossy().starts_with('.') {
-                import_path = project.get_project_type_path(source_unit_directory.as_path(), filename.string.as_str())?;
-            } else {
-                import_path = source_unit_directory.join(import_path);
-            }
-            
-            import_path = crate::get_canonical_path(import_path, false, false)
-                .map_err(|e| Error::Wrapped(Box::new(e))).unwrap();
-            
-            if !import_path.exists() {
-                return Err(Error::Wrapped(Box::new(
-                    std::io::Error::new(
-                        std::io::ErrorKind::NotFound,
-                        format!("File not found: {}", import_path.to_string_lossy()),
-                    )
-                )));
-            }
+        let import_path = project.resolve_import_path(source_unit_directory.as_path(), import_path)?;
 
-            let import_path = crate::get_canonical_path(import_path, false, false)
-                .map_err(|e| Error::Wrapped(Box::new(e)))?;
+        match definition_names {
+            Some(definition_names) => {
+                for definition_name in definition_names {
+                    if project.get_translated_definition(&import_path, definition_name).is_none() {
+                        resolve_import(project, definition_name, &import_path)?;
+                    }
+                }
+            }
 
-            if !project.translated_definitions.iter().any(|t| definition_name.map(|n| *n == t.name).unwrap_or(true) && t.path == import_path) {
-                if let Some(definition_name) = definition_name {
-                    resolve_import(project, definition_name, &import_path)?;
-                } else {
-                    project.translate(definition_name, &import_path)?;
+            None => {
+                if project.symbol_index.get_definitions(&import_path).is_empty() {
+                    project.translate(None, &import_path)?;
                 }
             }
+        }
+    }
 
-            Ok(())
-        };
+    Ok(())
+}
+
+/// Resolves the import aliases at the start of the `expression`, or returns `None` if it doesn't start with an import alias.
+///
+/// For example, `X.Lib.f(a)` becomes `Lib.f(a)` when `X` is an alias of an imported file.
+pub fn resolve_expression_import_aliases(
+    project: &Project,
+    translated_definition: &TranslatedDefinition,
+    expression: &solidity::Expression,
+) -> Option<solidity::Expression> {
+    if !project.import_aliases.contains_key(&translated_definition.path) {
+        return None;
+    }
 
-        match import_directive {
-            solidity::Import::Plain(solidity::ImportPath::Filename(filename), _) => {
-                translate_import_directive(None, filename)?;
+    // Collect the identifiers of a path expression (i.e: `X.Lib.f`)
+    fn collect_identifiers(expression: &solidity::Expression, identifiers: &mut Vec<solidity::Identifier>) -> bool {
+        match expression {
+            solidity::Expression::Variable(identifier) => {
+                identifiers.push(identifier.clone());
+                true
             }
 
-            solidity::Import::Rename(solidity::ImportPath::Filename(filename), identifiers, _) => {
-                for (identifier, _) in identifiers.iter() {
-                    translate_import_directive(Some(&identifier.name), filename)?;
+            solidity::Expression::MemberAccess(_, container, member) => {
+                if !collect_identifiers(container, identifiers) {
+                    return false;
                 }
+
+                identifiers.push(member.clone());
+                true
             }
 
-            _ => panic!("Unsupported import directive: {import_directive:#?}"),
+            _ => false,
         }
     }
 
-    Ok(())
+    match expression {
+        solidity::Expression::Variable(_) | solidity::Expression::MemberAccess(_, _, _) => {
+            let mut identifiers = vec![];
+
+            if !collect_identifiers(expression, &mut identifiers) {
+                let solidity::Expression::MemberAccess(loc, container, member) = expression else { return None };
+                let container = resolve_expression_import_aliases(project, translated_definition, container)?;
+                return Some(solidity::Expression::MemberAccess(*loc, Box::new(container), member.clone()));
+            }
+
+            let identifiers = project.resolve_import_aliases(&translated_definition.path, identifiers.as_slice())?;
+            let mut identifiers = identifiers.into_iter();
+
+            let mut result = solidity::Expression::Variable(identifiers.next()?);
+
+            for identifier in identifiers {
+                result = solidity::Expression::MemberAccess(expression.loc(), Box::new(result), identifier);
+            }
+
+            Some(result)
+        }
+
+        solidity::Expression::FunctionCall(loc, function, arguments) => {
+            let function = resolve_expression_import_aliases(project, translated_definition, function)?;
+            Some(solidity::Expression::FunctionCall(*loc, Box::new(function), arguments.clone()))
+        }
+
+        solidity::Expression::NamedFunctionCall(loc, function, arguments) => {
+            let function = resolve_expression_import_aliases(project, translated_definition, function)?;
+            Some(solidity::Expression::NamedFunctionCall(*loc, Box::new(function), arguments.clone()))
+        }
+
+        solidity::Expression::FunctionCallBlock(loc, function, block) => {
+            let function = resolve_expression_import_aliases(project, translated_definition, function)?;
+            Some(solidity::Expression::FunctionCallBlock(*loc, Box::new(function), block.clone()))
+        }
+
+        solidity::Expression::New(loc, expression) => {
+            let expression = resolve_expression_import_aliases(project, translated_definition, expression)?;
+            Some(solidity::Expression::New(*loc, Box::new(expression)))
+        }
+
+        solidity::Expression::ArraySubscript(loc, expression, index) => {
+            let expression = resolve_expression_import_aliases(project, translated_definition, expression)?;
+            Some(solidity::Expression::ArraySubscript(*loc, Box::new(expression), index.clone()))
+        }
+
+        _ => None,
+    }
 }
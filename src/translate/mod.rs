@@ -13,13 +13,15 @@ mod type_names;
 pub use self::{assembly::*, contracts::*, enums::*, expressions::*, functions::*, import_directives::*, statements::*, storage::*, structs::*, type_definitions::*, type_names::*};
 
 use crate::{errors::Error, sway};
+use convert_case::Case;
+use num_bigint::BigUint;
+use num_traits::One;
 use solang_parser::pt as solidity;
 use std::{
-    cell::RefCell,
     collections::HashMap,
     fmt::Display,
     path::{Path, PathBuf},
-    rc::Rc
+    sync::{Arc, RwLock},
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -69,17 +71,21 @@ pub struct TranslatedModifier {
     pub post_body: Option<sway::Block>,
 }
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
 pub struct TranslationScope {
-    pub parent: Option<Rc<RefCell<TranslationScope>>>,
-    pub variables: Vec<Rc<RefCell<TranslatedVariable>>>,
-    pub functions: Vec<Rc<RefCell<TranslatedFunction>>>,
+    pub parent: Option<Arc<RwLock<TranslationScope>>>,
+    pub variables: Vec<Arc<RwLock<TranslatedVariable>>>,
+    pub functions: Vec<Arc<RwLock<TranslatedFunction>>>,
+    /// Whether arithmetic in the scope wraps on overflow, i.e. in an `unchecked` block or a source unit older than Solidity 0.8.
+    pub unchecked: bool,
+    /// The return type of the function whose body the scope belongs to, if it returns a value.
+    pub return_type: Option<sway::TypeName>,
 }
 
 impl TranslationScope {
     pub fn dump(&self, variables: bool, functions: bool) {
         if let Some(parent) = self.parent.as_ref() {
-            parent.borrow().dump(variables, functions);
+            parent.read().unwrap().dump(variables, functions);
         }
         
         if variables {
@@ -99,6 +105,16 @@ impl TranslationScope {
         }
     }
     
+    /// Checks if arithmetic in the scope or any of its parents wraps on overflow
+    pub fn is_unchecked(&self) -> bool {
+        self.unchecked || self.parent.as_ref().map(|p| p.read().unwrap().is_unchecked()).unwrap_or(false)
+    }
+
+    /// Gets the return type of the function whose body the scope or any of its parents belongs to
+    pub fn get_return_type(&self) -> Option<sway::TypeName> {
+        self.return_type.clone().or_else(|| self.parent.as_ref().and_then(|p| p.read().unwrap().get_return_type()))
+    }
+
     #[inline]
     pub fn generate_unique_variable_name(&self, name: &str) -> String {
         let mut result = name.to_string();
@@ -111,13 +127,13 @@ impl TranslationScope {
     }
 
     /// Attempts to get a reference to a translated variable using its old name
-    pub fn get_variable_from_old_name(&self, old_name: &str) -> Option<Rc<RefCell<TranslatedVariable>>> {
-        if let Some(variable) = self.variables.iter().rev().find(|v| v.borrow().old_name == old_name) {
+    pub fn get_variable_from_old_name(&self, old_name: &str) -> Option<Arc<RwLock<TranslatedVariable>>> {
+        if let Some(variable) = self.variables.iter().rev().find(|v| v.read().unwrap().old_name == old_name) {
             return Some(variable.clone());
         }
 
         if let Some(parent) = self.parent.as_ref() {
-            if let Some(variable) = parent.borrow().get_variable_from_old_name(old_name) {
+            if let Some(variable) = parent.read().unwrap().get_variable_from_old_name(old_name) {
                 return Some(variable);
             }
         }
@@ -126,13 +142,13 @@ impl TranslationScope {
     }
 
     /// Attempts to get a reference to a translated variable using its new name
-    pub fn get_variable_from_new_name(&self, new_name: &str) -> Option<Rc<RefCell<TranslatedVariable>>> {
-        if let Some(variable) = self.variables.iter().rev().find(|v| v.borrow().new_name == new_name) {
+    pub fn get_variable_from_new_name(&self, new_name: &str) -> Option<Arc<RwLock<TranslatedVariable>>> {
+        if let Some(variable) = self.variables.iter().rev().find(|v| v.read().unwrap().new_name == new_name) {
             return Some(variable.clone());
         }
 
         if let Some(parent) = self.parent.as_ref() {
-            if let Some(variable) = parent.borrow().get_variable_from_new_name(new_name) {
+            if let Some(variable) = parent.read().unwrap().get_variable_from_new_name(new_name) {
                 return Some(variable);
             }
         }
@@ -141,13 +157,13 @@ impl TranslationScope {
     }
 
     /// Attempts to find a translated variable using a custom function
-    pub fn find_variable<F: Copy + FnMut(&&Rc<RefCell<TranslatedVariable>>) -> bool>(&self, f: F) -> Option<Rc<RefCell<TranslatedVariable>>> {
+    pub fn find_variable<F: Copy + FnMut(&&Arc<RwLock<TranslatedVariable>>) -> bool>(&self, f: F) -> Option<Arc<RwLock<TranslatedVariable>>> {
         if let Some(variable) = self.variables.iter().find(f) {
             return Some(variable.clone());
         }
 
         if let Some(parent) = self.parent.as_ref() {
-            if let Some(variable) = parent.borrow().find_variable(f) {
+            if let Some(variable) = parent.read().unwrap().find_variable(f) {
                 return Some(variable);
             }
         }
@@ -161,9 +177,9 @@ impl TranslationScope {
         old_name: &str,
         parameters: &[sway::Expression],
         parameter_types: &[sway::TypeName],
-    ) -> Option<Rc<RefCell<TranslatedFunction>>> {
+    ) -> Option<Arc<RwLock<TranslatedFunction>>> {
         self.find_function(|f| {
-            let f = f.borrow();
+            let f = f.read().unwrap();
 
             // Ensure the function's old name matches the function call we're translating
             if f.old_name != old_name {
@@ -183,7 +199,7 @@ impl TranslationScope {
                     continue;
                 }
 
-                if !value_type_name.is_compatible_with(parameter_type_name) {
+                if !value_type_name.is_compatible_with(parameter_type_name) && !can_coerce_signed_expression(parameter_type_name, &parameters[i], value_type_name) {
                     return false;
                 }
             }
@@ -192,14 +208,25 @@ impl TranslationScope {
         })
     }
 
+    /// Finds all of the translated functions which match a custom function, starting with the innermost scope
+    pub fn find_functions<F: Copy + FnMut(&&Arc<RwLock<TranslatedFunction>>) -> bool>(&self, f: F) -> Vec<Arc<RwLock<TranslatedFunction>>> {
+        let mut functions = self.functions.iter().filter(f).cloned().collect::<Vec<_>>();
+
+        if let Some(parent) = self.parent.as_ref() {
+            functions.extend(parent.read().unwrap().find_functions(f));
+        }
+
+        functions
+    }
+
     /// Atempts to find a translated function using a custom function
-    pub fn find_function<F: Copy + FnMut(&&Rc<RefCell<TranslatedFunction>>) -> bool>(&self, f: F) -> Option<Rc<RefCell<TranslatedFunction>>> {
+    pub fn find_function<F: Copy + FnMut(&&Arc<RwLock<TranslatedFunction>>) -> bool>(&self, f: F) -> Option<Arc<RwLock<TranslatedFunction>>> {
         if let Some(function) = self.functions.iter().find(f) {
             return Some(function.clone());
         }
 
         if let Some(parent) = self.parent.as_ref() {
-            if let Some(function) = parent.borrow().find_function(f) {
+            if let Some(function) = parent.read().unwrap().find_function(f) {
                 return Some(function);
             }
         }
@@ -220,7 +247,7 @@ pub struct DeferredInitialization {
 #[derive(Clone, Debug, Default)]
 pub struct TranslatedDefinition {
     pub path: PathBuf,
-    pub toplevel_scope: Rc<RefCell<TranslationScope>>,
+    pub toplevel_scope: Arc<RwLock<TranslationScope>>,
     pub kind: Option<solidity::ContractTy>,
     pub dependencies: Vec<String>,
     pub deferred_initializations: Vec<DeferredInitialization>,
@@ -234,6 +261,7 @@ pub struct TranslatedDefinition {
     pub enums: Vec<TranslatedEnum>,
     pub events_enums: Vec<(sway::Enum, sway::Impl)>,
     pub errors_enums: Vec<(sway::Enum, sway::Impl)>,
+    pub function_enums: Vec<(sway::Enum, sway::Function)>,
     pub constants: Vec<sway::Constant>,
     pub abis: Vec<sway::Abi>,
     pub abi: Option<sway::Abi>,
@@ -322,6 +350,17 @@ impl Display for TranslatedDefinition {
             writeln!(f, "{}", sway::TabbedDisplayer(abi_encode_impl))?;
             written += 1;
         }
+
+        for (i, (function_enum, dispatch_function)) in self.function_enums.iter().enumerate() {
+            if (i == 0 && written > 0) || i > 0 {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "{}", sway::TabbedDisplayer(function_enum))?;
+            writeln!(f)?;
+            writeln!(f, "{}", sway::TabbedDisplayer(dispatch_function))?;
+            written += 1;
+        }
         
         for (i, x) in self.abis.iter().enumerate() {
             if (i == 0 && written > 0) || i > 0 {
@@ -422,6 +461,11 @@ impl From<TranslatedDefinition> for sway::Module {
             result.items.push(sway::ModuleItem::Enum(errors_enum.clone()));
             result.items.push(sway::ModuleItem::Impl(abi_encode_impl.clone()));
         }
+
+        for (function_enum, dispatch_function) in val.function_enums.iter() {
+            result.items.push(sway::ModuleItem::Enum(function_enum.clone()));
+            result.items.push(sway::ModuleItem::Function(dispatch_function.clone()));
+        }
         
         for x in val.abis.iter() {
             result.items.push(sway::ModuleItem::Abi(x.clone()));
@@ -459,7 +503,7 @@ impl TranslatedDefinition {
     pub fn new<P: AsRef<Path>, S1: ToString, S2: ToString>(path: P, kind: solidity::ContractTy, name: S1, inherits: Vec<S2>) -> Self {
         Self {
             path: path.as_ref().into(),
-            toplevel_scope: Rc::new(RefCell::new(TranslationScope::default())),
+            toplevel_scope: Arc::new(RwLock::new(TranslationScope::default())),
             kind: Some(kind),
             dependencies: vec![],
             deferred_initializations: vec![],
@@ -473,6 +517,7 @@ impl TranslatedDefinition {
             structs: vec![],
             events_enums: vec![],
             errors_enums: vec![],
+            function_enums: vec![],
             constants: vec![],
             abis: vec![],
             abi: None,
@@ -535,6 +580,779 @@ impl TranslatedDefinition {
         }
     }
 
+    /// Ensures that a type definition exists for an unsigned integer width which Sway doesn't have, i.e. `type u24 = u32;`
+    pub fn ensure_uint_type_declared(&mut self, bits: usize) -> sway::TypeName {
+        let type_name = sway::TypeName::Identifier {
+            name: format!("u{bits}"),
+            generic_parameters: None,
+        };
+
+        if !self.type_definitions.iter().any(|t| t.name == type_name) {
+            self.type_definitions.push(sway::TypeDefinition {
+                is_public: true,
+                name: type_name.clone(),
+                underlying_type: Some(sway::TypeName::Identifier {
+                    name: match bits {
+                        0..=8 => "u8",
+                        9..=16 => "u16",
+                        17..=32 => "u32",
+                        33..=64 => "u64",
+                        _ => "u256",
+                    }.into(),
+                    generic_parameters: None,
+                }),
+            });
+        }
+
+        type_name
+    }
+
+    /// Ensures that the range-checking function of an unsigned integer width which Sway doesn't have exists, and returns its name.
+    /// The function reverts when its value doesn't fit in the width, just like Solidity's checked arithmetic:
+    ///
+    /// ```sway
+    /// fn u24_checked(value: u32) -> u24 {
+    ///     require(value <= 16777215, "Arithmetic overflow");
+    ///     value
+    /// }
+    /// ```
+    pub fn ensure_uint_check_function_declared(&mut self, bits: usize) -> String {
+        let type_name = self.ensure_uint_type_declared(bits);
+        let underlying_type = self.get_underlying_type(&type_name);
+        let function_name = format!("u{bits}_checked");
+
+        *self.function_call_counts.entry(function_name.clone()).or_insert(0) += 1;
+
+        if self.functions.iter().any(|f| f.name == function_name) {
+            return function_name;
+        }
+
+        let parameters = sway::ParameterList {
+            entries: vec![
+                sway::Parameter {
+                    is_ref: false,
+                    is_mut: false,
+                    name: "value".into(),
+                    type_name: Some(underlying_type),
+                },
+            ],
+        };
+
+        self.toplevel_scope.write().unwrap().functions.push(Arc::new(RwLock::new(TranslatedFunction {
+            old_name: String::new(),
+            new_name: function_name.clone(),
+            parameters: parameters.clone(),
+            constructor_calls: vec![],
+            modifiers: vec![],
+            return_type: Some(type_name.clone()),
+        })));
+
+        self.functions.push(sway::Function {
+            doc_comment: None,
+            attributes: None,
+            is_public: false,
+            name: function_name.clone(),
+            generic_parameters: None,
+            parameters,
+            return_type: Some(type_name),
+            body: Some(sway::Block {
+                statements: vec![
+                    sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("require".into()),
+                        generic_parameters: None,
+                        parameters: vec![
+                            sway::Expression::from(sway::BinaryExpression {
+                                operator: "<=".into(),
+                                lhs: sway::Expression::Identifier("value".into()),
+                                rhs: sway::Expression::from(sway::Literal::DecInt((BigUint::one() << bits) - BigUint::one())),
+                            }),
+                            sway::Expression::from(sway::Literal::String("Arithmetic overflow".into())),
+                        ],
+                    })),
+                ],
+                final_expr: Some(sway::Expression::Identifier("value".into())),
+            }),
+            location: None,
+        });
+
+        function_name
+    }
+
+    /// Ensures that a type definition exists for a signed integer width which Sway doesn't have, i.e. `type i24 = I32;`
+    pub fn ensure_int_type_declared(&mut self, bits: usize) -> sway::TypeName {
+        let type_name = sway::TypeName::Identifier {
+            name: format!("i{bits}"),
+            generic_parameters: None,
+        };
+
+        if !self.type_definitions.iter().any(|t| t.name == type_name) {
+            self.type_definitions.push(sway::TypeDefinition {
+                is_public: true,
+                name: type_name.clone(),
+                underlying_type: Some(sway::TypeName::Identifier {
+                    name: format!("I{}", get_int_storage_bits(bits)),
+                    generic_parameters: None,
+                }),
+            });
+        }
+
+        type_name
+    }
+
+    /// Ensures that the range-checking function of a signed integer width which Sway doesn't have exists, and returns its name.
+    /// The `underlying` field grows with the value, so the function compares it against the bounds of the width:
+    ///
+    /// ```sway
+    /// fn i24_checked(value: I32) -> i24 {
+    ///     require(value.underlying >= 2139095040 && value.underlying <= 2155872255, "Arithmetic overflow");
+    ///     value
+    /// }
+    /// ```
+    pub fn ensure_int_check_function_declared(&mut self, bits: usize) -> String {
+        let type_name = self.ensure_int_type_declared(bits);
+        let underlying_type = self.get_underlying_type(&type_name);
+        let function_name = format!("i{bits}_checked");
+
+        *self.function_call_counts.entry(function_name.clone()).or_insert(0) += 1;
+
+        if self.functions.iter().any(|f| f.name == function_name) {
+            return function_name;
+        }
+
+        let parameters = sway::ParameterList {
+            entries: vec![
+                sway::Parameter {
+                    is_ref: false,
+                    is_mut: false,
+                    name: "value".into(),
+                    type_name: Some(underlying_type),
+                },
+            ],
+        };
+
+        self.toplevel_scope.write().unwrap().functions.push(Arc::new(RwLock::new(TranslatedFunction {
+            old_name: String::new(),
+            new_name: function_name.clone(),
+            parameters: parameters.clone(),
+            constructor_calls: vec![],
+            modifiers: vec![],
+            return_type: Some(type_name.clone()),
+        })));
+
+        // The bounds of the width, offset by the `indent()` of the type which holds it
+        let indent = BigUint::one() << (get_int_storage_bits(bits) - 1);
+        let half = BigUint::one() << (bits - 1);
+
+        let underlying = sway::Expression::from(sway::MemberAccess {
+            expression: sway::Expression::Identifier("value".into()),
+            member: "underlying".into(),
+        });
+
+        self.functions.push(sway::Function {
+            doc_comment: None,
+            attributes: None,
+            is_public: false,
+            name: function_name.clone(),
+            generic_parameters: None,
+            parameters,
+            return_type: Some(type_name),
+            body: Some(sway::Block {
+                statements: vec![
+                    sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("require".into()),
+                        generic_parameters: None,
+                        parameters: vec![
+                            sway::Expression::from(sway::BinaryExpression {
+                                operator: "&&".into(),
+                                lhs: sway::Expression::from(sway::BinaryExpression {
+                                    operator: ">=".into(),
+                                    lhs: underlying.clone(),
+                                    rhs: sway::Expression::from(sway::Literal::DecInt(&indent - &half)),
+                                }),
+                                rhs: sway::Expression::from(sway::BinaryExpression {
+                                    operator: "<=".into(),
+                                    lhs: underlying,
+                                    rhs: sway::Expression::from(sway::Literal::DecInt(&indent + &half - BigUint::one())),
+                                }),
+                            }),
+                            sway::Expression::from(sway::Literal::String("Arithmetic overflow".into())),
+                        ],
+                    })),
+                ],
+                final_expr: Some(sway::Expression::Identifier("value".into())),
+            }),
+            location: None,
+        });
+
+        function_name
+    }
+
+    /// Gets the Solidity bit width of a signed integer type, including the type definitions of the widths which Sway doesn't have (i.e. `24` for `i24`)
+    pub fn get_int_width(&self, type_name: &sway::TypeName) -> Option<usize> {
+        if let Some(bits) = type_name.non_standard_int_bits().or_else(|| type_name.int_bits()) {
+            return Some(bits);
+        }
+
+        let type_definition = self.type_definitions.iter().find(|t| t.name == *type_name)?;
+        self.get_int_width(type_definition.underlying_type.as_ref()?)
+    }
+
+    /// Ensures that a function which performs an arithmetic operation on an unsigned integer type without reverting on overflow exists,
+    /// and returns its name. Widths which Sway doesn't have are truncated to their own width:
+    ///
+    /// ```sway
+    /// fn wrapping_add_u24(lhs: u24, rhs: u24) -> u24 {
+    ///     let flags = disable_panic_on_overflow();
+    ///     let result = (lhs + rhs) & 16777215;
+    ///     set_flags(flags);
+    ///     result
+    /// }
+    /// ```
+    pub fn ensure_wrapping_function_declared(&mut self, operator: &str, type_name: &sway::TypeName) -> Result<String, Error> {
+        let sway::TypeName::Identifier { name: type_name_string, generic_parameters: None } = type_name else {
+            return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Expected unsigned integer type name for wrapping arithmetic, found {type_name}")));
+        };
+
+        let operator_name = match operator {
+            "+" => "add",
+            "-" => "sub",
+            "*" => "mul",
+            "**" => "pow",
+            _ => return Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("wrapping {operator} operator"))),
+        };
+
+        let function_name = format!("wrapping_{operator_name}_{}", type_name_string.to_lowercase());
+
+        *self.function_call_counts.entry(function_name.clone()).or_insert(0) += 1;
+
+        if self.functions.iter().any(|f| f.name == function_name) {
+            return Ok(function_name);
+        }
+
+        self.ensure_use_declared("std::flags::disable_panic_on_overflow");
+        self.ensure_use_declared("std::flags::set_flags");
+
+        let parameters = sway::ParameterList {
+            entries: vec![
+                sway::Parameter {
+                    is_ref: false,
+                    is_mut: false,
+                    name: "lhs".into(),
+                    type_name: Some(type_name.clone()),
+                },
+                sway::Parameter {
+                    is_ref: false,
+                    is_mut: false,
+                    name: "rhs".into(),
+                    type_name: Some(if operator == "**" {
+                        sway::TypeName::Identifier {
+                            name: "u32".into(),
+                            generic_parameters: None,
+                        }
+                    } else {
+                        type_name.clone()
+                    }),
+                },
+            ],
+        };
+
+        let mut result = if operator == "**" {
+            self.ensure_use_declared("std::math::Power");
+
+            // lhs.pow(rhs)
+            sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::from(sway::MemberAccess {
+                    expression: sway::Expression::Identifier("lhs".into()),
+                    member: "pow".into(),
+                }),
+                generic_parameters: None,
+                parameters: vec![sway::Expression::Identifier("rhs".into())],
+            })
+        } else {
+            // lhs op rhs
+            sway::Expression::from(sway::BinaryExpression {
+                operator: operator.into(),
+                lhs: sway::Expression::Identifier("lhs".into()),
+                rhs: sway::Expression::Identifier("rhs".into()),
+            })
+        };
+
+        // (result) & mask
+        if type_name.is_non_standard_uint() {
+            result = sway::Expression::from(sway::BinaryExpression {
+                operator: "&".into(),
+                lhs: sway::Expression::Tuple(vec![result]),
+                rhs: sway::Expression::from(sway::Literal::DecInt((BigUint::one() << type_name.uint_bits().unwrap()) - BigUint::one())),
+            });
+        }
+
+        self.toplevel_scope.write().unwrap().functions.push(Arc::new(RwLock::new(TranslatedFunction {
+            old_name: String::new(),
+            new_name: function_name.clone(),
+            parameters: parameters.clone(),
+            constructor_calls: vec![],
+            modifiers: vec![],
+            return_type: Some(type_name.clone()),
+        })));
+
+        self.functions.push(sway::Function {
+            doc_comment: None,
+            attributes: None,
+            is_public: false,
+            name: function_name.clone(),
+            generic_parameters: None,
+            parameters,
+            return_type: Some(type_name.clone()),
+            body: Some(sway::Block {
+                statements: vec![
+                    sway::Statement::from(sway::Let {
+                        pattern: sway::LetPattern::from(sway::LetIdentifier {
+                            is_mutable: false,
+                            name: "flags".into(),
+                        }),
+                        type_name: None,
+                        value: sway::Expression::from(sway::FunctionCall {
+                            function: sway::Expression::Identifier("disable_panic_on_overflow".into()),
+                            generic_parameters: None,
+                            parameters: vec![],
+                        }),
+                    }),
+                    sway::Statement::from(sway::Let {
+                        pattern: sway::LetPattern::from(sway::LetIdentifier {
+                            is_mutable: false,
+                            name: "result".into(),
+                        }),
+                        type_name: None,
+                        value: result,
+                    }),
+                    sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("set_flags".into()),
+                        generic_parameters: None,
+                        parameters: vec![sway::Expression::Identifier("flags".into())],
+                    })),
+                ],
+                final_expr: Some(sway::Expression::Identifier("result".into())),
+            }),
+            location: None,
+        });
+
+        Ok(function_name)
+    }
+
+    /// Ensures that a function which multiplies or divides values of a fixed point type narrower than 256 bits exists, and returns its name.
+    /// The operands are widened to 256 bits so the rescaled intermediate product doesn't overflow, and the result reverts if it doesn't fit in the type:
+    ///
+    /// ```sway
+    /// fn fixed128x18_mul(lhs: fixed128x18, rhs: fixed128x18) -> fixed128x18 {
+    ///     let result = I256 { underlying: u256::from(lhs.underlying) + offset } * I256 { underlying: u256::from(rhs.underlying) + offset } / scale;
+    ///     require(result.underlying >= min && result.underlying <= max, "Arithmetic overflow");
+    ///     I128 { underlying: U128::try_from((result.underlying ^ I256::indent()) & mask).unwrap() ^ I128::indent() }
+    /// }
+    /// ```
+    pub fn ensure_fixed_point_function_declared(&mut self, operator: &str, type_name: &sway::TypeName) -> Result<String, Error> {
+        let Some((signed, bits, decimals)) = parse_fixed_point_type_name(&type_name.to_string()) else {
+            return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Expected fixed point type name, found {type_name}")));
+        };
+
+        let operator_name = match operator {
+            "*" => "mul",
+            "/" => "div",
+            _ => return Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("fixed point {operator} operator"))),
+        };
+
+        let function_name = format!("{type_name}_{operator_name}");
+
+        *self.function_call_counts.entry(function_name.clone()).or_insert(0) += 1;
+
+        if self.functions.iter().any(|f| f.name == function_name) {
+            return Ok(function_name);
+        }
+
+        let underlying_type_name = self.get_underlying_type(type_name);
+        let storage_bits = underlying_type_name.int_bits().or_else(|| underlying_type_name.uint_bits()).unwrap();
+        let bits = bits as usize;
+
+        let parameters = sway::ParameterList {
+            entries: ["lhs", "rhs"].into_iter().map(|name| sway::Parameter {
+                is_ref: false,
+                is_mut: false,
+                name: name.into(),
+                type_name: Some(type_name.clone()),
+            }).collect(),
+        };
+
+        let scale = BigUint::from(10u8).pow(decimals as u32);
+
+        let (lhs, rhs, scale) = if signed {
+            self.ensure_use_declared("signed_integers::i256::*");
+
+            (
+                create_signed_resize_expression(self, storage_bits, 256, sway::Expression::Identifier("lhs".into())),
+                create_signed_resize_expression(self, storage_bits, 256, sway::Expression::Identifier("rhs".into())),
+                create_signed_literal_expression(self, 256, &scale, false),
+            )
+        } else {
+            (
+                create_uint_resize_expression(self, storage_bits, 256, sway::Expression::Identifier("lhs".into())),
+                create_uint_resize_expression(self, storage_bits, 256, sway::Expression::Identifier("rhs".into())),
+                create_uint_literal_expression(self, 256, scale),
+            )
+        };
+
+        // lhs * rhs / scale or lhs * scale / rhs
+        let result = sway::Expression::from(sway::BinaryExpression {
+            operator: "/".into(),
+            lhs: sway::Expression::from(sway::BinaryExpression {
+                operator: "*".into(),
+                lhs,
+                rhs: if operator == "*" { rhs.clone() } else { scale.clone() },
+            }),
+            rhs: if operator == "*" { scale } else { rhs },
+        });
+
+        // The bounds of the type, offset by `I256::indent()` for signed types
+        let (value, condition) = if signed {
+            let indent = BigUint::one() << 255;
+            let half = BigUint::one() << (bits - 1);
+
+            let value = sway::Expression::from(sway::MemberAccess {
+                expression: sway::Expression::Identifier("result".into()),
+                member: "underlying".into(),
+            });
+
+            let condition = sway::Expression::from(sway::BinaryExpression {
+                operator: "&&".into(),
+                lhs: sway::Expression::from(sway::BinaryExpression {
+                    operator: ">=".into(),
+                    lhs: value.clone(),
+                    rhs: create_uint_literal_expression(self, 256, &indent - &half),
+                }),
+                rhs: sway::Expression::from(sway::BinaryExpression {
+                    operator: "<=".into(),
+                    lhs: value,
+                    rhs: create_uint_literal_expression(self, 256, &indent + &half - BigUint::one()),
+                }),
+            });
+
+            (create_signed_resize_expression(self, 256, storage_bits, sway::Expression::Identifier("result".into())), condition)
+        } else {
+            let condition = sway::Expression::from(sway::BinaryExpression {
+                operator: "<=".into(),
+                lhs: sway::Expression::Identifier("result".into()),
+                rhs: create_uint_literal_expression(self, 256, (BigUint::one() << bits) - BigUint::one()),
+            });
+
+            (create_uint_resize_expression(self, 256, storage_bits, sway::Expression::Identifier("result".into())), condition)
+        };
+
+        self.toplevel_scope.write().unwrap().functions.push(Arc::new(RwLock::new(TranslatedFunction {
+            old_name: String::new(),
+            new_name: function_name.clone(),
+            parameters: parameters.clone(),
+            constructor_calls: vec![],
+            modifiers: vec![],
+            return_type: Some(type_name.clone()),
+        })));
+
+        self.functions.push(sway::Function {
+            doc_comment: None,
+            attributes: None,
+            is_public: false,
+            name: function_name.clone(),
+            generic_parameters: None,
+            parameters,
+            return_type: Some(type_name.clone()),
+            body: Some(sway::Block {
+                statements: vec![
+                    sway::Statement::from(sway::Let {
+                        pattern: sway::LetPattern::from(sway::LetIdentifier {
+                            is_mutable: false,
+                            name: "result".into(),
+                        }),
+                        type_name: None,
+                        value: result,
+                    }),
+                    sway::Statement::from(sway::Expression::from(sway::FunctionCall {
+                        function: sway::Expression::Identifier("require".into()),
+                        generic_parameters: None,
+                        parameters: vec![
+                            condition,
+                            sway::Expression::from(sway::Literal::String("Arithmetic overflow".into())),
+                        ],
+                    })),
+                ],
+                final_expr: Some(value),
+            }),
+            location: None,
+        });
+
+        Ok(function_name)
+    }
+
+    /// Ensures that an enum of the internal functions with the supplied signature exists, along with a function which calls the function that a value
+    /// of the enum refers to, and returns the enum's type name. The storage access of the dispatch function is widened to cover `has_storage_read` and
+    /// `has_storage_write`:
+    ///
+    /// ```sway
+    /// enum FunctionU256ReturnsU256 {
+    ///     Unassigned: (),
+    ///     Double: (),
+    /// }
+    ///
+    /// fn dispatch_function_u256_returns_u256(function: FunctionU256ReturnsU256, arg0: u256) -> u256 {
+    ///     match function {
+    ///         FunctionU256ReturnsU256::Unassigned => revert(0x51),
+    ///         FunctionU256ReturnsU256::Double => double(arg0),
+    ///     }
+    /// }
+    /// ```
+    pub fn ensure_function_type_declared(
+        &mut self,
+        parameter_types: &[sway::TypeName],
+        return_type: Option<&sway::TypeName>,
+        has_storage_read: bool,
+        has_storage_write: bool,
+    ) -> sway::TypeName {
+        let create_attributes = |has_storage_read: bool, has_storage_write: bool| -> Option<sway::AttributeList> {
+            let mut parameters = vec![];
+
+            if has_storage_read {
+                parameters.push("read".to_string());
+            }
+
+            if has_storage_write {
+                parameters.push("write".to_string());
+            }
+
+            if parameters.is_empty() {
+                None
+            } else {
+                Some(sway::AttributeList {
+                    attributes: vec![
+                        sway::Attribute {
+                            name: "storage".into(),
+                            parameters: Some(parameters),
+                        },
+                    ],
+                })
+            }
+        };
+
+        let name_parts = Self::get_function_type_name_parts(parameter_types, return_type);
+        let enum_name = name_parts.concat();
+
+        let type_name = sway::TypeName::Identifier {
+            name: enum_name.clone(),
+            generic_parameters: None,
+        };
+
+        if let Some((_, dispatch_function)) = self.function_enums.iter_mut().find(|(e, _)| e.name == enum_name) {
+            // Widen the storage access of the dispatch function if necessary
+            let (mut storage_read, mut storage_write) = (false, false);
+
+            if let Some(parameters) = dispatch_function.attributes.as_ref().and_then(|a| a.attributes.iter().find(|a| a.name == "storage")).and_then(|a| a.parameters.as_ref()) {
+                storage_read = parameters.iter().any(|p| p == "read");
+                storage_write = parameters.iter().any(|p| p == "write");
+            }
+
+            dispatch_function.attributes = create_attributes(storage_read || has_storage_read, storage_write || has_storage_write);
+
+            return type_name;
+        }
+
+        let dispatch_function_name = format!("dispatch_{}", name_parts.iter().map(|p| p.to_lowercase()).collect::<Vec<_>>().join("_"));
+
+        let mut parameters = sway::ParameterList {
+            entries: vec![
+                sway::Parameter {
+                    is_ref: false,
+                    is_mut: false,
+                    name: "function".into(),
+                    type_name: Some(type_name.clone()),
+                },
+            ],
+        };
+
+        for (i, parameter_type) in parameter_types.iter().enumerate() {
+            parameters.entries.push(sway::Parameter {
+                is_ref: false,
+                is_mut: false,
+                name: format!("arg{i}"),
+                type_name: Some(parameter_type.clone()),
+            });
+        }
+
+        let unassigned_name = format!("{enum_name}::Unassigned");
+
+        self.toplevel_scope.write().unwrap().variables.push(Arc::new(RwLock::new(TranslatedVariable {
+            old_name: String::new(),
+            new_name: unassigned_name.clone(),
+            type_name: type_name.clone(),
+            ..Default::default()
+        })));
+
+        self.toplevel_scope.write().unwrap().functions.push(Arc::new(RwLock::new(TranslatedFunction {
+            old_name: String::new(),
+            new_name: dispatch_function_name.clone(),
+            parameters: parameters.clone(),
+            constructor_calls: vec![],
+            modifiers: vec![],
+            return_type: return_type.cloned(),
+        })));
+
+        self.function_enums.push((
+            sway::Enum {
+                attributes: None,
+                is_public: false,
+                name: enum_name,
+                generic_parameters: None,
+                variants: vec![
+                    sway::EnumVariant {
+                        doc_comment: None,
+                        name: "Unassigned".into(),
+                        type_name: sway::TypeName::Tuple { type_names: vec![] },
+                    },
+                ],
+            },
+            sway::Function {
+                doc_comment: None,
+                attributes: create_attributes(has_storage_read, has_storage_write),
+                is_public: false,
+                name: dispatch_function_name,
+                generic_parameters: None,
+                parameters,
+                return_type: return_type.cloned(),
+                body: Some(sway::Block {
+                    statements: vec![],
+                    final_expr: Some(sway::Expression::from(sway::Match {
+                        expression: sway::Expression::Identifier("function".into()),
+                        branches: vec![
+                            // Calling an unassigned internal function panics with code 0x51 in Solidity
+                            sway::MatchBranch {
+                                pattern: sway::Expression::Identifier(unassigned_name),
+                                value: sway::Expression::from(sway::FunctionCall {
+                                    function: sway::Expression::Identifier("revert".into()),
+                                    generic_parameters: None,
+                                    parameters: vec![
+                                        sway::Expression::from(sway::Literal::HexInt(BigUint::from(0x51u8))),
+                                    ],
+                                }),
+                            },
+                        ],
+                    })),
+                }),
+                location: None,
+            },
+        ));
+
+        type_name
+    }
+
+    /// Gets the parts of the name of the enum of internal functions with the supplied signature, i.e. `["Function", "U256", "Returns", "U256"]`.
+    fn get_function_type_name_parts(parameter_types: &[sway::TypeName], return_type: Option<&sway::TypeName>) -> Vec<String> {
+        // Make an identifier out of each type name, i.e. `StorageKey<StorageMap<u256, u256>>` => `StorageKeyStorageMapU256U256`
+        let type_name_to_identifier = |type_name: &sway::TypeName| -> String {
+            type_name.to_string()
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|s| !s.is_empty())
+                .map(|s| {
+                    let mut chars = s.chars();
+                    chars.next().unwrap().to_uppercase().chain(chars).collect::<String>()
+                })
+                .collect()
+        };
+
+        let mut name_parts = vec!["Function".to_string()];
+        name_parts.extend(parameter_types.iter().map(type_name_to_identifier));
+
+        if let Some(return_type) = return_type {
+            name_parts.push("Returns".into());
+            name_parts.push(type_name_to_identifier(return_type));
+        }
+
+        name_parts
+    }
+
+    /// Gets the type name of the enum of internal functions with the signature of the supplied function, i.e. `FunctionU256ReturnsU256`.
+    pub fn get_function_type_name(function: &TranslatedFunction) -> sway::TypeName {
+        let parameter_types = function.parameters.entries.iter()
+            .map(|p| p.type_name.clone().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        sway::TypeName::Identifier {
+            name: Self::get_function_type_name_parts(&parameter_types, function.return_type.as_ref()).concat(),
+            generic_parameters: None,
+        }
+    }
+
+    /// Ensures that the supplied function is a variant of the enum of internal functions with its signature, and returns the expression which refers to it.
+    pub fn ensure_function_type_variant_declared(&mut self, function: &TranslatedFunction) -> Result<sway::Expression, Error> {
+        let parameter_types = function.parameters.entries.iter()
+            .map(|p| p.type_name.clone().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        let type_name = self.ensure_function_type_declared(&parameter_types, function.return_type.as_ref(), false, false);
+
+        let sway::TypeName::Identifier { name: enum_name, generic_parameters: None } = type_name else {
+            return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Expected function type enum name, found {type_name}")));
+        };
+
+        let variant_name = crate::translate_naming_convention(function.new_name.as_str(), Case::Pascal);
+        let variant_path = format!("{enum_name}::{variant_name}");
+
+        // Ensure the target function is emitted, since it can be called through the dispatch function
+        *self.function_call_counts.entry(function.new_name.clone()).or_insert(0) += 1;
+
+        let Some((function_enum, dispatch_function)) = self.function_enums.iter_mut().find(|(e, _)| e.name == enum_name) else {
+            return Err(Error::UnresolvedReference(solidity::Loc::Implicit, format!("Failed to find function type enum `{enum_name}`")));
+        };
+
+        if function_enum.variants.iter().any(|v| v.name == variant_name) {
+            return Ok(sway::Expression::Identifier(variant_path));
+        }
+
+        function_enum.variants.push(sway::EnumVariant {
+            doc_comment: None,
+            name: variant_name,
+            type_name: sway::TypeName::Tuple { type_names: vec![] },
+        });
+
+        let Some(sway::Expression::Match(dispatch_match)) = dispatch_function.body.as_mut().and_then(|b| b.final_expr.as_mut()) else {
+            return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Malformed dispatch function `{}`, expected a match expression", dispatch_function.name)));
+        };
+
+        // variant => function(arg0, ..)
+        dispatch_match.branches.push(sway::MatchBranch {
+            pattern: sway::Expression::Identifier(variant_path.clone()),
+            value: sway::Expression::from(sway::FunctionCall {
+                function: sway::Expression::Identifier(function.new_name.clone()),
+                generic_parameters: None,
+                parameters: (0..parameter_types.len()).map(|i| sway::Expression::Identifier(format!("arg{i}"))).collect(),
+            }),
+        });
+
+        self.toplevel_scope.write().unwrap().variables.push(Arc::new(RwLock::new(TranslatedVariable {
+            old_name: String::new(),
+            new_name: variant_path.clone(),
+            type_name: sway::TypeName::Identifier {
+                name: enum_name,
+                generic_parameters: None,
+            },
+            ..Default::default()
+        })));
+
+        Ok(sway::Expression::Identifier(variant_path))
+    }
+
+    /// Gets the name of the dispatch function of the supplied type name, if it is an enum of internal functions.
+    pub fn get_function_type_dispatch_name(&self, type_name: &sway::TypeName) -> Option<String> {
+        let sway::TypeName::Identifier { name, generic_parameters: None } = type_name else { return None };
+
+        self.function_enums.iter()
+            .find(|(e, _)| e.name == *name)
+            .map(|(_, f)| f.name.clone())
+    }
+
     #[inline]
     pub fn import_enum(&mut self, translated_enum: &TranslatedEnum) {
         let sway::TypeName::Identifier { name, generic_parameters: None } = &translated_enum.type_definition.name else {
@@ -544,7 +1362,7 @@ impl TranslatedDefinition {
         for item in translated_enum.variants_impl.items.iter() {
             let sway::ImplItem::Constant(c) = item else { continue };
             
-            self.toplevel_scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+            self.toplevel_scope.write().unwrap().variables.push(Arc::new(RwLock::new(TranslatedVariable {
                 old_name: String::new(), // TODO: is this ok?
                 new_name: format!("{}::{}", name, c.name),
                 type_name: translated_enum.type_definition.name.clone(),
@@ -560,6 +1378,7 @@ impl TranslatedDefinition {
     pub fn get_abi(&mut self) -> &mut sway::Abi {
         if self.abi.is_none() {
             self.abi = Some(sway::Abi {
+                doc_comment: None,
                 name: self.name.clone(),
                 inherits: vec![],
                 functions: vec![],
@@ -657,7 +1476,7 @@ impl TranslatedDefinition {
 
     pub fn get_expression_type(
         &mut self,
-        scope: Rc<RefCell<TranslationScope>>,
+        scope: Arc<RwLock<TranslationScope>>,
         expression: &sway::Expression,
     ) -> Result<sway::TypeName, Error> {
         match expression {
@@ -706,15 +1525,15 @@ impl TranslatedDefinition {
                     }
                 }
 
-                let Some(variable) = scope.borrow().get_variable_from_new_name(name) else {
-                    panic!("error: Variable not found in scope: \"{name}\"");
+                let Some(variable) = scope.read().unwrap().get_variable_from_new_name(name) else {
+                    return Err(Error::UnresolvedReference(solidity::Loc::Implicit, format!("Variable not found in scope: \"{name}\"")));
                 };
         
-                let variable = variable.borrow();
+                let variable = variable.read().unwrap();
 
                 // Variable should not be a storage field
                 if variable.is_storage {
-                    panic!("error: Variable not found in scope: \"{name}\"");
+                    return Err(Error::UnresolvedReference(solidity::Loc::Implicit, format!("Variable not found in scope: \"{name}\"")));
                 }
 
                 Ok(variable.type_name.clone())
@@ -736,11 +1555,11 @@ impl TranslatedDefinition {
     
                         "abi" => {
                             if parameters.len() != 2 {
-                                panic!("Malformed abi cast, expected 2 parameters, found {}", parameters.len());
+                                return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Malformed abi cast, expected 2 parameters, found {}", parameters.len())));
                             }
     
                             let sway::Expression::Identifier(definition_name) = &parameters[0] else {
-                                panic!("Malformed abi cast, expected identifier, found {:#?}", parameters[0]);
+                                return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Malformed abi cast, expected identifier, found {}", sway::TabbedDisplayer(&parameters[0]))));
                             };
     
                             Ok(sway::TypeName::Identifier {
@@ -995,8 +1814,8 @@ impl TranslatedDefinition {
                                 .collect::<Result<Vec<_>, _>>()?;
                             
                             // Ensure the function exists in scope
-                            let Some(function) = scope.borrow().find_function(|f| {
-                                let f = f.borrow();
+                            let Some(function) = scope.read().unwrap().find_function(|f| {
+                                let f = f.read().unwrap();
     
                                 // Ensure the function's new name matches the function call we're translating
                                 if f.new_name != new_name {
@@ -1011,17 +1830,17 @@ impl TranslatedDefinition {
                                 for (i, value_type_name) in parameter_types.iter().enumerate() {
                                     let Some(parameter_type_name) = f.parameters.entries[i].type_name.as_ref() else { continue };
     
-                                    if !value_type_name.is_compatible_with(parameter_type_name) {
+                                    if !value_type_name.is_compatible_with(parameter_type_name) && !can_coerce_signed_expression(parameter_type_name, &parameters[i], value_type_name) {
                                         return false;
                                     }
                                 }
     
                                 true
                             }) else {
-                                panic!("Failed to find function `{new_name}` in scope");
+                                return Err(Error::UnresolvedReference(solidity::Loc::Implicit, format!("Failed to find function `{new_name}` in scope")));
                             };
     
-                            let function = function.borrow();
+                            let function = function.read().unwrap();
     
                             if let Some(return_type) = function.return_type.as_ref() {
                                 Ok(return_type.clone())
@@ -1032,7 +1851,7 @@ impl TranslatedDefinition {
                     }
     
                     sway::Expression::MemberAccess(member_access) => match self.get_expression_type(scope.clone(), &member_access.expression)? {
-                        sway::TypeName::Undefined => panic!("Undefined type name"),
+                        sway::TypeName::Undefined => Err(Error::InvalidConstruct(solidity::Loc::Implicit, "Undefined type name".into())),
     
                         sway::TypeName::Identifier { name, generic_parameters } => match (name.as_str(), generic_parameters.as_ref()) {
                             ("b256", None) => match member_access.member.as_str() {
@@ -1051,7 +1870,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
     
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
     
                             ("Bytes", None) => match member_access.member.as_str() {
@@ -1077,7 +1896,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
     
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
     
                             ("I8", None) => match member_access.member.as_str() {
@@ -1086,7 +1905,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
 
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
 
                             ("I16", None) => match member_access.member.as_str() {
@@ -1095,7 +1914,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
 
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
 
                             ("I32", None) => match member_access.member.as_str() {
@@ -1104,7 +1923,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
 
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
 
                             ("I64", None) => match member_access.member.as_str() {
@@ -1113,7 +1932,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
 
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
 
                             ("I128", None) => match member_access.member.as_str() {
@@ -1122,7 +1941,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
 
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
 
                             ("I256", None) => match member_access.member.as_str() {
@@ -1131,7 +1950,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
 
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
 
                             ("Identity", None) => match member_access.member.as_str() {
@@ -1175,19 +1994,19 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
     
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
     
                             ("Option", Some(generic_parameters)) if generic_parameters.entries.len() == 1 => match member_access.member.as_str() {
                                 "unwrap" => Ok(generic_parameters.entries[0].type_name.clone()),
                                 
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
                             
                             ("Result", Some(generic_parameters)) if generic_parameters.entries.len() == 2 => match member_access.member.as_str() {
                                 "unwrap" => Ok(generic_parameters.entries[0].type_name.clone()),
                                 
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
 
                             ("raw_slice", None) => match member_access.member.as_str() {
@@ -1196,7 +2015,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
 
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
                             
                             ("StorageKey", Some(generic_parameters)) if generic_parameters.entries.len() == 1 => match member_access.member.as_str() {
@@ -1251,7 +2070,7 @@ impl TranslatedDefinition {
     
                                             "write_slice" => Ok(sway::TypeName::Tuple { type_names: vec![] }),
     
-                                            _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                            _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                                         }
     
                                         ("StorageMap", Some(generic_parameters)) if generic_parameters.entries.len() == 2 => match member_access.member.as_str() {
@@ -1300,7 +2119,7 @@ impl TranslatedDefinition {
                                                 }),
                                             }),
     
-                                            _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                            _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                                         }
     
                                         ("StorageString", None) => match member_access.member.as_str() {
@@ -1331,7 +2150,7 @@ impl TranslatedDefinition {
     
                                             "write_slice" => Ok(sway::TypeName::Tuple { type_names: vec![] }),
     
-                                            _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                            _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                                         }
     
                                         ("StorageVec", Some(generic_parameters)) if generic_parameters.entries.len() == 1 => match member_access.member.as_str() {
@@ -1455,13 +2274,13 @@ impl TranslatedDefinition {
     
                                             "swap" => Ok(sway::TypeName::Tuple { type_names: vec![] }),
     
-                                            _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                            _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                                         }
     
-                                        _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                        _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                                     }
     
-                                    _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                    _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                                 }
                             }
     
@@ -1471,7 +2290,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
     
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
     
                             ("u8", None) => match member_access.member.as_str() {
@@ -1500,7 +2319,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
     
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
     
                             ("u16", None) => match member_access.member.as_str() {
@@ -1534,7 +2353,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
     
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
     
                             ("u32", None) => match member_access.member.as_str() {
@@ -1563,7 +2382,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
     
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
     
                             ("u64", None) => match member_access.member.as_str() {
@@ -1601,7 +2420,7 @@ impl TranslatedDefinition {
                                     })
                                 }
     
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
     
                             ("u256", None) => match member_access.member.as_str() {
@@ -1625,7 +2444,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
     
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
     
                             ("Vec", Some(generic_parameters)) if generic_parameters.entries.len() == 1 => match member_access.member.as_str() {
@@ -1643,7 +2462,7 @@ impl TranslatedDefinition {
                                     generic_parameters: None,
                                 }),
                                 
-                                _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                                _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                             }
     
                             (name, None) => {
@@ -1663,10 +2482,10 @@ impl TranslatedDefinition {
                                     }
                                 }
                                 
-                                todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression))
+                                Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression))))
                             }
     
-                            _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                            _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                         }
     
                         sway::TypeName::StringSlice => match member_access.member.as_str() {
@@ -1675,13 +2494,13 @@ impl TranslatedDefinition {
                                 generic_parameters: None,
                             }),
     
-                            _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                            _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                         }
     
-                        _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                        _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                     }
     
-                    _ => todo!("get type of function call expression: {} - {expression:#?}", sway::TabbedDisplayer(expression)),
+                    _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of function call expression: {}", sway::TabbedDisplayer(expression)))),
                 }
             }
 
@@ -1690,7 +2509,7 @@ impl TranslatedDefinition {
                     return Ok(sway::TypeName::Tuple { type_names: vec![] });
                 };
 
-                let inner_scope = Rc::new(RefCell::new(TranslationScope {
+                let inner_scope = Arc::new(RwLock::new(TranslationScope {
                     parent: Some(scope.clone()),
                     ..Default::default()
                 }));
@@ -1700,7 +2519,7 @@ impl TranslatedDefinition {
                         pattern,
                         type_name,
                         value,
-                    }) = statement else { continue };
+                    }) = statement.unlocated() else { continue };
 
                     let type_name = match type_name.as_ref() {
                         Some(type_name) => type_name.clone(),
@@ -1708,7 +2527,7 @@ impl TranslatedDefinition {
                     };
 
                     let add_variable = |id: &sway::LetIdentifier, type_name: &sway::TypeName| {
-                        inner_scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+                        inner_scope.write().unwrap().variables.push(Arc::new(RwLock::new(TranslatedVariable {
                             old_name: String::new(),
                             new_name: id.name.clone(),
                             type_name: type_name.clone(),
@@ -1720,7 +2539,7 @@ impl TranslatedDefinition {
                         sway::LetPattern::Identifier(id) => add_variable(id, &type_name),
                         sway::LetPattern::Tuple(ids) => {
                             let sway::TypeName::Tuple { type_names } = &type_name else {
-                                panic!("Expected tuple type, found {type_name}");
+                                return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Expected tuple type, found {type_name}")));
                             };
 
                             for (id, type_name) in ids.iter().zip(type_names.iter()) {
@@ -1765,7 +2584,7 @@ impl TranslatedDefinition {
 
                     sway::TypeName::Array { type_name, .. } => type_name.as_ref(),
 
-                    _ => todo!("array access for type {element_type_name}"),
+                    _ => return Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("array access for type {element_type_name}"))),
                 };
 
                 Ok(type_name.clone())
@@ -1774,11 +2593,11 @@ impl TranslatedDefinition {
             sway::Expression::MemberAccess(member_access) => match &member_access.expression {
                 sway::Expression::Identifier(name) => match name.as_str() {
                     "storage" => {
-                        let Some(variable) = scope.borrow().find_variable(|v| v.borrow().is_storage && v.borrow().new_name == member_access.member) else {
-                            panic!("Failed to find storage variable in scope: `{}`", member_access.member);
+                        let Some(variable) = scope.read().unwrap().find_variable(|v| v.read().unwrap().is_storage && v.read().unwrap().new_name == member_access.member) else {
+                            return Err(Error::UnresolvedReference(solidity::Loc::Implicit, format!("Failed to find storage variable in scope: `{}`", member_access.member)));
                         };
 
-                        let variable = variable.borrow();
+                        let variable = variable.read().unwrap();
 
                         Ok(sway::TypeName::Identifier {
                             name: "StorageKey".into(),
@@ -1804,7 +2623,7 @@ impl TranslatedDefinition {
                                         generic_parameters: None,
                                     }),
 
-                                    _ => todo!("get type of {container_type} member access expression: {expression:#?}"),
+                                    _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of {container_type} member access expression: {}", sway::TabbedDisplayer(expression)))),
                                 }
 
                                 ("I16", None) => match member_access.member.as_str() {
@@ -1813,7 +2632,7 @@ impl TranslatedDefinition {
                                         generic_parameters: None,
                                     }),
 
-                                    _ => todo!("get type of {container_type} member access expression: {expression:#?}"),
+                                    _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of {container_type} member access expression: {}", sway::TabbedDisplayer(expression)))),
                                 }
 
                                 ("I32", None) => match member_access.member.as_str() {
@@ -1822,7 +2641,7 @@ impl TranslatedDefinition {
                                         generic_parameters: None,
                                     }),
 
-                                    _ => todo!("get type of {container_type} member access expression: {expression:#?}"),
+                                    _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of {container_type} member access expression: {}", sway::TabbedDisplayer(expression)))),
                                 }
 
                                 ("I64", None) => match member_access.member.as_str() {
@@ -1831,16 +2650,16 @@ impl TranslatedDefinition {
                                         generic_parameters: None,
                                     }),
 
-                                    _ => todo!("get type of {container_type} member access expression: {expression:#?}"),
+                                    _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of {container_type} member access expression: {}", sway::TabbedDisplayer(expression)))),
                                 }
 
                                 ("I128", None) => match member_access.member.as_str() {
                                     "underlying" => Ok(sway::TypeName::Identifier {
-                                        name: "u128".into(),
+                                        name: "U128".into(),
                                         generic_parameters: None,
                                     }),
 
-                                    _ => todo!("get type of {container_type} member access expression: {expression:#?}"),
+                                    _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of {container_type} member access expression: {}", sway::TabbedDisplayer(expression)))),
                                 }
 
                                 ("I256", None) => match member_access.member.as_str() {
@@ -1849,7 +2668,7 @@ impl TranslatedDefinition {
                                         generic_parameters: None,
                                     }),
 
-                                    _ => todo!("get type of {container_type} member access expression: {expression:#?}"),
+                                    _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of {container_type} member access expression: {}", sway::TabbedDisplayer(expression)))),
                                 }
 
                                 _ => {
@@ -1860,11 +2679,11 @@ impl TranslatedDefinition {
                                         }
                                     }
 
-                                    todo!("get type of {container_type} member access expression: {expression:#?}")
+                                    Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of {container_type} member access expression: {}", sway::TabbedDisplayer(expression))))
                                 }
                             }
                             
-                            _ => todo!("get type of {container_type} member access expression: {expression:#?}"),
+                            _ => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of {container_type} member access expression: {}", sway::TabbedDisplayer(expression)))),
                         }
                     }
                 }
@@ -1878,7 +2697,7 @@ impl TranslatedDefinition {
                         sway::TypeName::Identifier { name, generic_parameters } => match (name.as_str(), generic_parameters.as_ref()) {
                             ("I256", None) => match member_access.member.as_str() {
                                 "underlying" => return Ok(sway::TypeName::Identifier {
-                                    name: "U256".into(),
+                                    name: "u256".into(),
                                     generic_parameters: None,
                                 }),
 
@@ -1894,13 +2713,13 @@ impl TranslatedDefinition {
                     // Check to see if container is a struct
                     if let Some(struct_definition) = self.structs.iter().find(|s| s.name == type_name_string) {
                         let Some(field) = struct_definition.fields.iter().find(|f| f.name == member_access.member) else {
-                            panic!("{type_name} does not contain a field named \"{}\" - member access expression: {:#?}", member_access.member, member_access.expression)
+                            return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("{type_name} does not contain a field named \"{}\"", member_access.member)))
                         };
 
                         return Ok(field.type_name.clone());
                     }
 
-                    todo!("get type of {type_name} member access expression: {:#?}", expression)
+                    Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of {type_name} member access expression: {}", sway::TabbedDisplayer(expression))))
                 }
             }
             
@@ -1946,9 +2765,10 @@ impl TranslatedDefinition {
             sway::Expression::Continue => Ok(sway::TypeName::Tuple { type_names: vec![] }),
             sway::Expression::Break => Ok(sway::TypeName::Tuple { type_names: vec![] }),
             
-            sway::Expression::AsmBlock(_) => todo!("get type of asm block: {expression:#?}"),
+            sway::Expression::AsmBlock(_) => Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, format!("get type of asm block: {}", sway::TabbedDisplayer(expression)))),
             
             sway::Expression::Commented(_, x) => self.get_expression_type(scope.clone(), x),
+            sway::Expression::Located(_, x) => self.get_expression_type(scope.clone(), x),
         }
     }
 }
@@ -1,19 +1,19 @@
 use super::{
-    create_value_expression, translate_assembly_statement, translate_assignment_expression,
-    translate_expression, translate_pre_or_post_operator_value_expression, translate_type_name,
-    TranslatedDefinition, TranslatedVariable, TranslationScope,
+    coerce_signed_expression, create_value_expression, resolve_expression_import_aliases, translate_assembly_statement, translate_assignment_expression,
+    translate_expression, translate_fixed_point_literal_expression, translate_function_value_expression, translate_pre_or_post_operator_value_expression,
+    translate_type_name, TranslatedDefinition, TranslatedVariable, TranslationScope,
 };
-use crate::{errors::Error, project::Project, sway, translate_naming_convention};
+use crate::{errors::Error, project::{get_comment_text, Project, SymbolKind}, report::ReportEntryKind, sway, translate_naming_convention};
 use convert_case::Case;
 use num_bigint::BigUint;
 use num_traits::{One, Zero};
-use solang_parser::pt as solidity;
-use std::{cell::RefCell, rc::Rc};
+use solang_parser::{helpers::CodeLocation, pt as solidity};
+use std::sync::{Arc, RwLock};
 
 pub fn translate_block(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     statements: &[solidity::Statement]
 ) -> Result<sway::Block, Error> {
     let mut block = sway::Block::default();
@@ -23,23 +23,26 @@ pub fn translate_block(
         // Translate the statement
         let sway_statement = translate_statement(project, translated_definition, scope.clone(), statement)?;
 
+        // Keep any comments preceding the statement
+        block.statements.extend(translate_statement_comments(project, translated_definition, statement));
+
         // Store the index of the sway statement
         let statement_index = block.statements.len();
 
         // Add the sway statement to the sway block
-        block.statements.push(sway_statement);
+        block.statements.push(create_located_statement(project, translated_definition, statement, sway_statement));
 
         // If the sway statement is a variable declaration, keep track of its statement index
-        if let Some(sway::Statement::Let(sway_variable)) = block.statements.last() {
+        if let Some(sway::Statement::Let(sway_variable)) = block.statements.last().map(sway::Statement::unlocated) {
             let store_variable_statement_index = |id: &sway::LetIdentifier| {
                 if id.name == "_" {
                     return;
                 }
 
-                let scope = scope.borrow();
+                let scope = scope.read().unwrap();
 
-                let scope_entry = scope.variables.iter().rev().find(|v| v.borrow().new_name == id.name).unwrap();
-                let mut scope_entry = scope_entry.borrow_mut();
+                let scope_entry = scope.variables.iter().rev().find(|v| v.read().unwrap().new_name == id.name).unwrap();
+                let mut scope_entry = scope_entry.write().unwrap();
 
                 scope_entry.statement_index = Some(statement_index);
             };
@@ -56,25 +59,51 @@ pub fn translate_block(
     Ok(block)
 }
 
+/// Translates the comments directly preceding the Solidity `statement` into commented statements.
+#[inline]
+pub fn translate_statement_comments(
+    project: &Project,
+    translated_definition: &TranslatedDefinition,
+    statement: &solidity::Statement,
+) -> Vec<sway::Statement> {
+    project.get_preceding_comments(&translated_definition.path, &statement.loc()).into_iter()
+        .map(|comment| sway::Statement::Commented(get_comment_text(comment).replace("*/", "* /"), None))
+        .collect()
+}
+
+/// Attaches the location of the Solidity `statement` to the translated `sway_statement` so it can be included in source maps.
+#[inline]
+pub fn create_located_statement(
+    project: &Project,
+    translated_definition: &TranslatedDefinition,
+    statement: &solidity::Statement,
+    sway_statement: sway::Statement,
+) -> sway::Statement {
+    match project.loc_to_source_location(&translated_definition.path, &statement.loc()) {
+        Some(location) => sway::Statement::Located(location, Box::new(sway_statement)),
+        None => sway_statement,
+    }
+}
+
 pub fn finalize_block_translation(
     _project: &mut Project,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     block: &mut sway::Block,
 ) -> Result<(), Error> {
     // Check the block for variable declarations that need to be marked mutable
-    for variable in scope.borrow().variables.iter() {
+    for variable in scope.read().unwrap().variables.iter() {
         // Only check variables that are declared as statements
-        let Some(statement_index) = variable.borrow().statement_index else { continue };
+        let Some(statement_index) = variable.read().unwrap().statement_index else { continue };
 
         // If the variable has any mutations, mark it as mutable
-        if variable.borrow().mutation_count > 0 {
-            let let_statement = match &mut block.statements[statement_index] {
+        if variable.read().unwrap().mutation_count > 0 {
+            let let_statement = match block.statements[statement_index].unlocated_mut() {
                 sway::Statement::Let(let_statement) => let_statement,
-                statement => panic!("Expected let statement, found: {} - {statement:?}", sway::TabbedDisplayer(statement)),
+                statement => return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Expected let statement, found: {}", sway::TabbedDisplayer(statement)))),
             };
 
             let mark_let_identifier_mutable = |id: &mut sway::LetIdentifier| {
-                if id.name == variable.borrow().new_name {
+                if id.name == variable.read().unwrap().new_name {
                     id.is_mutable = true;
                 }
             };
@@ -91,16 +120,16 @@ pub fn finalize_block_translation(
         let mut statements = None;
 
         {
-            let sway::Statement::Expression(sway::Expression::Block(sub_block)) = &block.statements[i] else { continue };
+            let sway::Statement::Expression(sway::Expression::Block(sub_block)) = block.statements[i].unlocated() else { continue };
             
             let mut var_count = 0;
 
             for statement in sub_block.statements.iter() {
-                let sway::Statement::Let(sway::Let { pattern, .. }) = statement else { continue };
+                let sway::Statement::Let(sway::Let { pattern, .. }) = statement.unlocated() else { continue };
 
                 let mut check_let_identifier = |identifier: &sway::LetIdentifier| {
-                    if let Some(scope) = scope.borrow().parent.as_ref() {
-                        if scope.borrow().get_variable_from_new_name(&identifier.name).is_some() {
+                    if let Some(scope) = scope.read().unwrap().parent.as_ref() {
+                        if scope.read().unwrap().get_variable_from_new_name(&identifier.name).is_some() {
                             var_count += 1;
                         }
                     }
@@ -134,7 +163,7 @@ pub fn finalize_block_translation(
     }
 
     // If the last statement is a block, flatten it
-    if let Some(sway::Statement::Expression(sway::Expression::Block(inner_block))) = block.statements.last().cloned() {
+    if let Some(sway::Statement::Expression(sway::Expression::Block(inner_block))) = block.statements.last().map(sway::Statement::unlocated).cloned() {
         block.statements.pop();
         block.statements.extend(inner_block.statements);
     }
@@ -145,11 +174,11 @@ pub fn finalize_block_translation(
 pub fn translate_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     statement: &solidity::Statement
 ) -> Result<sway::Statement, Error> {
-    match statement {
-        solidity::Statement::Block { statements, .. } => translate_block_statement(project, translated_definition, scope.clone(), statements),
+    let result = match statement {
+        solidity::Statement::Block { unchecked, statements, .. } => translate_block_statement(project, translated_definition, scope.clone(), *unchecked, statements),
         solidity::Statement::Assembly { dialect, flags, block, .. } => translate_assembly_statement(project, translated_definition, scope.clone(), dialect, flags, block),
         solidity::Statement::Args(_, named_arguments) => translate_args_statement(project, translated_definition, scope.clone(), named_arguments),
         solidity::Statement::If(_, condition, then_body, else_if) => translate_if_statement(project, translated_definition, scope.clone(), condition, then_body, else_if),
@@ -169,14 +198,18 @@ pub fn translate_statement(
 
         solidity::Statement::Emit(_, expression) => translate_emit_statement(project, translated_definition, scope.clone(), expression),
         solidity::Statement::Try(_, expr, params_and_body, catch_clauses) => translate_try_catch_statement(project, translated_definition, scope, expr, params_and_body, catch_clauses),
-        solidity::Statement::Error(_) => panic!("Encountered a statement that was not parsed correctly"),
-    }
+        solidity::Statement::Error(_) => Err(Error::InvalidConstruct(statement.loc(), "Encountered a statement that was not parsed correctly".into())),
+    };
+
+    // Attach the location of the statement to any errors that don't have one
+    result.map_err(|e| e.with_loc(&statement.loc()))
 }
+
 #[allow(clippy::type_complexity)]
 pub fn translate_try_catch_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expr: &solidity::Expression,
     params_and_body:  &Option<(Vec<(solidity::Loc, Option<solidity::Parameter>)>, Box<solidity::Statement>)>,
     catch_clauses: &[solidity::CatchClause],
@@ -202,24 +235,24 @@ pub fn translate_try_catch_statement(
                 };
                 let store_let_identifier = |id: &sway::LetIdentifier, type_name: &sway::TypeName| {
                     
-                    let variable = Rc::new(RefCell::new(TranslatedVariable {
+                    let variable = Arc::new(RwLock::new(TranslatedVariable {
                         old_name: id.name.clone(),
                         new_name: id.name.clone(),
                         type_name: type_name.clone(),
                         ..Default::default()
                     }));
-                    scope.borrow_mut().variables.push(variable);
+                    scope.write().unwrap().variables.push(variable);
                     
-                    let variable = scope.borrow().get_variable_from_new_name(&id.name).unwrap();
-                    variable.borrow_mut().statement_index = Some(statements.len());
+                    let variable = scope.read().unwrap().get_variable_from_new_name(&id.name).unwrap();
+                    variable.write().unwrap().statement_index = Some(statements.len());
                 };
                 match &let_statement.pattern {
                     sway::LetPattern::Identifier(id) => {
-                        let type_name = translate_type_name(project, translated_definition, &params[0].1.as_ref().unwrap().ty, false, false);
+                        let type_name = translate_type_name(project, translated_definition, &params[0].1.as_ref().unwrap().ty, false, false)?;
                         store_let_identifier(id, &type_name);
                     },
                     sway::LetPattern::Tuple(ids) => {
-                        let type_names = params.iter().map(|(_, p)| translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, false)).collect::<Vec<_>>();
+                        let type_names = params.iter().map(|(_, p)| translate_type_name(project, translated_definition, &p.as_ref().unwrap().ty, false, false)).collect::<Result<Vec<_>, _>>()?;
                         ids.iter().zip(type_names.iter()).for_each(|(id, type_name)| store_let_identifier(id, type_name));
                     }
                 }
@@ -238,10 +271,11 @@ pub fn translate_try_catch_statement(
             };
             
         },
-        None => todo!(),
+        None => return Err(Error::UnsupportedConstruct(expr.loc(), "try statement without returns clause".into())),
     }
     
     for cc in catch_clauses {
+        project.report_unsupported(translated_definition, &cc.loc(), ReportEntryKind::Unsupported, cc);
         statements.push(sway::Statement::Commented(format!("unsupported: {cc}"), None));
     }
     
@@ -255,11 +289,13 @@ pub fn translate_try_catch_statement(
 pub fn translate_block_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
+    unchecked: bool,
     statements: &[solidity::Statement],
 ) -> Result<sway::Statement, Error> {
-    let scope = Rc::new(RefCell::new(TranslationScope {
+    let scope = Arc::new(RwLock::new(TranslationScope {
         parent: Some(scope.clone()),
+        unchecked,
         ..Default::default()
     }));
 
@@ -275,17 +311,17 @@ pub fn translate_block_statement(
 pub fn translate_args_statement(
     _project: &mut Project,
     _translated_definition: &mut TranslatedDefinition,
-    _scope: Rc<RefCell<TranslationScope>>,
+    _scope: Arc<RwLock<TranslationScope>>,
     _named_arguments: &[solidity::NamedArgument],
 ) -> Result<sway::Statement, Error> {
-    todo!("translate args statement")
+    Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, "translate args statement".into()))
 }
 
 #[inline]
 pub fn translate_if_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     condition: &solidity::Expression,
     then_body: &solidity::Statement,
     else_if: &Option<Box<solidity::Statement>>,
@@ -333,7 +369,7 @@ pub fn translate_if_statement(
 pub fn translate_while_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     condition: &solidity::Expression,
     body: &solidity::Statement,
 ) -> Result<sway::Statement, Error> {
@@ -353,7 +389,7 @@ pub fn translate_while_statement(
 pub fn translate_expression_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Statement, Error> {
     match expression {
@@ -380,15 +416,15 @@ pub fn translate_expression_statement(
                     let Some(p) = p.as_ref() else { continue };
                     let Some(name) = p.name.as_ref() else { continue };
 
-                    variables.push(Rc::new(RefCell::new(TranslatedVariable {
+                    variables.push(Arc::new(RwLock::new(TranslatedVariable {
                         old_name: name.name.clone(),
                         new_name: crate::translate_naming_convention(name.name.as_str(), Case::Snake),
-                        type_name: translate_type_name(project, translated_definition, &p.ty, false, false),
+                        type_name: translate_type_name(project, translated_definition, &p.ty, false, false)?,
                         ..Default::default()
                     })));
                 }
 
-                scope.borrow_mut().variables.extend(variables);
+                scope.write().unwrap().variables.extend(variables);
 
                 // Create the variable declaration statement
                 return Ok(sway::Statement::from(sway::Let {
@@ -415,13 +451,13 @@ pub fn translate_expression_statement(
                                 if let Some(p) = p.as_ref() {
                                     translate_type_name(project, translated_definition, &p.ty, false, false)
                                 } else {
-                                    sway::TypeName::Identifier {
+                                    Ok(sway::TypeName::Identifier {
                                         name: "_".into(),
                                         generic_parameters: None,
-                                    }
+                                    })
                                 }
                             })
-                            .collect(),
+                            .collect::<Result<Vec<_>, _>>()?,
                     }),
                     
                     value: translate_expression(project, translated_definition, scope.clone(), rhs.as_ref())?,
@@ -467,18 +503,18 @@ pub fn translate_expression_statement(
 pub fn translate_variable_definition_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     variable_declaration: &solidity::VariableDeclaration,
     initializer: &Option<solidity::Expression>,
 ) -> Result<sway::Statement, Error> {
     let old_name = variable_declaration.name.as_ref().unwrap().name.clone();
     let new_name = crate::translate_naming_convention(old_name.as_str(), Case::Snake);
-    let mut type_name = translate_type_name(project, translated_definition, &variable_declaration.ty, false, false);
+    let mut type_name = translate_type_name(project, translated_definition, &variable_declaration.ty, false, false)?;
     let mut abi_type_name = None;
 
     // Check if the parameter's type is an ABI
     if let sway::TypeName::Identifier { name, generic_parameters: None } = &type_name {
-        if project.find_definition_with_abi(name.as_str()).is_some() {
+        if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_some() {
             abi_type_name = Some(type_name.clone());
 
             type_name = sway::TypeName::Identifier {
@@ -492,13 +528,13 @@ pub fn translate_variable_definition_statement(
 
     if let Some(solidity::Expression::New(_, new_expression)) = initializer.as_ref() {
         let solidity::Expression::FunctionCall(_, ty, args) = new_expression.as_ref() else {
-            panic!("Unexpected new expression: {} - {new_expression:#?}", new_expression);
+            return Err(Error::InvalidConstruct(variable_declaration.loc, format!("Unexpected new expression: {new_expression}")));
         };
 
-        let new_type_name = translate_type_name(project, translated_definition, ty, false, false);
+        let new_type_name = translate_type_name(project, translated_definition, ty, false, false)?;
 
         if type_name != new_type_name {
-            panic!("Invalid new expression type name: expected `{type_name}`, found `{new_type_name}`");
+            return Err(Error::InvalidConstruct(variable_declaration.loc, format!("Invalid new expression type name: expected `{type_name}`, found `{new_type_name}`")));
         }
 
         match &type_name {
@@ -514,7 +550,7 @@ pub fn translate_variable_definition_statement(
                 // }
 
                 if args.len() != 1 {
-                    panic!("Invalid new array expression: expected 1 argument, found {}", args.len());
+                    return Err(Error::InvalidConstruct(variable_declaration.loc, format!("Invalid new array expression: expected 1 argument, found {}", args.len())));
                 }
 
                 let element_type_name = &generic_parameters.entries.first().unwrap().type_name;
@@ -570,7 +606,7 @@ pub fn translate_variable_definition_statement(
                                         }),
                                         generic_parameters: None,
                                         parameters: vec![
-                                            create_value_expression(translated_definition, scope.clone(), element_type_name, None),
+                                            create_value_expression(translated_definition, scope.clone(), element_type_name, None)?,
                                         ],
                                     })),
 
@@ -606,13 +642,21 @@ pub fn translate_variable_definition_statement(
         value: if let Some(value) = value {
             value
         } else if let Some(x) = initializer.as_ref() {
-            translate_pre_or_post_operator_value_expression(project, translated_definition, scope.clone(), x)?
+            // Overloaded internal functions are resolved by the function type of the variable
+            if let Some(value) = translate_function_value_expression(translated_definition, scope.clone(), x, Some(&type_name))? {
+                value
+            } else if let Some(value) = translate_fixed_point_literal_expression(translated_definition, &type_name, x)? {
+                value
+            } else {
+                let value = translate_pre_or_post_operator_value_expression(project, translated_definition, scope.clone(), x)?;
+                coerce_signed_expression(translated_definition, scope.clone(), &type_name, value)
+            }
         } else {
-            create_value_expression(translated_definition, scope.clone(), &type_name, None)
+            create_value_expression(translated_definition, scope.clone(), &type_name, None)?
         },
     });
 
-    scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+    scope.write().unwrap().variables.push(Arc::new(RwLock::new(TranslatedVariable {
         old_name,
         new_name,
         type_name,
@@ -627,7 +671,7 @@ pub fn translate_variable_definition_statement(
 pub fn translate_for_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     initialization: &Option<Box<solidity::Statement>>,
     condition: &Option<Box<solidity::Expression>>,
     update: &Option<Box<solidity::Expression>>,
@@ -642,7 +686,7 @@ pub fn translate_for_statement(
     // }
 
     // Create a scope for the block that will contain the for loop logic
-    let scope = Rc::new(RefCell::new(TranslationScope {
+    let scope = Arc::new(RwLock::new(TranslationScope {
         parent: Some(scope.clone()),
         ..Default::default()
     }));
@@ -657,17 +701,18 @@ pub fn translate_for_statement(
 
         // Store the statement index of variable declaration statements in their scope entries
         if let sway::Statement::Let(sway::Let { pattern, .. }) = &mut statement {
-            let store_let_identifier_statement_index = |id: &mut sway::LetIdentifier| {
-                let Some(variable) = scope.borrow().get_variable_from_new_name(&id.name) else {
-                    panic!("error: Variable not found in scope: \"{}\"", id.name);
+            let store_let_identifier_statement_index = |id: &mut sway::LetIdentifier| -> Result<(), Error> {
+                let Some(variable) = scope.read().unwrap().get_variable_from_new_name(&id.name) else {
+                    return Err(Error::UnresolvedReference(solidity::Loc::Implicit, format!("Variable not found in scope: \"{}\"", id.name)));
                 };
                 
-                variable.borrow_mut().statement_index = Some(statement_index);
+                variable.write().unwrap().statement_index = Some(statement_index);
+                Ok(())
             };
 
             match pattern {
-                sway::LetPattern::Identifier(id) => store_let_identifier_statement_index(id),
-                sway::LetPattern::Tuple(ids) => ids.iter_mut().for_each(store_let_identifier_statement_index),
+                sway::LetPattern::Identifier(id) => store_let_identifier_statement_index(id)?,
+                sway::LetPattern::Tuple(ids) => ids.iter_mut().try_for_each(store_let_identifier_statement_index)?,
             }
         }
 
@@ -754,7 +799,7 @@ pub fn translate_for_statement(
 pub fn translate_do_while_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     body: &solidity::Statement,
     condition: &solidity::Expression,
 ) -> Result<sway::Statement, Error> {
@@ -792,31 +837,50 @@ pub fn translate_do_while_statement(
 pub fn translate_return_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &Option<solidity::Expression>,
 ) -> Result<sway::Statement, Error> {
-    Ok(sway::Statement::from(sway::Expression::Return(
-        if let Some(x) = expression.as_ref() {
-            Some(Box::new(
-                translate_expression(project, translated_definition, scope.clone(), x)?
-            ))
-        } else {
-            None
+    let Some(x) = expression.as_ref() else {
+        return Ok(sway::Statement::from(sway::Expression::Return(None)));
+    };
+
+    let mut value = translate_expression(project, translated_definition, scope.clone(), x)?;
+
+    // Convert returned literals and smaller signed integers to the signed return types of the function
+    let return_type = scope.read().unwrap().get_return_type();
+
+    match (return_type, &mut value) {
+        (Some(sway::TypeName::Tuple { type_names }), sway::Expression::Tuple(values)) if type_names.len() == values.len() => {
+            for (type_name, value) in type_names.iter().zip(values.iter_mut()) {
+                *value = coerce_signed_expression(translated_definition, scope.clone(), type_name, value.clone());
+            }
         }
-    )))
+
+        (Some(type_name), _) => value = coerce_signed_expression(translated_definition, scope.clone(), &type_name, value),
+
+        _ => {}
+    }
+
+    Ok(sway::Statement::from(sway::Expression::Return(Some(Box::new(value)))))
 }
 
 #[inline]
 pub fn translate_revert_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     error_type: &Option<solidity::IdentifierPath>,
     parameters: &[solidity::Expression],
 ) -> Result<sway::Statement, Error> {
     if let Some(error_type) = error_type.as_ref() {
+        // Resolve the import aliases of the error type (if any)
+        let error_type = &solidity::IdentifierPath {
+            loc: error_type.loc,
+            identifiers: project.resolve_import_aliases(&translated_definition.path, &error_type.identifiers).unwrap_or_else(|| error_type.identifiers.clone()),
+        };
+
         if !(1..=2).contains(&error_type.identifiers.len()) {
-            panic!("Unexpected error type: expected 1 or 2 identifiers, found {}", error_type.identifiers.len());
+            return Err(Error::InvalidConstruct(solidity::Loc::Implicit, format!("Unexpected error type: expected 1 or 2 identifiers, found {}", error_type.identifiers.len())));
         }
 
         let mut ids_iter = error_type.identifiers.iter();
@@ -825,17 +889,26 @@ pub fn translate_revert_statement(
         let (error_variant_name, errors_enum_and_impl) = if error_type.identifiers.len() == 2 {
             let external_definition_name = ids_iter.next().unwrap().name.clone();
             let error_variant_name = ids_iter.next().unwrap().name.clone();
-            let external_definition = project.translated_definitions.iter_mut().find(|d| d.name == external_definition_name).unwrap();
+
+            let Some(external_definition) = project.find_symbol(&translated_definition.path, SymbolKind::Error, &error_variant_name, |d| d.name == external_definition_name) else {
+                return Err(Error::UnresolvedReference(error_type.loc, format!("Failed to find error \"{error_variant_name}\" in \"{external_definition_name}\"")));
+            };
+
             let errors_enum_and_impl = external_definition.errors_enums.iter().find(|(e, _)| e.variants.iter().any(|v| v.name == error_variant_name)).cloned().unwrap();
             (error_variant_name, errors_enum_and_impl)
         } else {
             let error_variant_name = ids_iter.next().unwrap().name.clone();
-            let errors_enum_and_impl = translated_definition.errors_enums.iter().find(|(e, _)| e.variants.iter().any(|v| v.name == error_variant_name)).cloned().unwrap();
+
+            let Some(errors_enum_and_impl) = translated_definition.errors_enums.iter().find(|(e, _)| e.variants.iter().any(|v| v.name == error_variant_name)).cloned() else {
+                return Err(Error::UnresolvedReference(error_type.loc, format!("Failed to find error \"{error_variant_name}\" in \"{}\"", translated_definition.name)));
+            };
+
             (error_variant_name, errors_enum_and_impl)
         };
 
         // Add the error definition to the current definition if we haven't already
         if !translated_definition.errors_enums.contains(&errors_enum_and_impl) {
+            translated_definition.ensure_use_declared("core::codec::AbiEncode");
             translated_definition.errors_enums.push(errors_enum_and_impl.clone());
         }
 
@@ -863,15 +936,7 @@ pub fn translate_revert_statement(
                                 )),
                                 generic_parameters: None,
                                 parameters: vec![
-                                    if parameters.len() == 1 {
-                                        translate_expression(project, translated_definition, scope.clone(), &parameters[0])?
-                                    } else {
-                                        sway::Expression::Tuple(
-                                            parameters.iter()
-                                                .map(|p| translate_expression(project, translated_definition, scope.clone(), p))
-                                                .collect::<Result<Vec<_>, _>>()?
-                                        )
-                                    },
+                                    translate_enum_variant_arguments(project, translated_definition, scope.clone(), &errors_enum, &error_variant_name, parameters)?,
                                 ]
                             })
                         },
@@ -926,24 +991,57 @@ pub fn translate_revert_statement(
         })));
     }
 
-    todo!("translate revert statement")
+    Err(Error::UnsupportedConstruct(solidity::Loc::Implicit, "translate revert statement".into()))
 }
 
 #[inline]
 pub fn translate_emit_statement(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     expression: &solidity::Expression,
 ) -> Result<sway::Statement, Error> {
-    
+    // Translate the event without its import aliases (if any)
+    if let Some(expression) = resolve_expression_import_aliases(project, translated_definition, expression) {
+        return translate_emit_statement(project, translated_definition, scope, &expression);
+    }
+
     if let solidity::Expression::FunctionCall(_, x, parameters) = expression {
-        if let solidity::Expression::Variable(solidity::Identifier { name: event_variant_name, .. }) = x.as_ref() {
-            // Find the events enum containing the variant
-            let Some((events_enum, _)) = translated_definition.events_enums.iter().find(|(e, _)| e.variants.iter().any(|v| v.name == *event_variant_name)) else {
-                panic!("Failed to find event variant \"{event_variant_name}\" in \"{}\": {:#?}", translated_definition.name, translated_definition.events_enums);
-            };
-            
+        let events_enum = match x.as_ref() {
+            solidity::Expression::Variable(solidity::Identifier { name: event_variant_name, .. }) => {
+                // Find the events enum containing the variant
+                let Some((events_enum, _)) = translated_definition.events_enums.iter().find(|(e, _)| e.variants.iter().any(|v| v.name == *event_variant_name)) else {
+                    return Err(Error::UnresolvedReference(expression.loc(), format!("Failed to find event variant \"{event_variant_name}\" in \"{}\"", translated_definition.name)));
+                };
+
+                Some((event_variant_name, events_enum.clone()))
+            }
+
+            solidity::Expression::MemberAccess(_, container, member) => match container.as_ref() {
+                // Find the events enum of the external definition containing the variant
+                solidity::Expression::Variable(solidity::Identifier { name: external_definition_name, .. }) => {
+                    let Some(external_definition) = project.find_symbol(&translated_definition.path, SymbolKind::Event, &member.name, |d| d.name == *external_definition_name) else {
+                        return Err(Error::UnresolvedReference(expression.loc(), format!("Failed to find event variant \"{}\" in \"{external_definition_name}\"", member.name)));
+                    };
+
+                    let events_enum_and_impl = external_definition.events_enums.iter().find(|(e, _)| e.variants.iter().any(|v| v.name == member.name)).cloned().unwrap();
+
+                    // Add the event definition to the current definition if we haven't already
+                    if !translated_definition.events_enums.contains(&events_enum_and_impl) {
+                        translated_definition.ensure_use_declared("core::codec::AbiEncode");
+                        translated_definition.events_enums.push(events_enum_and_impl.clone());
+                    }
+
+                    Some((&member.name, events_enum_and_impl.0))
+                }
+
+                _ => None,
+            }
+
+            _ => None,
+        };
+
+        if let Some((event_variant_name, events_enum)) = events_enum {
             return Ok(sway::Statement::from(sway::Expression::from(sway::FunctionCall {
                 function: sway::Expression::Identifier("log".into()),
                 generic_parameters: None,
@@ -963,15 +1061,7 @@ pub fn translate_emit_statement(
                             )),
                             generic_parameters: None,
                             parameters: vec![
-                                if parameters.len() == 1 {
-                                    translate_expression(project, translated_definition, scope.clone(), &parameters[0])?
-                                } else {
-                                    sway::Expression::Tuple(
-                                        parameters.iter()
-                                            .map(|p| translate_expression(project, translated_definition, scope.clone(), p))
-                                            .collect::<Result<Vec<_>, _>>()?
-                                    )
-                                },
+                                translate_enum_variant_arguments(project, translated_definition, scope.clone(), &events_enum, event_variant_name, parameters)?,
                             ]
                         })
                     },
@@ -981,14 +1071,49 @@ pub fn translate_emit_statement(
     }
             
 
-    todo!("translate emit statement")
+    Err(Error::UnsupportedConstruct(expression.loc(), "translate emit statement".into()))
+}
+
+/// Translates the arguments of an event or error variant, converting literals and smaller signed integers to the signed types of its fields.
+fn translate_enum_variant_arguments(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    scope: Arc<RwLock<TranslationScope>>,
+    enum_definition: &sway::Enum,
+    variant_name: &str,
+    parameters: &[solidity::Expression],
+) -> Result<sway::Expression, Error> {
+    let variant_type_name = enum_definition.variants.iter().find(|v| v.name == variant_name).map(|v| v.type_name.clone());
+
+    let mut values = vec![];
+
+    for (i, parameter) in parameters.iter().enumerate() {
+        let value = translate_expression(project, translated_definition, scope.clone(), parameter)?;
+
+        let type_name = match variant_type_name.as_ref() {
+            Some(sway::TypeName::Tuple { type_names }) if parameters.len() > 1 => type_names.get(i),
+            Some(type_name) if parameters.len() == 1 => Some(type_name),
+            _ => None,
+        };
+
+        values.push(match type_name {
+            Some(type_name) => coerce_signed_expression(translated_definition, scope.clone(), type_name, value),
+            None => value,
+        });
+    }
+
+    if values.len() == 1 {
+        Ok(values.remove(0))
+    } else {
+        Ok(sway::Expression::Tuple(values))
+    }
 }
 
 #[inline]
 pub fn translate_revert_named_arguments(
     project: &mut Project,
     translated_definition: &mut TranslatedDefinition,
-    scope: Rc<RefCell<TranslationScope>>,
+    scope: Arc<RwLock<TranslationScope>>,
     path: &Option<solidity::IdentifierPath>,
     named_args: &[solidity::NamedArgument]
 ) -> Result<sway::Statement, Error> {
@@ -1001,5 +1126,5 @@ pub fn translate_revert_named_arguments(
         return translate_revert_statement(project, translated_definition, scope, path, &error_expressions)
     }
 
-    todo!("translate revert named arguments : {:#?}", path)
+    Err(Error::UnsupportedConstruct(path.as_ref().unwrap().loc, format!("translate revert named arguments: {}", path.as_ref().unwrap())))
 }
\ No newline at end of file
@@ -1,11 +1,11 @@
 use super::{
-    create_value_expression, translate_expression, translate_type_name, DeferredInitialization,
-    TranslatedDefinition, TranslatedFunction, TranslatedVariable, TranslationScope,
+    create_value_expression, translate_expression, translate_fixed_point_literal_expression, translate_function_value_expression, translate_type_name,
+    DeferredInitialization, TranslatedDefinition, TranslatedFunction, TranslatedVariable, TranslationScope,
 };
 use crate::{project::Project, sway, Error};
 use convert_case::Case;
 use solang_parser::pt as solidity;
-use std::{cell::RefCell, rc::Rc};
+use std::sync::{Arc, RwLock};
 
 #[inline]
 pub fn translate_storage_name(
@@ -55,17 +55,17 @@ pub fn translate_state_variable(
     };
 
     // Translate the variable's type name
-    let mut variable_type_name = translate_type_name(project, translated_definition, &variable_definition.ty, is_storage, false);
+    let mut variable_type_name = translate_type_name(project, translated_definition, &variable_definition.ty, is_storage, false)?;
     let mut abi_type_name = None;
 
     // Check if the variable's type is an ABI
     if let sway::TypeName::Identifier { name, generic_parameters: None } = &variable_type_name {
         // Check if type is a contract that hasn't been defined yet
-        if project.find_definition_with_abi(name.as_str()).is_none() && translated_definition.contract_names.iter().any(|n| n == name) {
+        if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_none() && translated_definition.contract_names.iter().any(|n| n == name) {
             project.translate(Some(name), &translated_definition.path).unwrap();
         }
 
-        if project.find_definition_with_abi(name.as_str()).is_some() {
+        if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_some() {
             abi_type_name = Some(variable_type_name.clone());
 
             variable_type_name = sway::TypeName::Identifier {
@@ -80,7 +80,7 @@ pub fn translate_state_variable(
     //
 
     // Translate the variable's initial value
-    let value_scope = Rc::new(RefCell::new(TranslationScope {
+    let value_scope = Arc::new(RwLock::new(TranslationScope {
         parent: Some(translated_definition.toplevel_scope.clone()),
         ..Default::default()
     }));
@@ -130,29 +130,43 @@ pub fn translate_state_variable(
                     })
                     .transpose()?;
                 
-                create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, initializer.as_ref())
+                create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, initializer.as_ref())?
             }
 
             _ => {
                 let initializer = variable_definition.initializer.as_ref()
-                    .map(|x| translate_expression(project, translated_definition, value_scope.clone(), x))
+                    .map(|x| {
+                        // Overloaded internal functions are resolved by the function type of the variable
+                        if let Some(value) = translate_function_value_expression(translated_definition, value_scope.clone(), x, Some(&variable_type_name))? {
+                            return Ok(value);
+                        }
+
+                        match translate_fixed_point_literal_expression(translated_definition, &variable_type_name, x)? {
+                            Some(value) => Ok(value),
+                            None => translate_expression(project, translated_definition, value_scope.clone(), x),
+                        }
+                    })
                     .transpose()?;
 
-                create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, initializer.as_ref())
+                create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, initializer.as_ref())?
             }
         }
 
         _ => if let Some(x) = variable_definition.initializer.as_ref() {
             let value = translate_expression(project, translated_definition, value_scope.clone(), x)?;
-            create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, Some(&value))
+            create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, Some(&value))?
         } else {
-            create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, None)
+            create_value_expression(translated_definition, value_scope.clone(), &variable_type_name, None)?
         },
     };
 
+    // Get the NatSpec doc comment of the variable definition (if any)
+    let doc_comment = project.get_doc_comment(&translated_definition.path, &variable_definition.loc);
+
     // Handle constant variable definitions
     if is_constant {
         translated_definition.constants.push(sway::Constant {
+            doc_comment: doc_comment.clone(),
             is_public,
             name: new_name.clone(),
             type_name: variable_type_name.clone(),
@@ -166,6 +180,7 @@ pub fn translate_state_variable(
         //
 
         translated_definition.get_configurable().fields.push(sway::ConfigurableField {
+            doc_comment: doc_comment.clone(),
             name: new_name.clone(),
             type_name: variable_type_name.clone(),
             value,
@@ -174,6 +189,7 @@ pub fn translate_state_variable(
     // Handle regular state variable definitions
     else {
         translated_definition.get_storage().fields.push(sway::StorageField {
+            doc_comment: doc_comment.clone(),
             name: new_name.clone(),
             type_name: variable_type_name.clone(),
             value,
@@ -181,7 +197,7 @@ pub fn translate_state_variable(
     }
     
     // Add the storage variable for function scopes
-    translated_definition.toplevel_scope.borrow_mut().variables.push(Rc::new(RefCell::new(TranslatedVariable {
+    translated_definition.toplevel_scope.write().unwrap().variables.push(Arc::new(RwLock::new(TranslatedVariable {
         old_name: old_name.clone(),
         new_name: new_name.clone(),
         type_name: variable_type_name.clone(),
@@ -220,6 +236,7 @@ pub fn translate_state_variable(
 
     // Create the function declaration for the abi
     let mut sway_function = sway::Function {
+        doc_comment,
         attributes: if is_storage {
             Some(sway::AttributeList {
                 attributes: vec![
@@ -242,11 +259,12 @@ pub fn translate_state_variable(
         },
         return_type: Some(return_type),
         body: None,
+        location: project.loc_to_source_location(&translated_definition.path, &variable_definition.loc),
     };
 
     if let Some(abi) = translated_definition.abi.as_mut() {
         // Only add the function to the abi if it doesn't already exist
-        if !abi.functions.contains(&sway_function) {
+        if !abi.functions.iter().any(|f| f.has_same_signature(&sway_function)) {
             abi.functions.push(sway_function.clone());
         }
     } else {
@@ -255,7 +273,7 @@ pub fn translate_state_variable(
     }
 
     // Add the toplevel function to the scope
-    translated_definition.toplevel_scope.borrow_mut().functions.push(Rc::new(RefCell::new(TranslatedFunction {
+    translated_definition.toplevel_scope.write().unwrap().functions.push(Arc::new(RwLock::new(TranslatedFunction {
         old_name: old_name.clone(),
         new_name: new_name.clone(),
         parameters: sway_function.parameters.clone(),
@@ -308,7 +326,7 @@ pub fn translate_state_variable(
         } else if is_constant || is_immutable {
             sway::Expression::Identifier(new_name.clone())
         } else {
-            todo!("Handle getter function for non-storage variables: {} - {variable_definition:#?}", variable_definition.to_string())
+            return Err(Error::UnsupportedConstruct(variable_definition.loc, format!("Handle getter function for non-storage variables: {variable_definition}")))
         }),
     });
 
@@ -330,7 +348,7 @@ pub fn translate_state_variable(
 
     if let Some(contract_impl) = translated_definition.find_contract_impl_mut() {
         // Only add the function wrapper to the contract impl if it doesn't already exist
-        if !contract_impl.items.contains(&impl_item) {
+        if !contract_impl.items.iter().any(|i| i.has_same_signature(&impl_item)) {
             contract_impl.items.push(impl_item);
         }
     } else {
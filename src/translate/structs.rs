@@ -10,17 +10,18 @@ pub fn translate_struct_definition(
     struct_definition: &solidity::StructDefinition,
 ) -> Result<(), Error> {
     let struct_definition = sway::Struct {
+        doc_comment: project.get_doc_comment(&translated_definition.path, &struct_definition.loc),
         attributes: None,
         is_public: false,
         name: struct_definition.name.as_ref().unwrap().name.clone(),
         generic_parameters: None,
         fields: struct_definition.fields.iter().map(|f| {
-            sway::StructField {
+            Ok(sway::StructField {
                 is_public: false,
                 name: crate::translate_naming_convention(f.name.as_ref().unwrap().name.as_str(), Case::Snake), // TODO: keep track of original name
-                type_name: translate_type_name(project, translated_definition, &f.ty, false, false),
-            }
-        }).collect(),
+                type_name: translate_type_name(project, translated_definition, &f.ty, false, false)?,
+            })
+        }).collect::<Result<Vec<_>, Error>>()?,
     };
 
     translated_definition.structs.push(struct_definition);
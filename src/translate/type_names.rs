@@ -1,7 +1,7 @@
-use super::{translate_expression, TranslatedDefinition, TranslationScope};
-use crate::{project::Project, sway};
-use solang_parser::pt as solidity;
-use std::{cell::RefCell, rc::Rc};
+use super::{resolve_expression_import_aliases, translate_expression, TranslatedDefinition, TranslationScope};
+use crate::{errors::Error, project::{Project, SymbolKind}, sway};
+use solang_parser::{helpers::CodeLocation, pt as solidity};
+use std::sync::{Arc, RwLock};
 
 #[inline]
 pub fn translate_return_type_name(
@@ -23,7 +23,7 @@ pub fn translate_return_type_name(
         _ => {
             // Check if the parameter's type is an ABI and make it an Identity
             if let sway::TypeName::Identifier { name, generic_parameters: None } = &type_name {
-                if project.find_definition_with_abi(name.as_str()).is_some() {
+                if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_some() {
                     return sway::TypeName::Identifier {
                         name: "Identity".into(),
                         generic_parameters: None,
@@ -42,8 +42,13 @@ pub fn translate_type_name(
     type_name: &solidity::Expression,
     is_storage: bool,
     is_parameter: bool,
-) -> sway::TypeName {
-    match type_name {
+) -> Result<sway::TypeName, Error> {
+    // Translate the type name without its import aliases (if any)
+    if let Some(type_name) = resolve_expression_import_aliases(project, translated_definition, type_name) {
+        return translate_type_name(project, translated_definition, &type_name, is_storage, is_parameter);
+    }
+
+    Ok(match type_name {
         solidity::Expression::Type(_, type_expression) => match type_expression {
             solidity::Type::Address => sway::TypeName::Identifier {
                 name: "Identity".into(),
@@ -56,7 +61,7 @@ pub fn translate_type_name(
                 generic_parameters: None,
             },
 
-            solidity::Type::Payable => todo!("payable types (used for casting)"),
+            solidity::Type::Payable => return Err(Error::UnsupportedConstruct(type_name.loc(), "payable types (used for casting)".into())),
             
             solidity::Type::Bool => sway::TypeName::Identifier {
                 name: "bool".into(),
@@ -80,91 +85,34 @@ pub fn translate_type_name(
                     "signed_integers = { git = \"https://github.com/fuellabs/sway-libs\", branch = \"master\" }"
                 );
 
-                sway::TypeName::Identifier {
-                    name: match *bits {
-                        0..=8 => {
-                            if *bits != 8 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I8`...");
-                            }
-                            translated_definition.ensure_use_declared("signed_integers::i8::*");
-                            "I8".into()
-                        }
-                        9..=16 => {
-                            if *bits != 16 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I16`...");
-                            }
-                            translated_definition.ensure_use_declared("signed_integers::i16::*");
-                            "I16".into()
-                        }
-                        17..=32 => {
-                            if *bits != 32 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I32`...");
-                            }
-                            translated_definition.ensure_use_declared("signed_integers::i32::*");
-                            "I32".into()
-                        }
-                        33..=64 => {
-                            if *bits != 64 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I64`...");
-                            }
-                            translated_definition.ensure_use_declared("signed_integers::i64::*");
-                            "I64".into()
-                        }
-                        65..=128 => {
-                            if *bits != 128 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I128`...");
-                            }
-                            translated_definition.ensure_use_declared("signed_integers::i128::*");
-                            "I128".into()
-                        }
-                        129..=256 => {
-                            if *bits != 256 {
-                                eprintln!("WARNING: unsupported signed integer type `int{bits}`, using `I256`...");
-                            }
-                            translated_definition.ensure_use_declared("signed_integers::i256::*");
-                            "I256".into()
-                        }
-                        _ => panic!("Invalid uint type: {bits}"),
-                    },
-                    generic_parameters: None,
+                if *bits == 0 || *bits > 256 || *bits % 8 != 0 {
+                    return Err(Error::InvalidConstruct(type_name.loc(), format!("Invalid int type: {bits}")));
                 }
-            }
 
-            solidity::Type::Uint(bits) => sway::TypeName::Identifier {
-                name: match *bits {
-                    0..=8 => {
-                        if *bits != 8 {
-                            eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u8`...");
-                        }
-                        "u8".into()
-                    }
-                    9..=16 => {
-                        if *bits != 16 {
-                            eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u16`...");
-                        }
-                        "u16".into()
-                    }
-                    17..=32 => {
-                        if *bits != 32 {
-                            eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u32`...");
-                        }
-                        "u32".into()
-                    }
-                    33..=64 => {
-                        if *bits != 64 {
-                            eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u64`...");
-                        }
-                        "u64".into()
-                    }
-                    65..=256 => {
-                        if *bits != 256 {
-                            eprintln!("WARNING: unsupported unsigned integer type `uint{bits}`, using `u256`...");
-                        }
-                        "u256".into()
+                let storage_bits = get_int_storage_bits(*bits as usize);
+                translated_definition.ensure_use_declared(format!("signed_integers::i{storage_bits}::*").as_str());
+
+                if *bits as usize == storage_bits {
+                    sway::TypeName::Identifier {
+                        name: format!("I{bits}"),
+                        generic_parameters: None,
                     }
-                    _ => panic!("Invalid uint type: {bits}"),
+                } else {
+                    // Widths which Sway doesn't have get a type definition, so that their arithmetic can be range-checked
+                    translated_definition.ensure_int_type_declared(*bits as usize)
+                }
+            }
+
+            solidity::Type::Uint(bits) => match *bits {
+                8 | 16 | 32 | 64 | 256 => sway::TypeName::Identifier {
+                    name: format!("u{bits}"),
+                    generic_parameters: None,
                 },
-                generic_parameters: None,
+
+                // Widths which Sway doesn't have get a type definition, so that their arithmetic can be range-checked
+                1..=255 if *bits % 8 == 0 => translated_definition.ensure_uint_type_declared(*bits as usize),
+
+                _ => return Err(Error::InvalidConstruct(type_name.loc(), format!("Invalid uint type: {bits}"))),
             },
 
             solidity::Type::Bytes(length) => match *length {
@@ -182,7 +130,8 @@ pub fn translate_type_name(
                 }
             },
 
-            solidity::Type::Rational => todo!("rational types"),
+            // `fixed` is an alias of `fixed128x18`
+            solidity::Type::Rational => translate_fixed_point_type_name(project, translated_definition, &type_name.loc(), true, 128, 18, is_storage, is_parameter)?,
 
             solidity::Type::DynamicBytes => sway::TypeName::Identifier {
                 name: {
@@ -209,11 +158,11 @@ pub fn translate_type_name(
                                         generic_parameters: Some(sway::GenericParameterList {
                                             entries: vec![
                                                 sway::GenericParameter {
-                                                    type_name: translate_type_name(project, translated_definition, key.as_ref(), is_storage, is_parameter),
+                                                    type_name: translate_type_name(project, translated_definition, key.as_ref(), is_storage, is_parameter)?,
                                                     implements: None,
                                                 },
                                                 sway::GenericParameter {
-                                                    type_name: translate_type_name(project, translated_definition, value.as_ref(), is_storage, is_parameter),
+                                                    type_name: translate_type_name(project, translated_definition, value.as_ref(), is_storage, is_parameter)?,
                                                     implements: None,
                                                 },
                                             ],
@@ -230,11 +179,11 @@ pub fn translate_type_name(
                         generic_parameters: Some(sway::GenericParameterList {
                             entries: vec![
                                 sway::GenericParameter {
-                                    type_name: translate_type_name(project, translated_definition, key.as_ref(), is_storage, is_parameter),
+                                    type_name: translate_type_name(project, translated_definition, key.as_ref(), is_storage, is_parameter)?,
                                     implements: None,
                                 },
                                 sway::GenericParameter {
-                                    type_name: translate_type_name(project, translated_definition, value.as_ref(), is_storage, is_parameter),
+                                    type_name: translate_type_name(project, translated_definition, value.as_ref(), is_storage, is_parameter)?,
                                     implements: None,
                                 },
                             ],
@@ -243,24 +192,79 @@ pub fn translate_type_name(
                 }
             }
 
-            solidity::Type::Function { .. } => todo!("function types"),
+            solidity::Type::Function { params, attributes, returns } => {
+                // External function types would need an abi and a selector to be called
+                if attributes.iter().any(|a| matches!(a, solidity::FunctionAttribute::Visibility(solidity::Visibility::External(_)))) {
+                    return Err(Error::UnsupportedConstruct(type_name.loc(), "external function types".into()));
+                }
+
+                let mut parameter_types = vec![];
+
+                for (_, parameter) in params.iter() {
+                    let Some(parameter) = parameter.as_ref() else { continue };
+                    let mut parameter_type = translate_type_name(project, translated_definition, &parameter.ty, false, true)?;
+
+                    // Check if the parameter's type is an ABI and make it an Identity
+                    if let sway::TypeName::Identifier { name, generic_parameters: None } = &parameter_type {
+                        if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_some() {
+                            parameter_type = sway::TypeName::Identifier {
+                                name: "Identity".into(),
+                                generic_parameters: None,
+                            };
+                        }
+                    }
+
+                    parameter_types.push(parameter_type);
+                }
+
+                let mut return_types = vec![];
+
+                for (_, parameter) in returns.as_ref().map(|(returns, _)| returns.as_slice()).unwrap_or_default() {
+                    let Some(parameter) = parameter.as_ref() else { continue };
+                    let return_type = translate_type_name(project, translated_definition, &parameter.ty, false, true)?;
+                    return_types.push(translate_return_type_name(project, translated_definition, return_type));
+                }
+
+                let return_type = match return_types.len() {
+                    0 => None,
+                    1 => return_types.pop(),
+                    _ => Some(sway::TypeName::Tuple { type_names: return_types }),
+                };
+
+                // Pure functions don't access storage, view functions only read from it
+                let (has_storage_read, has_storage_write) = match attributes.iter().find_map(|a| match a {
+                    solidity::FunctionAttribute::Mutability(mutability) => Some(mutability),
+                    _ => None,
+                }) {
+                    Some(solidity::Mutability::Pure(_) | solidity::Mutability::Constant(_)) => (false, false),
+                    Some(solidity::Mutability::View(_)) => (true, false),
+                    _ => (true, true),
+                };
+
+                translated_definition.ensure_function_type_declared(&parameter_types, return_type.as_ref(), has_storage_read, has_storage_write)
+            }
         }
 
         solidity::Expression::Variable(solidity::Identifier { name, .. }) => {
+            // Check if type is a fixed point type, which the parser doesn't recognize
+            if let Some((signed, bits, decimals)) = parse_fixed_point_type_name(name) {
+                return translate_fixed_point_type_name(project, translated_definition, &type_name.loc(), signed, bits, decimals, is_storage, is_parameter);
+            }
+
             // Check if type is a type definition
             if translated_definition.type_definitions.iter().any(|t| matches!(&t.name, sway::TypeName::Identifier { name: type_name, generic_parameters: None } if type_name == name)) {
-                return sway::TypeName::Identifier {
+                return Ok(sway::TypeName::Identifier {
                     name: name.clone(),
                     generic_parameters: None,
-                };
+                });
             }
             
             // Check if type is a struct
             if translated_definition.struct_names.iter().any(|n| n == name) {
-                return sway::TypeName::Identifier {
+                return Ok(sway::TypeName::Identifier {
                     name: name.clone(),
                     generic_parameters: None,
-                };
+                });
             }
             
             // Check if type is an enum
@@ -268,47 +272,46 @@ pub fn translate_type_name(
                 sway::TypeName::Identifier { name: type_name, generic_parameters: None } => type_name == name,
                 _ => false,
             }) {
-                return sway::TypeName::Identifier {
+                return Ok(sway::TypeName::Identifier {
                     name: name.clone(),
                     generic_parameters: None,
-                };
+                });
             }
             
             // Check if type is a contract that hasn't been defined yet
-            if project.find_definition_with_abi(name.as_str()).is_none() && translated_definition.contract_names.iter().any(|n| n == name) {
-                project.translate(Some(name), &translated_definition.path).unwrap();
+            if project.find_definition_with_abi(&translated_definition.path, name.as_str()).is_none() && translated_definition.contract_names.iter().any(|n| n == name) {
+                project.translate(Some(name), &translated_definition.path)?;
             }
 
             // Check if type is an ABI
-            if let Some(external_definition) = project.find_definition_with_abi(name.as_str()) {
+            if let Some(external_definition) = project.find_definition_with_abi(&translated_definition.path, name.as_str()) {
                 // Ensure the ABI is added to the current definition
                 if !translated_definition.abis.iter().any(|a| a.name == *name) {
                     translated_definition.abis.push(external_definition.abi.as_ref().unwrap().clone());
                 }
 
-                return sway::TypeName::Identifier {
+                return Ok(sway::TypeName::Identifier {
                     name: external_definition.name.clone(),
                     generic_parameters: None,
-                };
+                });
             }
 
-            todo!("translate variable type expression: {} - {type_name:#?}", type_name.to_string())
+            return Err(Error::UnsupportedConstruct(type_name.loc(), format!("type name: {type_name}")))
         }
 
         solidity::Expression::ArraySubscript(_, type_name, length) => match length.as_ref() {
             Some(length) => sway::TypeName::Array {
-                type_name: Box::new(translate_type_name(project, translated_definition, type_name, is_storage, is_parameter)),
+                type_name: Box::new(translate_type_name(project, translated_definition, type_name, is_storage, is_parameter)?),
                 length: {
                     // Create an empty scope to translate the array length expression
-                    let scope = Rc::new(RefCell::new(TranslationScope {
+                    let scope = Arc::new(RwLock::new(TranslationScope {
                         parent: Some(translated_definition.toplevel_scope.clone()),
                         ..Default::default()
                     }));
 
-                    match translate_expression(project, translated_definition, scope.clone(), length.as_ref()) {
-                        Ok(sway::Expression::Literal(sway::Literal::DecInt(length) | sway::Literal::HexInt(length))) => length.try_into().unwrap(),
-                        Ok(_) => panic!("Invalid array length expression: {length:#?}"),
-                        Err(e) => panic!("Failed to translate array length expression: {e}"),
+                    match translate_expression(project, translated_definition, scope.clone(), length.as_ref())? {
+                        sway::Expression::Literal(sway::Literal::DecInt(length) | sway::Literal::HexInt(length)) => length.try_into().unwrap(),
+                        _ => return Err(Error::UnsupportedConstruct(length.loc(), format!("array length expression: {length}"))),
                     }
                 },
             },
@@ -325,7 +328,7 @@ pub fn translate_type_name(
                 generic_parameters: Some(sway::GenericParameterList {
                     entries: vec![
                         sway::GenericParameter {
-                            type_name: translate_type_name(project, translated_definition, type_name, is_storage, is_parameter),
+                            type_name: translate_type_name(project, translated_definition, type_name, is_storage, is_parameter)?,
                             implements: None,
                         },
                     ],
@@ -335,42 +338,117 @@ pub fn translate_type_name(
 
         solidity::Expression::MemberAccess(_, container, member) => match container.as_ref() {
             solidity::Expression::Variable(solidity::Identifier { name, .. }) => {
-                let mut type_name = None;
-                let mut translated_enum = None;
-
-                // Check to see if container is an external definition
-                if let Some(external_definition) = project.translated_definitions.iter().find(|d| d.name == *name) {
-                    // Check to see if member is an enum
-                    if let Some(external_enum) = external_definition.enums.iter().find(|e| {
-                        let sway::TypeName::Identifier { name, generic_parameters: None } = &e.type_definition.name else {
-                            panic!("Expected Identifier type name, found {:#?}", e.type_definition.name);
-                        };
+                // Check to see if member is an enum of an external definition
+                if let Some(external_definition) = project.find_symbol(&translated_definition.path, SymbolKind::Enum, &member.name, |d| d.name == *name) {
+                    let external_enum = external_definition.enums.iter().find(|e| {
+                        let sway::TypeName::Identifier { name, generic_parameters: None } = &e.type_definition.name else { return false };
 
                         *name == member.name
-                    }) {
-                        // Import the enum if we haven't already
-                        if !translated_definition.enums.contains(external_enum) {
-                            translated_enum = Some(external_enum.clone());
-                        }
+                    }).cloned().unwrap();
 
-                        type_name = Some(external_enum.type_definition.name.clone());
+                    // Import the enum if we haven't already
+                    if !translated_definition.enums.contains(&external_enum) {
+                        translated_definition.import_enum(&external_enum);
                     }
+
+                    return Ok(external_enum.type_definition.name.clone());
                 }
 
-                if let Some(type_name) = type_name {
-                    if let Some(translated_enum) = translated_enum.as_ref() {
-                        translated_definition.import_enum(translated_enum);
+                // Check to see if member is a struct of an external definition
+                if let Some(external_definition) = project.find_symbol(&translated_definition.path, SymbolKind::Struct, &member.name, |d| d.name == *name) {
+                    let external_struct = external_definition.structs.iter().find(|s| s.name == member.name).cloned().unwrap();
+
+                    // Import the struct if we haven't already
+                    if !translated_definition.structs.contains(&external_struct) {
+                        translated_definition.structs.push(external_struct.clone());
+                    }
+
+                    if !translated_definition.struct_names.contains(&external_struct.name) {
+                        translated_definition.struct_names.push(external_struct.name.clone());
                     }
 
-                    return type_name;
+                    return Ok(sway::TypeName::Identifier {
+                        name: external_struct.name.clone(),
+                        generic_parameters: None,
+                    });
                 }
 
-                todo!("member access type name expression: {type_name:#?}")
+                return Err(Error::UnsupportedConstruct(container.loc(), format!("member access type name expression: {name}.{}", member.name)))
             }
 
-            _ => todo!("member access type name expression: {type_name:#?}")
+            _ => return Err(Error::UnsupportedConstruct(type_name.loc(), format!("member access type name expression: {type_name}")))
         }
 
-        _ => unimplemented!("type name expression: {type_name:#?}"),
+        _ => return Err(Error::UnsupportedConstruct(type_name.loc(), format!("type name expression: {type_name}"))),
+    })
+}
+
+/// Parses a `fixedMxN` or `ufixedMxN` type name into its signedness, bit width and number of decimals.
+pub fn parse_fixed_point_type_name(name: &str) -> Option<(bool, u16, u8)> {
+    let (signed, rest) = match name.strip_prefix('u') {
+        Some(rest) => (false, rest.strip_prefix("fixed")?),
+        None => (true, name.strip_prefix("fixed")?),
+    };
+
+    if rest.is_empty() {
+        return Some((signed, 128, 18));
+    }
+
+    let (bits, decimals) = rest.split_once('x')?;
+    let (bits, decimals) = (bits.parse::<u16>().ok()?, decimals.parse::<u8>().ok()?);
+
+    if bits == 0 || bits > 256 || bits % 8 != 0 || decimals > 80 {
+        return None;
+    }
+
+    Some((signed, bits, decimals))
+}
+
+/// Translates a fixed point type to a type definition of the integer type of its width, i.e. `type ufixed128x18 = u128;`.
+/// Values of the type are stored as integers which are scaled by its decimals.
+#[allow(clippy::too_many_arguments)]
+fn translate_fixed_point_type_name(
+    project: &mut Project,
+    translated_definition: &mut TranslatedDefinition,
+    loc: &solidity::Loc,
+    signed: bool,
+    bits: u16,
+    decimals: u8,
+    is_storage: bool,
+    is_parameter: bool,
+) -> Result<sway::TypeName, Error> {
+    let type_name = sway::TypeName::Identifier {
+        name: format!("{}fixed{bits}x{decimals}", if signed { "" } else { "u" }),
+        generic_parameters: None,
+    };
+
+    if !translated_definition.type_definitions.iter().any(|t| t.name == type_name) {
+        let underlying_type = translate_type_name(
+            project,
+            translated_definition,
+            &solidity::Expression::Type(*loc, if signed { solidity::Type::Int(bits) } else { solidity::Type::Uint(bits) }),
+            is_storage,
+            is_parameter,
+        )?;
+
+        translated_definition.type_definitions.push(sway::TypeDefinition {
+            is_public: true,
+            name: type_name.clone(),
+            underlying_type: Some(underlying_type),
+        });
+    }
+
+    Ok(type_name)
+}
+
+/// Gets the bit width of the signed integer type of `sway-libs` which holds values of the Solidity `int{bits}` type
+pub fn get_int_storage_bits(bits: usize) -> usize {
+    match bits {
+        0..=8 => 8,
+        9..=16 => 16,
+        17..=32 => 32,
+        33..=64 => 64,
+        65..=128 => 128,
+        _ => 256,
     }
 }
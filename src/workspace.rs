@@ -0,0 +1,362 @@
+use crate::{sway, translate::TranslatedDefinition, translate_naming_convention};
+use convert_case::Case;
+use solang_parser::pt as solidity;
+use std::path::PathBuf;
+
+/// A package in a Forc workspace which holds a single translated definition.
+#[derive(Clone, Debug)]
+pub struct WorkspaceMember {
+    /// The name of the Forc package, which is also the name of its directory in the workspace.
+    pub name: String,
+    pub definition_name: String,
+    pub path: PathBuf,
+    pub module: sway::Module,
+    /// The lines of the `[dependencies]` table in the package's `Forc.toml`.
+    pub dependencies: Vec<String>,
+}
+
+impl WorkspaceMember {
+    /// Creates a library package which only holds the `abi` of the translated definition, along with the types, events and errors it uses.
+    ///
+    /// Other Sway projects can depend on the package to call the translated contract. Returns `None` if the definition has no `abi`.
+    pub fn abi_package(translated_definition: &TranslatedDefinition) -> Option<Self> {
+        let abi = translated_definition.abi.as_ref()?;
+
+        let mut module = sway::Module::new(sway::ModuleKind::Library);
+
+        for x in translated_definition.uses.iter() {
+            module.items.push(sway::ModuleItem::Use(x.clone()));
+        }
+
+        // Collect the ABIs that the definition's ABI inherits from
+        let mut abis = vec![abi.clone()];
+        let mut index = 0;
+
+        while index < abis.len() {
+            for inherit in abis[index].inherits.clone() {
+                if abis.iter().any(|a| a.name == inherit) {
+                    continue;
+                }
+
+                if let Some(inherited_abi) = translated_definition.abis.iter().find(|a| a.name == inherit) {
+                    abis.push(inherited_abi.clone());
+                }
+            }
+
+            index += 1;
+        }
+
+        // Collect the type names used by the ABI functions, events and errors
+        let mut type_names = vec![];
+
+        for function in abis.iter().flat_map(|a| a.functions.iter()) {
+            type_names.extend(function.parameters.entries.iter().filter_map(|p| p.type_name.clone()));
+            type_names.extend(function.return_type.clone());
+        }
+
+        for (x, _) in translated_definition.events_enums.iter().chain(translated_definition.errors_enums.iter()) {
+            type_names.extend(x.variants.iter().map(|v| v.type_name.clone()));
+        }
+
+        // Collect the type definitions, enums and structs which are used, along with the types they use themselves
+        let mut type_definitions = vec![];
+        let mut enums = vec![];
+        let mut structs = vec![];
+
+        while let Some(type_name) = type_names.pop() {
+            match type_name {
+                sway::TypeName::Identifier { name, generic_parameters } => {
+                    if let Some(generic_parameters) = generic_parameters {
+                        type_names.extend(generic_parameters.entries.into_iter().map(|p| p.type_name));
+                    }
+
+                    let is_named = |x: &sway::TypeDefinition| matches!(&x.name, sway::TypeName::Identifier { name: n, .. } if *n == name);
+
+                    if let Some(x) = translated_definition.type_definitions.iter().find(|x| is_named(x)) {
+                        if !type_definitions.contains(x) {
+                            type_definitions.push(x.clone());
+                            type_names.extend(x.underlying_type.clone());
+                        }
+                    } else if let Some(x) = translated_definition.enums.iter().find(|x| is_named(&x.type_definition)) {
+                        if !enums.contains(x) {
+                            enums.push(x.clone());
+                            type_names.extend(x.type_definition.underlying_type.clone());
+                        }
+                    } else if let Some(x) = translated_definition.structs.iter().find(|x| x.name == name) {
+                        if !structs.contains(x) {
+                            structs.push(x.clone());
+                            type_names.extend(x.fields.iter().map(|f| f.type_name.clone()));
+                        }
+                    }
+                }
+
+                sway::TypeName::Array { type_name, .. } => type_names.push(*type_name),
+                sway::TypeName::Tuple { type_names: x } => type_names.extend(x),
+
+                _ => {}
+            }
+        }
+
+        // Keep the items in the order they were translated in
+        for x in translated_definition.type_definitions.iter().filter(|x| type_definitions.contains(x)) {
+            module.items.push(sway::ModuleItem::TypeDefinition(x.clone()));
+        }
+
+        for x in translated_definition.enums.iter().filter(|x| enums.contains(x)) {
+            module.items.push(sway::ModuleItem::TypeDefinition(x.type_definition.clone()));
+            module.items.push(sway::ModuleItem::Impl(x.variants_impl.clone()));
+        }
+
+        for x in translated_definition.structs.iter().filter(|x| structs.contains(x)) {
+            module.items.push(sway::ModuleItem::Struct(x.clone()));
+        }
+
+        for (x, abi_encode_impl) in translated_definition.events_enums.iter().chain(translated_definition.errors_enums.iter()) {
+            module.items.push(sway::ModuleItem::Enum(x.clone()));
+            module.items.push(sway::ModuleItem::Impl(abi_encode_impl.clone()));
+        }
+
+        for x in abis.into_iter().rev() {
+            module.items.push(sway::ModuleItem::Abi(x));
+        }
+
+        for item in module.items.iter_mut() {
+            make_item_public(item);
+        }
+
+        Some(Self {
+            name: format!("{}_abi", translate_naming_convention(translated_definition.name.as_str(), Case::Snake)),
+            definition_name: translated_definition.name.clone(),
+            path: translated_definition.path.clone(),
+            module,
+            dependencies: translated_definition.dependencies.clone(),
+        })
+    }
+}
+
+/// A Forc workspace with one member package per translated definition.
+///
+/// Solidity libraries and interfaces become library members. Types, ABIs and library functions which are shared between members
+/// are only declared by the first library member that contains them, and every other member imports them through a path dependency.
+#[derive(Clone, Debug, Default)]
+pub struct Workspace {
+    pub members: Vec<WorkspaceMember>,
+}
+
+impl Workspace {
+    /// Creates a workspace from the supplied `translated_definitions`, which should be in the order they were translated in.
+    pub fn new(translated_definitions: &[TranslatedDefinition]) -> Self {
+        let mut members: Vec<WorkspaceMember> = vec![];
+        let mut is_library = vec![];
+
+        for translated_definition in translated_definitions.iter() {
+            let name = get_unique_member_name(members.as_slice(), translate_naming_convention(translated_definition.name.as_str(), Case::Snake));
+
+            let library = matches!(
+                translated_definition.kind.as_ref(),
+                Some(solidity::ContractTy::Interface(_) | solidity::ContractTy::Library(_))
+            );
+
+            let mut module: sway::Module = translated_definition.clone().into();
+
+            if library {
+                module.kind = sway::ModuleKind::Library;
+            }
+
+            // Solidity libraries are called through their functions, so drop the contract implementation of their ABI
+            if let Some(solidity::ContractTy::Library(_)) = translated_definition.kind.as_ref() {
+                module.items.retain(|item| match item {
+                    sway::ModuleItem::Abi(x) => x.name != translated_definition.name,
+
+                    sway::ModuleItem::Impl(x) => !matches!(
+                        x.for_type_name.as_ref(),
+                        Some(sway::TypeName::Identifier { name, generic_parameters: None }) if name == "Contract"
+                    ),
+
+                    _ => true,
+                });
+            }
+
+            members.push(WorkspaceMember {
+                name,
+                definition_name: translated_definition.name.clone(),
+                path: translated_definition.path.clone(),
+                module,
+                dependencies: translated_definition.dependencies.clone(),
+            });
+
+            is_library.push(library);
+        }
+
+        // Each shareable item is owned by the first library member which contains it
+        let mut owners: Vec<(sway::ModuleItem, usize)> = vec![];
+
+        for (index, member) in members.iter().enumerate() {
+            if !is_library[index] {
+                continue;
+            }
+
+            for item in member.module.items.iter() {
+                if get_shared_item_name(item).is_none() || owners.iter().any(|(x, _)| x == item) {
+                    continue;
+                }
+
+                owners.push((item.clone(), index));
+            }
+        }
+
+        // Replace the items owned by other members with imports
+        for index in 0..members.len() {
+            let mut imports: Vec<(usize, Vec<String>)> = vec![];
+            let mut removed_type_names = vec![];
+
+            members[index].module.items.retain(|item| {
+                let Some(name) = get_shared_item_name(item) else { return true };
+                let Some((_, owner_index)) = owners.iter().find(|(x, _)| x == item) else { return true };
+
+                if *owner_index == index {
+                    return true;
+                }
+
+                match imports.iter_mut().find(|(i, _)| i == owner_index) {
+                    Some((_, names)) => names.push(name.clone()),
+                    None => imports.push((*owner_index, vec![name.clone()])),
+                }
+
+                removed_type_names.push((name, *owner_index));
+                false
+            });
+
+            // Implementations of imported types have to be declared alongside them
+            let owner_impls = removed_type_names.iter()
+                .flat_map(|(name, owner_index)| {
+                    members[*owner_index].module.items.iter().filter_map(|i| match i {
+                        sway::ModuleItem::Impl(x) if get_impl_type_name(x) == Some(name) => Some(x.clone()),
+                        _ => None,
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            members[index].module.items.retain(|item| {
+                let sway::ModuleItem::Impl(x) = item else { return true };
+                !owner_impls.contains(x)
+            });
+
+            for (owner_index, names) in imports.iter() {
+                let owner_name = members[*owner_index].name.clone();
+
+                let use_item = sway::ModuleItem::Use(sway::Use {
+                    is_public: false,
+                    tree: sway::UseTree::Path {
+                        prefix: owner_name.clone(),
+                        suffix: Box::new(if names.len() == 1 {
+                            sway::UseTree::Name { name: names[0].clone() }
+                        } else {
+                            sway::UseTree::Group {
+                                imports: names.iter().map(|name| sway::UseTree::Name { name: name.clone() }).collect(),
+                            }
+                        }),
+                    },
+                });
+
+                let module = &mut members[index].module;
+                let use_index = module.items.iter().take_while(|i| matches!(i, sway::ModuleItem::Use(_))).count();
+                module.items.insert(use_index, use_item);
+
+                let dependency = format!("{owner_name} = {{ path = \"../{owner_name}\" }}");
+
+                if !members[index].dependencies.contains(&dependency) {
+                    members[index].dependencies.push(dependency);
+                }
+            }
+        }
+
+        // Make the owned items public so they can be imported by other members
+        for (owned_item, owner_index) in owners.iter() {
+            if let Some(item) = members[*owner_index].module.items.iter_mut().find(|i| *i == owned_item) {
+                make_item_public(item);
+            }
+        }
+
+        Self { members }
+    }
+
+    /// Adds an ABI package member for each of the supplied `translated_definitions` which has an `abi`.
+    pub fn add_abi_packages(&mut self, translated_definitions: &[TranslatedDefinition]) {
+        for translated_definition in translated_definitions.iter() {
+            let Some(mut member) = WorkspaceMember::abi_package(translated_definition) else { continue };
+            member.name = get_unique_member_name(self.members.as_slice(), member.name);
+            self.members.push(member);
+        }
+    }
+
+    /// Gets the contents of the workspace's top-level `Forc.toml`.
+    pub fn manifest(&self) -> String {
+        format!(
+            "[workspace]\n\
+            members = [{}]\n\
+            ",
+            self.members.iter().map(|m| format!("\"{}\"", m.name)).collect::<Vec<_>>().join(", "),
+        )
+    }
+}
+
+/// Gets a package name based on `name` which isn't used by any of the `members`.
+fn get_unique_member_name(members: &[WorkspaceMember], name: String) -> String {
+    let mut result = name.clone();
+    let mut suffix = 1;
+
+    // Definitions with the same name in different files need unique package names
+    while members.iter().any(|m| m.name == result) {
+        suffix += 1;
+        result = format!("{name}_{suffix}");
+    }
+
+    result
+}
+
+/// Gets the name of a module item that can be shared between workspace members.
+fn get_shared_item_name(item: &sway::ModuleItem) -> Option<String> {
+    match item {
+        sway::ModuleItem::TypeDefinition(x) => match &x.name {
+            sway::TypeName::Identifier { name, generic_parameters: None } => Some(name.clone()),
+            _ => None,
+        },
+
+        sway::ModuleItem::Constant(x) => Some(x.name.clone()),
+        sway::ModuleItem::Struct(x) => Some(x.name.clone()),
+        sway::ModuleItem::Enum(x) => Some(x.name.clone()),
+        sway::ModuleItem::Abi(x) => Some(x.name.clone()),
+        sway::ModuleItem::Function(x) => Some(x.name.clone()),
+
+        _ => None,
+    }
+}
+
+/// Gets the name of the type that an `impl` block implements.
+fn get_impl_type_name(x: &sway::Impl) -> Option<&String> {
+    match x.for_type_name.as_ref().unwrap_or(&x.type_name) {
+        sway::TypeName::Identifier { name, generic_parameters: None } => Some(name),
+        _ => None,
+    }
+}
+
+fn make_item_public(item: &mut sway::ModuleItem) {
+    match item {
+        sway::ModuleItem::TypeDefinition(x) => x.is_public = true,
+        sway::ModuleItem::Constant(x) => x.is_public = true,
+
+        sway::ModuleItem::Struct(x) => {
+            x.is_public = true;
+
+            for field in x.fields.iter_mut() {
+                field.is_public = true;
+            }
+        }
+
+        sway::ModuleItem::Enum(x) => x.is_public = true,
+        sway::ModuleItem::Function(x) => x.is_public = true,
+
+        _ => {}
+    }
+}
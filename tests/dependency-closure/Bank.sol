@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+contract Shares {
+    uint256 public total;
+}
+
+interface IBank {
+    function deposit(uint256 amount) external;
+}
+
+contract Bank is IBank {
+    uint256 public balance;
+
+    // The parameter shadows the name of `Shares`, which `Bank` doesn't depend on
+    function deposit(uint256 Shares) external override {
+        balance += Shares;
+    }
+}
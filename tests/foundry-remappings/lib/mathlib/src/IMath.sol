@@ -0,0 +1,6 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+interface IMath {
+    function double(uint256 x) external pure returns (uint256);
+}
@@ -0,0 +1,6 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+interface IToken {
+    function totalSupply() external view returns (uint256);
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "@oz/token/IToken.sol";
+import "mathlib/IMath.sol";
+
+contract Vault {
+    function supply(IToken token, IMath math) public view returns (uint256) {
+        return math.double(token.totalSupply());
+    }
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "@oz/token/IToken.sol";
+
+contract LegacyVault {
+    function supply(IToken token) public view returns (uint256) {
+        return token.legacySupply();
+    }
+}
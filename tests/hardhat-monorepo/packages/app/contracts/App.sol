@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "@scope/shared/contracts/IShared.sol";
+
+contract App {
+    function token(IShared shared) public view returns (IToken) {
+        return shared.token();
+    }
+}
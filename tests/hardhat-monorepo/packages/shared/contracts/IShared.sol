@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "@scope/token/contracts/IToken.sol";
+
+interface IShared {
+    function token() external view returns (IToken);
+}
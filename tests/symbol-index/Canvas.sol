@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./a/Shapes.sol";
+
+contract Canvas {
+    Shapes.Kind public kind;
+
+    function move(Shapes.Point memory point) public {
+        kind = Shapes.Kind.Square;
+
+        if (point.x > 100) {
+            revert Shapes.OutOfBounds(point.x);
+        }
+
+        emit Shapes.Moved(point.x, point.y);
+    }
+
+    function scaledWidth(Shapes.Point memory point) public pure returns (uint256) {
+        return scale(Shapes.width(point));
+    }
+}
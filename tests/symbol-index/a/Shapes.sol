@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+library Shapes {
+    struct Point {
+        uint256 x;
+        uint256 y;
+    }
+
+    enum Kind {
+        Circle,
+        Square
+    }
+
+    event Moved(uint256 x, uint256 y);
+
+    error OutOfBounds(uint256 x);
+
+    function width(Point memory point) internal pure returns (uint256) {
+        return point.x;
+    }
+}
+
+function scale(uint256 x) pure returns (uint256) {
+    return x * 2;
+}
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+library Shapes {
+    struct Point {
+        int256 x;
+    }
+
+    enum Kind {
+        Triangle
+    }
+
+    function width(Point memory point) internal pure returns (int256) {
+        return point.x;
+    }
+}
+
+function scale(uint256 x) pure returns (uint256) {
+    return x * 3;
+}
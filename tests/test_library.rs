@@ -0,0 +1,1179 @@
+#[test]
+fn test_translate_source() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Counter {
+            uint256 public count;
+
+            function inc() public {
+                count += 1;
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Counter.sol", source, None).unwrap();
+    assert_eq!(translation.definitions.len(), 1);
+    assert_eq!(translation.definitions[0].name, "Counter");
+
+    let modules = translation.modules();
+    assert_eq!(modules.len(), 1);
+
+    let output = charcoal::sway::TabbedDisplayer(&modules[0]).to_string();
+    assert!(output.starts_with("contract;"));
+    assert!(output.contains("fn inc()"));
+}
+
+#[test]
+fn test_translate_path() {
+    let translation = charcoal::translate_path("./tests/solidity-by-example/contracts/Interface.sol", None).unwrap();
+
+    let names = translation.definitions.iter().map(|d| d.name.as_str()).collect::<Vec<_>>();
+    assert!(names.contains(&"Counter"));
+    assert!(names.contains(&"MyContract"));
+
+    // `uint112` is declared as a type alias rather than silently widened to `u256`
+    let output = translation.modules().iter()
+        .map(|module| charcoal::sway::TabbedDisplayer(module).to_string())
+        .collect::<String>();
+
+    assert!(output.contains("pub type u112 = u256;"));
+    assert!(output.contains("fn get_reserves() -> (u112, u112, u32);"));
+}
+
+#[test]
+fn test_translate_source_parse_error() {
+    assert!(charcoal::translate_source("Broken.sol", "contract {", None).is_err());
+}
+
+#[test]
+fn test_translate_source_recovers_from_errors() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Broken {
+            uint256 public count;
+
+            function good() public {
+                count += 1;
+            }
+
+            function bad() public {
+                assembly {
+                    leave
+                }
+            }
+        }
+
+        contract Fine {
+            function get() public pure returns (uint256) {
+                return 1;
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Broken.sol", source, None).unwrap();
+
+    let names = translation.definitions.iter().map(|d| d.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec!["Broken", "Fine"]);
+
+    let diagnostic = translation.diagnostics.iter()
+        .find(|d| d.level == charcoal::errors::DiagnosticLevel::Error)
+        .unwrap();
+
+    assert_eq!(diagnostic.line_and_column, Some((14, 21)));
+    assert!(diagnostic.message.contains("yul leave statement"));
+
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+    assert!(output.contains("fn good()"));
+
+    // The failing function keeps its signature, and its body is replaced with a placeholder
+    assert!(output.contains("fn bad();"));
+    assert!(output.contains("/* Failed to translate function: Unsupported yul leave statement: leave"));
+    assert!(output.contains("todo!()"));
+}
+
+#[test]
+fn test_translation_report() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Legacy {
+            function limit() public view returns (uint256) {
+                return block.gaslimit;
+            }
+
+            function random() public view returns (uint256) {
+                return block.difficulty + block.gaslimit;
+            }
+
+            function destroy() public {
+                selfdestruct(payable(msg.sender));
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Legacy.sol", source, None).unwrap();
+    let report = translation.report();
+
+    assert_eq!(report.count, 4);
+    assert_eq!(report.contracts.len(), 1);
+    assert_eq!(report.contracts[0].name, "Legacy");
+    assert_eq!(report.contracts[0].count, 4);
+
+    let function_counts = report.contracts[0].functions.iter().map(|f| (f.name.as_str(), f.count)).collect::<Vec<_>>();
+    assert_eq!(function_counts, vec![("limit", 1), ("random", 2), ("destroy", 1)]);
+
+    let entry = &report.entries[0];
+    assert_eq!(entry.kind, charcoal::report::ReportEntryKind::Todo);
+    assert_eq!((entry.line, entry.column), (Some(7), Some(24)));
+    assert_eq!(entry.message, "block.gaslimit");
+
+    assert!(report.entries.iter().any(|e| e.kind == charcoal::report::ReportEntryKind::Unsupported && e.message == "block.difficulty"));
+    assert!(report.entries.iter().any(|e| e.kind == charcoal::report::ReportEntryKind::Unimplemented && e.function_name.as_deref() == Some("destroy")));
+}
+
+#[test]
+fn test_source_map() {
+    let source = r#"// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+contract Counter {
+    uint256 public count;
+
+    function inc() public {
+        count += 1;
+    }
+}
+"#;
+
+    let translation = charcoal::translate_source("Counter.sol", source, None).unwrap();
+    let modules = translation.modules();
+
+    let (output, source_map) = charcoal::source_map::display_with_source_map(&modules[0]);
+
+    // The displayed code should be the same as without a source map
+    assert_eq!(output, charcoal::sway::TabbedDisplayer(&modules[0]).to_string());
+
+    let line = output.lines().position(|l| l.contains("storage.count.write(")).unwrap() + 1;
+    let location = source_map.find(line).unwrap();
+    assert_eq!((location.start_line, location.start_column), (8, 9));
+    assert_eq!((location.end_line, location.end_column), (8, 19));
+
+    let line = output.lines().position(|l| l.trim() == "fn inc() {").unwrap() + 1;
+    let location = source_map.find(line).unwrap();
+    assert_eq!((location.start_line, location.end_line), (7, 9));
+}
+
+#[test]
+fn test_inherited_abi_functions() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        interface IFoo {
+            function foo() external view returns (uint256);
+        }
+
+        contract Base is IFoo {
+            function foo() public view virtual returns (uint256) {
+                return 1;
+            }
+        }
+
+        contract Foo is Base {
+            function foo() public view override returns (uint256) {
+                return 2;
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Foo.sol", source, None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    // Inherited and overridden functions are only declared once, even though they come from different locations
+    for module in translation.modules().iter().skip(1) {
+        let output = charcoal::sway::TabbedDisplayer(module).to_string();
+        assert_eq!(output.matches("fn foo() -> u256;").count(), 1);
+        assert_eq!(output.matches("fn foo() -> u256 {").count(), 1);
+    }
+}
+
+#[test]
+fn test_translate_comments() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        /// @title A simple counter
+        contract Counter {
+            /// @notice The current count
+            uint256 public count;
+
+            /**
+             * @notice Emitted when the count changes
+             * @param value The new count
+             */
+            event Changed(uint256 value);
+
+            /// @notice Increments the count
+            function inc() public {
+                // Bump the count
+                count += 1;
+                emit Changed(count);
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Counter.sol", source, None).unwrap();
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+
+    assert!(output.contains("/// @title A simple counter\nabi Counter {"));
+    assert!(output.contains("    /// @notice Emitted when the count changes\n    /// @param value The new count\n    Changed: u256,"));
+    assert!(output.contains("    /// @notice The current count\n    count: u256 = 0,"));
+    assert!(output.contains("    /// @notice Increments the count\n    #[storage(read, write)]\n    fn inc() {"));
+    assert!(output.contains("        /* Bump the count */\n        storage.count.write("));
+}
+
+#[test]
+fn test_inherited_doc_comments() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        interface IFoo {
+            /// @notice Gets the foo
+            function foo() external view returns (uint256);
+
+            /// @notice Gets the bar
+            function bar() external view returns (uint256);
+        }
+
+        contract Foo is IFoo {
+            /// @inheritdoc IFoo
+            function foo() public pure override returns (uint256) {
+                return 1;
+            }
+
+            /// @notice Gets the bar of this contract
+            function bar() public pure override returns (uint256) {
+                return 2;
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Foo.sol", source, None).unwrap();
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[1]).to_string();
+
+    // Overrides are declared once, keeping the inherited documentation unless they have their own
+    assert_eq!(output.matches("fn foo() -> u256;").count(), 1);
+    assert!(output.contains("    /// @notice Gets the foo\n    #[storage(read)]\n    fn foo() -> u256;"));
+    assert_eq!(output.matches("fn bar() -> u256;").count(), 1);
+    assert!(output.contains("    /// @notice Gets the bar of this contract\n    #[storage(read)]\n    fn bar() -> u256;"));
+}
+
+#[test]
+fn test_translate_directory() {
+    let translation = charcoal::translate_path("./tests/solidity-by-example/contracts", None).unwrap();
+
+    // Each definition should only be translated once, even when it is imported by several files
+    for (i, definition) in translation.definitions.iter().enumerate() {
+        assert!(!translation.definitions[i + 1..].iter().any(|d| d.name == definition.name && d.path == definition.path));
+    }
+
+    // Definitions with the same name in different files should not be confused with each other
+    let paths = translation.definitions.iter()
+        .filter(|d| d.name == "A")
+        .map(|d| d.path.file_name().unwrap().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+
+    assert!(paths.contains(&"CallParentContracts.sol".to_string()));
+    assert!(paths.contains(&"Inheritance.sol".to_string()));
+    assert!(!translation.diagnostics.iter().any(|d| d.message.contains("A.foo()")));
+}
+
+#[test]
+fn test_workspace() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        interface IToken {
+            struct Info {
+                uint256 supply;
+                address owner;
+            }
+
+            event Minted(address to, uint256 amount);
+
+            function info() external view returns (Info memory);
+        }
+
+        contract Token is IToken {
+            Info data;
+
+            function info() external view returns (Info memory) {
+                return data;
+            }
+
+            function mint(uint256 amount) public {
+                data.supply += amount;
+                emit Minted(msg.sender, amount);
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Token.sol", source, None).unwrap();
+    let workspace = charcoal::workspace::Workspace::new(translation.definitions.as_slice());
+
+    assert_eq!(workspace.manifest(), "[workspace]\nmembers = [\"i_token\", \"token\"]\n");
+
+    let interface = &workspace.members[0];
+    assert!(matches!(interface.module.kind, charcoal::sway::ModuleKind::Library));
+
+    let interface_output = charcoal::sway::TabbedDisplayer(&interface.module).to_string();
+    assert!(interface_output.contains("pub struct Info {\n    pub supply: u256,"));
+    assert!(interface_output.contains("pub enum ITokenEvent {"));
+
+    let contract = &workspace.members[1];
+    assert!(contract.dependencies.contains(&"i_token = { path = \"../i_token\" }".to_string()));
+
+    let contract_output = charcoal::sway::TabbedDisplayer(&contract.module).to_string();
+    assert!(contract_output.contains("use i_token::{Info, ITokenEvent};"));
+    assert!(!contract_output.contains("struct Info"));
+    assert!(!contract_output.contains("impl AbiEncode for ITokenEvent"));
+}
+
+#[test]
+fn test_workspace_library() {
+    let translation = charcoal::translate_path("./tests/solidity-by-example/contracts/Library.sol", None).unwrap();
+    let workspace = charcoal::workspace::Workspace::new(translation.definitions.as_slice());
+
+    let library = workspace.members.iter().find(|m| m.name == "math").unwrap();
+    let library_output = charcoal::sway::TabbedDisplayer(&library.module).to_string();
+    assert!(library_output.starts_with("library;"));
+    assert!(library_output.contains("pub fn math_sqrt(y: u256) -> u256 {"));
+
+    // Libraries with public functions should not implement their ABI for `Contract`
+    let array = workspace.members.iter().find(|m| m.name == "array").unwrap();
+    let array_output = charcoal::sway::TabbedDisplayer(&array.module).to_string();
+    assert!(!array_output.contains("for Contract"));
+
+    let consumer = workspace.members.iter().find(|m| m.name == "test_math").unwrap();
+    let consumer_output = charcoal::sway::TabbedDisplayer(&consumer.module).to_string();
+    assert!(consumer_output.contains("use math::math_sqrt;"));
+    assert!(!consumer_output.contains("fn math_sqrt"));
+    assert!(consumer.dependencies.contains(&"math = { path = \"../math\" }".to_string()));
+}
+
+#[test]
+fn test_abi_package() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Vault {
+            struct Deposit {
+                uint256 amount;
+            }
+
+            struct Internal {
+                uint256 value;
+            }
+
+            event Deposited(address from, uint256 amount);
+
+            Internal internalState;
+
+            function deposit(Deposit memory d) public {
+                internalState.value += d.amount;
+                emit Deposited(msg.sender, d.amount);
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Vault.sol", source, None).unwrap();
+    let member = charcoal::workspace::WorkspaceMember::abi_package(&translation.definitions[0]).unwrap();
+    assert_eq!(member.name, "vault_abi");
+
+    let output = charcoal::sway::TabbedDisplayer(&member.module).to_string();
+    assert!(output.starts_with("library;"));
+    assert!(output.contains("pub struct Deposit {\n    pub amount: u256,\n}"));
+    assert!(output.contains("pub enum VaultEvent {"));
+    assert!(output.contains("abi Vault {"));
+
+    // Only the ABI and the types it uses should be included
+    assert!(!output.contains("Internal"));
+    assert!(!output.contains("storage {"));
+    assert!(!output.contains("for Contract"));
+}
+
+#[test]
+fn test_toplevel_constants() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        uint256 constant SCALE = 1000;
+        uint256 constant HALF_SCALE = SCALE / 2;
+
+        contract Scaled {
+            function half() public pure returns (uint256) {
+                return HALF_SCALE;
+            }
+        }
+
+        contract Other {
+            function scale() public pure returns (uint256) {
+                return SCALE;
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Scaled.sol", source, None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    for module in translation.modules() {
+        let output = charcoal::sway::TabbedDisplayer(&module).to_string();
+        assert!(output.contains("const SCALE: u256 = 1000;\nconst HALF_SCALE: u256 = SCALE / 2;"));
+    }
+}
+
+#[test]
+fn test_import_aliases() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        import "./Interface.sol" as I;
+        import * as J from "./Interface.sol";
+        import {ICounter as Counted} from "./Interface.sol";
+
+        contract Aliased {
+            function first(I.ICounter counter) public view returns (uint256) {
+                return counter.count();
+            }
+
+            function second(J.ICounter counter) public view returns (uint256) {
+                return counter.count();
+            }
+
+            function third(Counted counter) public view returns (uint256) {
+                return counter.count();
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("./tests/solidity-by-example/contracts/Aliased.sol", source, Some(&"Aliased".to_string())).unwrap();
+    assert!(!translation.diagnostics.iter().any(|d| d.path.ends_with("Aliased.sol")));
+
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+    assert_eq!(output.matches("abi(ICounter, counter.as_contract_id().unwrap().into()).count()").count(), 3);
+}
+
+#[test]
+fn test_foundry_remappings() {
+    let translation = charcoal::translate_path("./tests/foundry-remappings/src", None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    let outputs = translation.modules().iter()
+        .map(|module| charcoal::sway::TabbedDisplayer(module).to_string())
+        .collect::<Vec<_>>();
+
+    // `@oz/token/` wins over `@oz/` and `mathlib/` is remapped automatically from `lib/mathlib/src`
+    assert!(outputs.iter().any(|o| o.contains("fn total_supply() -> u256;") && o.contains("fn double(x: u256) -> u256;")));
+
+    // `src/legacy:@oz/` wins over `@oz/token/` for files in `src/legacy`
+    assert!(outputs.iter().any(|o| o.contains("fn legacy_supply() -> u256;") && !o.contains("total_supply")));
+}
+
+#[test]
+fn test_node_module_resolution() {
+    // `@scope/shared` is a symlinked workspace package and `@scope/token` is hoisted to the root of the monorepo
+    let translation = charcoal::translate_path("./tests/hardhat-monorepo/packages/app/contracts", None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+    assert!(output.contains("abi IShared {"));
+    assert!(output.contains("abi IToken {"));
+}
+
+#[test]
+fn test_standard_json() {
+    let translation = charcoal::translate_path("./tests/standard-json/input.json", Some(&"Vault".to_string())).unwrap();
+    assert!(translation.diagnostics.is_empty());
+    assert_eq!(translation.definitions.len(), 1);
+
+    // `@oz/token/IToken.sol` is remapped to a source loaded from its URL and `./Base.sol` is loaded from inline content
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+    assert!(output.contains("abi(IToken, token.as_contract_id().unwrap().into()).total_supply() * SCALE"));
+}
+
+#[test]
+fn test_standard_json_with_failing_source_unit() {
+    let translation = charcoal::translate_path("./tests/standard-json/partial.json", None).unwrap();
+
+    // The source unit which fails to parse is reported, and the other source units are still translated
+    let diagnostic = translation.diagnostics.iter()
+        .find(|d| d.level == charcoal::errors::DiagnosticLevel::Error)
+        .unwrap();
+
+    assert!(diagnostic.path.ends_with("contracts/Broken.sol"));
+    assert_eq!(diagnostic.line_and_column.map(|(line, _)| line), Some(5));
+
+    let names = translation.definitions.iter().map(|d| d.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["Counter"]);
+}
+
+#[test]
+fn test_dependency_closure() {
+    let translation = charcoal::translate_dependency_closure("./tests/foundry-remappings/src", &["Vault".to_string()]).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    // The imported interfaces are emitted before the contract which uses them, and `LegacyVault` is left out
+    let names = translation.definitions.iter().map(|d| d.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["IToken", "IMath", "Vault"]);
+
+    assert!(charcoal::translate_dependency_closure("./tests/foundry-remappings/src", &["Missing".to_string()]).is_err());
+
+    // Identifiers which merely share the name of a definition don't make it a dependency
+    let translation = charcoal::translate_dependency_closure("./tests/dependency-closure", &["Bank".to_string()]).unwrap();
+    let names = translation.definitions.iter().map(|d| d.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, ["IBank", "Bank"]);
+}
+
+#[test]
+fn test_exit_codes() {
+    let exit_code = |args: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_charcoal"))
+            .args(args)
+            .output()
+            .unwrap()
+            .status
+            .code()
+    };
+
+    assert_eq!(exit_code(&["-t", "./tests/solidity-by-example/contracts/HelloWorld.sol", "--strict"]), Some(0));
+    assert_eq!(exit_code(&["-t", "./tests/solidity-by-example/contracts/Import.sol"]), Some(0));
+    assert_eq!(exit_code(&["-t", "./tests/solidity-by-example/contracts/Import.sol", "--strict"]), Some(3));
+    assert_eq!(exit_code(&["-t", "./tests/solidity-by-example/contracts/Create.sol"]), Some(1));
+    assert_eq!(exit_code(&["-t", "./tests/solidity-by-example/contracts/Missing.sol"]), Some(1));
+
+    // Files which fail to parse exit with 2 in every mode, while the other files are still translated
+    for args in [&["-t", "./tests/parse-errors"][..], &["-t", "./tests/parse-errors", "-j", "2"], &["-t", "./tests/standard-json/partial.json"]] {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_charcoal"))
+            .args(args)
+            .output()
+            .unwrap();
+
+        assert_eq!(output.status.code(), Some(2));
+        assert!(String::from_utf8_lossy(&output.stdout).contains("abi Counter {"));
+    }
+}
+
+#[test]
+fn test_dependency_graph() {
+    use charcoal::graph::{GraphEdgeKind, GraphNodeKind};
+
+    let translation = charcoal::translate_path("./tests/solidity-by-example/contracts/Import.sol", None).unwrap();
+    let graph = &translation.graph;
+
+    let node = |name: &str| graph.nodes.iter().find(|n| n.name == name).unwrap();
+    let has_edge = |from: &str, to: &str, kind: GraphEdgeKind| graph.edges.iter().any(|e| e.from == node(from).id && e.to == node(to).id && e.kind == kind);
+
+    assert_eq!(node("Foo").kind, GraphNodeKind::Contract);
+    assert_eq!(node("Import").untranslated_count, 1);
+    assert!(has_edge("Import", "Foo", GraphEdgeKind::Import));
+    assert!(has_edge("Import", "Foo", GraphEdgeKind::AbiCall));
+
+    let translation = charcoal::translate_path("./tests/solidity-by-example/contracts/Library.sol", None).unwrap();
+    assert!(translation.graph.edges.iter().any(|e| e.kind == GraphEdgeKind::Using && e.to.ends_with(":Array")));
+
+    let dot = translation.graph.to_dot();
+    assert!(dot.starts_with("digraph {\n") && dot.contains("[style=dashed];"));
+}
+
+#[test]
+fn test_symbol_index() {
+    use charcoal::project::{Project, SymbolKind};
+
+    let root = charcoal::get_canonical_path("./tests/foundry-remappings", true, false).unwrap();
+    let vault_path = root.join("src/Vault.sol");
+    let legacy_vault_path = root.join("src/legacy/LegacyVault.sol");
+
+    let mut project = Project::default();
+    project.detect_project_type(&root).unwrap();
+    project.translate(None, &vault_path).unwrap();
+    project.translate(None, &legacy_vault_path).unwrap();
+
+    // Both files import an interface named `IToken`, but each one resolves to the file it imported
+    let token = project.find_translated_definition(&vault_path, "IToken").unwrap();
+    assert!(token.path.ends_with("lib/openzeppelin/contracts/token/IToken.sol"));
+
+    let legacy_token = project.find_symbol(&legacy_vault_path, SymbolKind::Definition, "IToken", |d| d.abi.is_some()).unwrap();
+    assert!(legacy_token.path.ends_with("lib/openzeppelin-legacy/contracts/token/IToken.sol"));
+
+    assert!(project.find_translated_definition(&legacy_vault_path, "IMath").is_none());
+    assert_eq!(project.collect_translated_definitions(None, &vault_path).len(), 1);
+}
+
+#[test]
+fn test_external_member_symbols() {
+    let translation = charcoal::translate_path("./tests/symbol-index/Canvas.sol", None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    // `Shapes` is resolved through the file imported by `Canvas.sol`, not the other library with the same name
+    let output = charcoal::sway::TabbedDisplayer(translation.modules().last().unwrap()).to_string();
+    assert!(output.contains("struct Point {\n    x: u256,\n    y: u256,\n}"));
+    assert!(output.contains("storage.kind.write(Kind::SQUARE);"));
+    assert!(output.contains("log(ShapesError::OutOfBounds(point.x));"));
+    assert!(output.contains("log(ShapesEvent::Moved((point.x, point.y)));"));
+    assert!(!output.contains("TRIANGLE"));
+
+    // Free and library functions are resolved through the same file
+    assert!(output.contains("fn shapes_scale(x: u256) -> u256 {\n    x * 2\n}"));
+    assert!(output.contains("fn shapes_width(point: Point) -> u256 {\n    point.x\n}"));
+    assert!(output.contains("shapes_scale(shapes_width(point))"));
+    assert!(!output.contains("x * 3"));
+}
+
+#[test]
+fn test_translate_path_with_jobs() {
+    let sequential = charcoal::translate_path("./tests/solidity-by-example/contracts", None).unwrap();
+    let parallel = charcoal::translate_path_with_jobs("./tests/solidity-by-example/contracts", None, 4).unwrap();
+
+    let outputs = |translation: &charcoal::Translation| {
+        let mut outputs = translation.modules().iter()
+            .map(|module| charcoal::sway::TabbedDisplayer(module).to_string())
+            .collect::<Vec<_>>();
+
+        outputs.sort();
+        outputs
+    };
+
+    assert_eq!(outputs(&parallel), outputs(&sequential));
+    assert_eq!(parallel.diagnostics.len(), sequential.diagnostics.len());
+
+    // Every definition still comes after the definitions it inherits from in the same file
+    for (i, translated_definition) in parallel.definitions.iter().enumerate() {
+        let position = |name: &String| parallel.definitions.iter().position(|d| d.name == *name && d.path == translated_definition.path);
+
+        for inherit in translated_definition.inherits.iter() {
+            assert!(position(inherit).map(|j| j < i).unwrap_or(true));
+        }
+    }
+
+    // Source units which share imports are grouped together, so each file is translated by a single thread
+    let root = charcoal::get_canonical_path("./tests/symbol-index", true, false).unwrap();
+    let source_unit_paths = charcoal::collect_source_unit_paths(&root).unwrap();
+
+    let mut project = charcoal::project::Project::default();
+    let groups = project.group_source_units_by_imports(source_unit_paths.as_slice());
+    assert_eq!(groups.len(), 2);
+
+    let (canvas_source_unit_paths, canvas_paths) = groups.iter().find(|(paths, _)| paths.contains(&root.join("Canvas.sol"))).unwrap();
+    assert_eq!(canvas_source_unit_paths.len(), 2);
+    assert!(canvas_paths.contains(&root.join("a/Shapes.sol")));
+    assert!(!canvas_paths.contains(&root.join("b/Shapes.sol")));
+}
+
+#[test]
+fn test_signed_integers() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Signed {
+            int256 public total = -5;
+
+            function shift(int256 a) public pure returns (int256) {
+                return a >> 3;
+            }
+
+            function remainder(int256 a, int256 b) public pure returns (int256) {
+                return a % b;
+            }
+
+            function widen(int8 a) public pure returns (int256) {
+                int256 b = a;
+                return b;
+            }
+
+            function narrow(int256 a) public pure returns (int8) {
+                return int8(a);
+            }
+
+            function reinterpret(int256 a) public pure returns (uint256) {
+                return uint256(a);
+            }
+
+            function isNegative(int256 a) public pure returns (bool) {
+                return a < -1;
+            }
+
+            function minusFive() public pure returns (int256) {
+                return -5;
+            }
+
+            function minusThree() public pure returns (int8) {
+                return -3;
+            }
+
+            function clamp(int8 a) internal pure returns (int8) {
+                return a < -1 ? -1 : a;
+            }
+
+            function clampedMinusThree() public pure returns (int8) {
+                return clamp(-3);
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Signed.sol", source, None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+
+    // Negative literals are folded into their value offset by `indent()`
+    assert!(output.contains("total: I256 = I256 {\n        underlying: 0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFB,\n    },"));
+    assert!(output.contains("a < I256 {\n            underlying: 0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF,\n        }"));
+
+    // Negative literals take the signed type of the return value, argument or other branch they're used as
+    assert!(output.contains("fn minus_five() -> I256 {\n        I256 {\n            underlying: 0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFB,\n        }\n    }"));
+    assert!(output.contains("fn minus_three() -> I8 {\n        I8 {\n            underlying: 125,\n        }\n    }"));
+    assert!(output.contains("clamp(I8 {\n            underlying: 125,\n        })"));
+    assert!(output.contains("if a < I8 {\n        underlying: 127,\n    } {\n        I8 {\n            underlying: 127,\n        }\n    } else {"));
+
+    // `>>` is an arithmetic shift
+    assert!(output.contains("let value = (a.underlying ^ I256::indent());"));
+    assert!(output.contains("underlying: (if value >> 255 == 0 {\n                    value >> 3\n                } else {\n                    !(!value >> 3)\n                }) ^ I256::indent(),"));
+
+    // `%` takes the sign of the dividend
+    assert!(output.contains("a - a / b * b"));
+
+    // Smaller signed integers are sign-extended by growing the offset
+    assert!(output.contains("underlying: u256::from(a.underlying) + 0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF80,"));
+
+    // Larger signed integers are truncated
+    assert!(output.contains("I8 {\n            underlying: u8::try_from((a.underlying ^ I256::indent()) & 255).unwrap() ^ I8::indent(),\n        }"));
+
+    // Signed and unsigned integers of the same width share their bits
+    assert!(output.contains("fn reinterpret(a: I256) -> u256 {\n        (a.underlying ^ I256::indent())\n    }"));
+}
+
+#[test]
+fn test_non_standard_uint_widths() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Oracle {
+            uint24 public count;
+            uint160 public price;
+
+            function record(uint256 value) public {
+                count += 1;
+                price = uint160(value);
+            }
+
+            function shift(uint24 a) public pure returns (uint24) {
+                return a << 4;
+            }
+
+            function max() public pure returns (uint24) {
+                return type(uint24).max;
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Oracle.sol", source, None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+
+    // Widths which Sway doesn't have are declared on the smallest type which holds them
+    assert!(output.contains("pub type u24 = u32;"));
+    assert!(output.contains("pub type u160 = u256;"));
+
+    // Additions revert when they overflow the width
+    assert!(output.contains("fn u24_checked(value: u32) -> u24 {\n    require(value <= 16777215, \"Arithmetic overflow\");\n    value\n}"));
+    assert!(output.contains("storage.count.write(u24_checked(storage.count.read() + 1));"));
+
+    // Conversions and left shifts are truncated to the width
+    assert!(output.contains("storage.price.write((value & 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF));"));
+    assert!(output.contains("(a << 4) & 16777215"));
+
+    assert!(output.contains("fn max() -> u24 {\n        16777215\n    }"));
+}
+
+#[test]
+fn test_non_standard_int_widths() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Pool {
+            int24 public tick;
+
+            function move(int24 delta) public {
+                tick += delta;
+            }
+
+            function narrow(int256 a) public pure returns (int24) {
+                return int24(a);
+            }
+
+            function wrap(int24 a, int24 b) public pure returns (int24) {
+                unchecked {
+                    return a * b;
+                }
+            }
+
+            function min() public pure returns (int24) {
+                return type(int24).min;
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Pool.sol", source, None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+
+    // Widths which Sway doesn't have are declared on the smallest signed type which holds them
+    assert!(output.contains("pub type i24 = I32;"));
+
+    // Arithmetic reverts when it overflows the width
+    assert!(output.contains("fn i24_checked(value: I32) -> i24 {\n    require(value.underlying >= 2139095040 && value.underlying <= 2155872255, \"Arithmetic overflow\");\n    value\n}"));
+    assert!(output.contains("storage.tick.write(i24_checked(storage.tick.read() + delta));"));
+
+    // Conversions and unchecked arithmetic are truncated and sign-extended to the width
+    assert!(output.contains("underlying: ((u32::try_from((a.underlying ^ I256::indent()) & 4294967295).unwrap() & 16777215) ^ 8388608) + 2139095040,"));
+    assert!(output.contains("underlying: ((wrapping_mul_u32((a.underlying ^ I32::indent()), (b.underlying ^ I32::indent())) & 16777215) ^ 8388608) + 2139095040,"));
+
+    assert!(output.contains("fn min() -> i24 {\n        I32 {\n            underlying: 2139095040,\n        }\n    }"));
+}
+
+#[test]
+fn test_unchecked_arithmetic() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Unchecked {
+            function sum(uint256 a, uint256 b) public pure returns (uint256) {
+                unchecked {
+                    return a + b;
+                }
+            }
+
+            function checkedSum(uint256 a, uint256 b) public pure returns (uint256) {
+                return a + b;
+            }
+
+            function decrement(int256 a) public pure returns (int256) {
+                unchecked {
+                    a -= 1;
+                }
+                return a;
+            }
+
+            function power(uint256 a, uint8 b) public pure returns (uint256) {
+                unchecked {
+                    return a ** b;
+                }
+            }
+
+            function negate(int256 a) public pure returns (int256) {
+                unchecked {
+                    return -a;
+                }
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Unchecked.sol", source, None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+
+    // Arithmetic in unchecked blocks wraps instead of reverting
+    assert!(output.contains("fn wrapping_add_u256(lhs: u256, rhs: u256) -> u256 {\n    let flags = disable_panic_on_overflow();\n    let result = lhs + rhs;\n    set_flags(flags);\n    result\n}"));
+    assert!(output.contains("fn sum(a: u256, b: u256) -> u256 {\n        wrapping_add_u256(a, b)\n    }"));
+    assert!(output.contains("fn checked_sum(a: u256, b: u256) -> u256 {\n        a + b\n    }"));
+
+    // Signed integers wrap their two's complement bits
+    assert!(output.contains("a = I256 {\n            underlying: wrapping_sub_u256((a.underlying ^ I256::indent()), 1) ^ I256::indent(),\n        };"));
+
+    // The exponent of a wrapping power is converted to `u32`
+    assert!(output.contains("fn wrapping_pow_u256(lhs: u256, rhs: u32) -> u256 {"));
+    assert!(output.contains("fn power(a: u256, b: u8) -> u256 {\n        wrapping_pow_u256(a, u32::from(b))\n    }"));
+
+    // Negating the minimum signed integer wraps instead of reverting
+    assert!(output.contains("fn negate(a: I256) -> I256 {\n        I256 {\n            underlying: wrapping_sub_u256(0, (a.underlying ^ I256::indent())) ^ I256::indent(),\n        }\n    }"));
+
+    // Sources older than Solidity 0.8 wrap everywhere
+    let source = r#"
+        pragma solidity >=0.5.0 <0.7.0;
+
+        contract Old {
+            function sum(uint a, uint b) public pure returns (uint) {
+                return a + b;
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Old.sol", source, None).unwrap();
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+    assert!(output.contains("wrapping_add_u256(a, b)"));
+
+    let source = r#"
+        pragma solidity ^0.6.12;
+
+        library SafeMath {
+            function add(uint256 a, uint256 b) internal pure returns (uint256) {
+                uint256 c = a + b;
+                require(c >= a, "SafeMath: addition overflow");
+                return c;
+            }
+        }
+
+        contract Safe {
+            using SafeMath for uint256;
+
+            function sum(uint256 a, uint256 b) public pure returns (uint256) {
+                return a.add(b) + 1;
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Safe.sol", source, None).unwrap();
+    let outputs = translation.modules().iter()
+        .map(|module| charcoal::sway::TabbedDisplayer(module).to_string())
+        .collect::<String>();
+
+    // SafeMath keeps its own overflow checks, while raw operators keep wrapping like they do in Solidity
+    assert!(outputs.contains("let c = wrapping_add_u256(a, b);"));
+    assert!(outputs.contains("require(c >= a, \"SafeMath: addition overflow\");"));
+    assert!(outputs.contains("wrapping_add_u256(safe_math_add(a, b), 1)"));
+}
+
+#[test]
+fn test_rational_and_fixed_point_numbers() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Rational {
+            uint256 public half = 0.5 ether;
+            uint256 public big = 2.5e18;
+            uint256 public week = 7 days;
+            uint256 public folded = 5 / 2 * 2;
+            ufixed128x18 public rate = 1.5;
+            fixed64x2 public delta = -0.25;
+
+            function product(ufixed128x18 a, ufixed128x18 b) public pure returns (ufixed128x18) {
+                return a * b;
+            }
+
+            function quotient(ufixed128x18 a, ufixed128x18 b) public pure returns (ufixed128x18) {
+                return a / b;
+            }
+
+            function increment(ufixed128x18 a) public pure returns (ufixed128x18) {
+                return a + 1;
+            }
+
+            function toFixed(uint256 x) public pure returns (ufixed128x18) {
+                return ufixed128x18(x);
+            }
+
+            function toInteger(ufixed128x18 x) public pure returns (uint256) {
+                return uint256(x);
+            }
+
+            function toMoreDecimals(ufixed64x2 x) public pure returns (ufixed128x18) {
+                return ufixed128x18(x);
+            }
+
+            function negative() public pure returns (int256) {
+                return -2.5 * 2;
+            }
+
+            function square(fixed128x18 a) public pure returns (fixed128x18) {
+                return a * a;
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Rational.sol", source, None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+
+    // Rational literals, exponents and units are folded into exact integers
+    assert!(output.contains("half: u256 = 500000000000000000,"));
+    assert!(output.contains("big: u256 = 2500000000000000000,"));
+    assert!(output.contains("week: u256 = 604800,"));
+    assert!(output.contains("folded: u256 = 5,"));
+
+    // Fixed point types are integers scaled by their decimals
+    assert!(output.contains("pub type ufixed128x18 = u128;"));
+    assert!(output.contains("pub type fixed64x2 = I64;"));
+    assert!(output.contains("rate: ufixed128x18 = 1500000000000000000,"));
+    assert!(output.contains("delta: fixed64x2 = I64 {\n        underlying: 9223372036854775783,\n    },"));
+
+    // Products and quotients of fixed point values are rescaled by their decimals
+    assert!(output.contains("fn product(a: ufixed128x18, b: ufixed128x18) -> ufixed128x18 {\n        a * b / 1000000000000000000\n    }"));
+    assert!(output.contains("fn quotient(a: ufixed128x18, b: ufixed128x18) -> ufixed128x18 {\n        a * 1000000000000000000 / b\n    }"));
+
+    // Products of narrower types are computed on 256 bits and checked before they're narrowed back, so `14.0 * 14.0` doesn't overflow
+    assert!(output.contains("fn square(a: fixed128x18) -> fixed128x18 {\n        fixed128x18_mul(a, a)\n    }"));
+    assert!(output.contains("fn fixed128x18_mul(lhs: fixed128x18, rhs: fixed128x18) -> fixed128x18 {\n    let result = I256 {"));
+    assert!(output.contains("require(result.underlying >= 0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF80000000000000000000000000000000 && result.underlying <= 0x800000000000000000000000000000007FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF, \"Arithmetic overflow\");"));
+
+    // Constant operands take the fixed point type of the other operand
+    assert!(output.contains("fn increment(a: ufixed128x18) -> ufixed128x18 {\n        a + 1000000000000000000\n    }"));
+
+    // Conversions between integers and fixed point types scale the value by the difference of their decimals
+    assert!(output.contains("fn to_fixed(x: u256) -> ufixed128x18 {\n        x * 1000000000000000000\n    }"));
+    assert!(output.contains("fn to_integer(x: ufixed128x18) -> u256 {\n        x / 1000000000000000000\n    }"));
+    assert!(output.contains("fn to_more_decimals(x: ufixed64x2) -> ufixed128x18 {\n        u256::from(x) * 10000000000000000\n    }"));
+
+    // Negative constants are signed integer literals
+    assert!(output.contains("fn negative() -> I256 {\n        I256 {\n            underlying: 0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFB,\n        }\n    }"));
+}
+
+#[test]
+fn test_function_types() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Callbacks {
+            uint256 public total;
+            function(uint256) internal pure returns (uint256) op = double;
+
+            function double(uint256 x) internal pure returns (uint256) {
+                return x * 2;
+            }
+
+            function square(uint256 x) internal pure returns (uint256) {
+                return x * x;
+            }
+
+            function record(uint256 x) internal {
+                total += x;
+            }
+
+            function apply(function(uint256) internal pure returns (uint256) f, uint256 x) internal pure returns (uint256) {
+                return f(x);
+            }
+
+            function compute(uint256 x) public returns (uint256) {
+                function(uint256) internal sink = record;
+                sink(apply(square, x));
+                return op(x);
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Callbacks.sol", source, None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+
+    // Each signature gets an enum of the functions used as values, which is unassigned by default
+    assert!(output.contains("enum FunctionU256ReturnsU256 {\n    Unassigned: (),\n    Double: (),\n    Square: (),\n}"));
+    assert!(output.contains("op: FunctionU256ReturnsU256 = FunctionU256ReturnsU256::Double,"));
+
+    // Calls through function values go through the dispatch function of their signature
+    assert!(output.contains("fn dispatch_function_u256_returns_u256(function: FunctionU256ReturnsU256, arg0: u256) -> u256 {"));
+    assert!(output.contains("FunctionU256ReturnsU256::Unassigned => revert(0x51),"));
+    assert!(output.contains("FunctionU256ReturnsU256::Square => square(arg0),"));
+    assert!(output.contains("fn apply(f: FunctionU256ReturnsU256, x: u256) -> u256 {\n    dispatch_function_u256_returns_u256(f, x)\n}"));
+    assert!(output.contains("dispatch_function_u256_returns_u256(storage.op.read(), x)"));
+
+    // Dispatch functions have the storage access of their function types
+    assert!(output.contains("#[storage(read, write)]\nfn dispatch_function_u256(function: FunctionU256, arg0: u256) {"));
+    assert!(output.contains("dispatch_function_u256(sink, apply(FunctionU256ReturnsU256::Square, x));"));
+}
+
+#[test]
+fn test_overloaded_function_values() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Overloads {
+            struct Op {
+                function(uint256) internal pure returns (uint256) apply;
+            }
+
+            mapping(uint256 => function(uint256) internal pure returns (uint256)) ops;
+
+            function overloaded(uint256 x) internal pure returns (uint256) {
+                return x + 1;
+            }
+
+            function overloaded(bool x) internal pure returns (bool) {
+                return !x;
+            }
+
+            function check(function(bool) internal pure returns (bool) f, bool x) internal pure returns (bool) {
+                return f(x);
+            }
+
+            function test(uint256 x) public returns (bool) {
+                function(bool) internal pure returns (bool) h = overloaded;
+                Op memory op = Op(overloaded);
+                ops[1] = overloaded;
+                uint256 y = op.apply(x) + ops[1](x);
+                return h(x > 1) && check(overloaded, y > 2);
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Overloads.sol", source, None).unwrap();
+    assert!(translation.diagnostics.is_empty());
+
+    let output = charcoal::sway::TabbedDisplayer(&translation.modules()[0]).to_string();
+
+    // Overloaded functions are resolved by the function type they're assigned or passed to
+    assert!(output.contains("let h = FunctionBoolReturnsBool::Overloaded2;"));
+    assert!(output.contains("apply: FunctionU256ReturnsU256::Overloaded,"));
+    assert!(output.contains("storage.ops.get(1).write(FunctionU256ReturnsU256::Overloaded);"));
+    assert!(output.contains("check(FunctionBoolReturnsBool::Overloaded2, y > 2)"));
+
+    // Function values in struct fields and mappings are called through their dispatch function
+    assert!(output.contains("dispatch_function_u256_returns_u256(op.apply, x) + dispatch_function_u256_returns_u256(storage.ops.get(1).read(), x)"));
+    assert!(output.contains("dispatch_function_bool_returns_bool(h, x > 1)"));
+}
+
+#[test]
+fn test_unsupported_call_block_arg_warning() {
+    let source = r#"
+        // SPDX-License-Identifier: MIT
+        pragma solidity ^0.8.24;
+
+        contract Child {}
+
+        contract Factory {
+            function make() public {
+                new Child{salt: bytes32(0)}();
+            }
+        }
+    "#;
+
+    let translation = charcoal::translate_source("Factory.sol", source, None).unwrap();
+
+    let diagnostic = translation.diagnostics.iter()
+        .find(|d| d.level == charcoal::errors::DiagnosticLevel::Warning)
+        .unwrap();
+
+    assert_eq!(diagnostic.line_and_column, Some((9, 27)));
+    assert!(diagnostic.message.contains("unsupported function call block arg: salt"));
+}